@@ -0,0 +1,535 @@
+use std::fs;
+use std::io::{self, ErrorKind, StdoutLock, Write};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use serde::Deserialize;
+use spaces_game::engine::board::{Board, BoardError, SavedBoard};
+use spaces_game::engine::enumerate::{enumerate_boards, enumerate_boards_on};
+use spaces_game::engine::game_board::GameBoard;
+use spaces_game::engine::layout::{Layout, LayoutPreset};
+use spaces_game::engine::movement::{Movement, MovementPreset};
+use spaces_game::engine::rules::{Ruleset, Variant};
+use spaces_game::engine::strategy::{self, StrategyBook};
+use spaces_game::export;
+use spaces_game::render::svg::{RenderOptions, Theme};
+use spaces_game::render::text;
+
+const USAGE: &str = "Usage:
+  spaces-cli validate <FILE>... [--movement <MOVES>] [--layout <MAP>]
+  spaces-cli simulate <PLAYER_FILE> <OPPONENT_FILE> [--rules <RULES>] [--movement <MOVES>]
+                      [--layout <MAP>]
+  spaces-cli tournament <DIR> [--rules <RULES>] [--movement <MOVES>] [--layout <MAP>]
+  spaces-cli enumerate (--size <N> | --layout <MAP>) [--max-steps <M>] [--count]
+                       [--movement <MOVES>]
+  spaces-cli train (--size <N> | --layout <MAP>) --out <FILE> [--max-steps <M>]
+                  [--iterations <K>] [--rules <RULES>] [--movement <MOVES>]
+  spaces-cli export <BOARD_FILE> [<OPPONENT_FILE>] --out <FILE.png|FILE.gif>
+                    [--width <PX>] [--theme dark|light|color-blind]
+                    [--rules <RULES>] [--movement <MOVES>] [--layout <MAP>]
+
+Board files hold a board, a saved board, or a list of either (such as the
+`saved_boards` entry exported from the browser's local storage).
+
+`train` plays every board of the size (up to --max-steps long, 3 more than
+the size by default) against every other and runs K rounds of regret
+matching (2000 by default) to find the Expert CPU's mixed strategy. The
+strategy is added to the strategy book in FILE, replacing any trained for the
+same size, rules and map; the web client loads `strategies/expert.json`.
+
+`export` writes one board as a PNG, or with an opponent file the round result
+as a PNG or its step-by-step replay as a GIF.
+
+Rounds are resolved with the classic rules unless `--rules` names a variant
+(classic, fading-traps, sideways, bumpers, big-goal), `goal-piece-ignored`
+for the classic rules without collisions against a piece that reached its
+goal, or a JSON file holding a ruleset.
+
+Boards must follow the classic movement (one square forward or sideways,
+traps next to the piece) unless `--movement` allows more: a comma-separated
+list of diagonal, backward, jumps, long-traps or free.
+
+Boards are built and rounds played on an open board unless `--layout` names a
+map (pillar, minefield, wormhole, crossroads) or a file holding one, written
+one row per line: `.` open, `#` wall, `x` neutral trap, `+` bonus square and
+letter pairs for teleporters.";
+
+/// Any of the shapes a board file can take.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum BoardFile {
+    Board(Board),
+    Saved(SavedBoard),
+    Boards(Vec<Board>),
+    SavedBoards(Vec<SavedBoard>),
+}
+
+impl BoardFile {
+    fn into_boards(self) -> Vec<Board> {
+        match self {
+            BoardFile::Board(board) => vec![board],
+            BoardFile::Saved(saved) => vec![saved.board],
+            BoardFile::Boards(boards) => boards,
+            BoardFile::SavedBoards(saved) => saved.into_iter().map(|s| s.board).collect(),
+        }
+    }
+}
+
+fn load_boards(path: &Path) -> Result<Vec<Board>, String> {
+    let data = fs::read_to_string(path)
+        .map_err(|e| format!("{}: {}", path.display(), e))?;
+    let file: BoardFile = serde_json::from_str(&data)
+        .map_err(|e| format!("{}: not a board file: {}", path.display(), e))?;
+    Ok(file.into_boards())
+}
+
+fn load_single_board(path: &Path, rules: &Rules) -> Result<Board, String> {
+    let mut boards = load_boards(path)?;
+    if boards.len() != 1 {
+        return Err(format!("{}: expected one board, found {}", path.display(), boards.len()));
+    }
+    let board = boards.remove(0);
+    rules.validate(&board).map_err(|e| format!("{}: invalid board: {}", path.display(), e))?;
+    Ok(board)
+}
+
+fn validate(args: &[String]) -> Result<(), String> {
+    let (rules, paths) = take_rules(args)?;
+    if paths.is_empty() {
+        return Err("validate needs at least one file".to_string());
+    }
+
+    let mut invalid = 0;
+    for path in &paths {
+        let boards = load_boards(Path::new(path))?;
+        for (index, board) in boards.iter().enumerate() {
+            match rules.validate(board) {
+                Ok(()) => println!("{}#{}: ok", path, index),
+                Err(e) => {
+                    println!("{}#{}: {}", path, index, e);
+                    invalid += 1;
+                }
+            }
+        }
+    }
+
+    if invalid > 0 {
+        return Err(format!("{} invalid board(s)", invalid));
+    }
+    Ok(())
+}
+
+/// What boards are checked against and rounds resolved with.
+struct Rules {
+    ruleset: Ruleset,
+    layout: Option<Layout>,
+}
+
+impl Rules {
+    fn validate(&self, board: &Board) -> Result<(), BoardError> {
+        match &self.layout {
+            Some(layout) => board.validate_on(&self.ruleset.movement, layout),
+            None => board.validate(&self.ruleset.movement),
+        }
+    }
+
+    fn game_board(&self, size: usize) -> GameBoard {
+        match &self.layout {
+            Some(layout) => GameBoard::with_layout(self.ruleset, layout.clone()),
+            None => GameBoard::with_ruleset(size, self.ruleset),
+        }
+    }
+}
+
+/// Pulls `--rules`, `--movement` and `--layout` out of `args`, returning the
+/// rules and the remaining arguments. `--movement` overrides the movement of
+/// the rules.
+fn take_rules(args: &[String]) -> Result<(Rules, Vec<String>), String> {
+    let mut ruleset = Ruleset::default();
+    let mut movement = None;
+    let mut layout = None;
+    let mut rest = Vec::new();
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--rules" => ruleset = parse_rules(args.next().ok_or("--rules needs a value")?)?,
+            "--movement" => movement = Some(parse_movement(args.next().ok_or("--movement needs a value")?)?),
+            "--layout" => layout = parse_layout(args.next().ok_or("--layout needs a value")?)?,
+            _ => rest.push(arg.clone()),
+        }
+    }
+    if let Some(movement) = movement {
+        ruleset.movement = movement;
+    }
+    Ok((Rules { ruleset, layout }, rest))
+}
+
+fn parse_layout(value: &str) -> Result<Option<Layout>, String> {
+    if let Some(preset) = LayoutPreset::ALL.into_iter().find(|p| p.key() == value) {
+        return Ok(preset.layout());
+    }
+    let path = Path::new(value);
+    let data = fs::read_to_string(path).map_err(|e| format!("{}: {}", value, e))?;
+    let layout = if path.extension().is_some_and(|ext| ext == "json") {
+        serde_json::from_str(&data).map_err(|e| format!("{}: not a layout: {}", value, e))?
+    } else {
+        let name = path.file_stem().unwrap_or_default().to_string_lossy();
+        Layout::parse(&name, &data).map_err(|e| format!("{}: {}", value, e))?
+    };
+    Ok(Some(layout))
+}
+
+/// Everything the comma-separated movement presets in `value` allow.
+fn parse_movement(value: &str) -> Result<Movement, String> {
+    value.split(',').try_fold(Movement::default(), |movement, key| {
+        MovementPreset::ALL
+            .into_iter()
+            .find(|preset| preset.key() == key.trim())
+            .map(|preset| movement.union(&preset.movement()))
+            .ok_or_else(|| format!("unknown movement `{}`", key))
+    })
+}
+
+fn parse_rules(value: &str) -> Result<Ruleset, String> {
+    if value == "goal-piece-ignored" {
+        return Ok(Ruleset::goal_piece_ignored());
+    }
+    if let Some(variant) = Variant::ALL.into_iter().find(|v| v.key() == value) {
+        return Ok(variant.ruleset());
+    }
+    if value.ends_with(".json") {
+        let data = fs::read_to_string(value).map_err(|e| format!("{}: {}", value, e))?;
+        return serde_json::from_str(&data).map_err(|e| format!("{}: not a ruleset: {}", value, e));
+    }
+    Err(format!("unknown rules `{}`", value))
+}
+
+fn simulate(args: &[String]) -> Result<(), String> {
+    let (rules, paths) = take_rules(args)?;
+    let [player_path, opponent_path] = paths.as_slice() else {
+        return Err("simulate needs a player file and an opponent file".to_string());
+    };
+    let player = load_single_board(Path::new(player_path), &rules)?;
+    let opponent = load_single_board(Path::new(opponent_path), &rules)?;
+    if player.size != opponent.size {
+        return Err(format!("board sizes differ ({} vs {})", player.size, opponent.size));
+    }
+
+    let mut game_board = rules.game_board(player.size);
+    game_board.process_turn(&player, &opponent);
+
+    write_output(|out| {
+        writeln!(out, "Player board:")?;
+        write!(out, "{}", text::render_board(&player))?;
+        writeln!(out, "Opponent board:")?;
+        write!(out, "{}", text::render_opponent_board(&opponent))?;
+        writeln!(out)?;
+        for event in &game_board.events {
+            writeln!(out, "{}", event)?;
+        }
+        writeln!(out)?;
+        write!(out, "{}", text::render_round(&game_board))?;
+        writeln!(out, "{}", text::LEGEND)?;
+        if rules.layout.is_some() {
+            writeln!(out, "{}", text::LAYOUT_LEGEND)?;
+        }
+        writeln!(out)?;
+        writeln!(out, "Player: {}  Opponent: {}", game_board.player_score, game_board.opponent_score)
+    })
+}
+
+#[derive(Default, Clone)]
+struct Standing {
+    name: String,
+    wins: u32,
+    losses: u32,
+    draws: u32,
+    points_for: i32,
+    points_against: i32,
+}
+
+fn tournament(args: &[String]) -> Result<(), String> {
+    let (rules, paths) = take_rules(args)?;
+    let [dir] = paths.as_slice() else {
+        return Err("tournament needs a directory of board files".to_string());
+    };
+
+    let mut files: Vec<PathBuf> = fs::read_dir(dir)
+        .map_err(|e| format!("{}: {}", dir, e))?
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+        .collect();
+    files.sort();
+
+    let mut entrants: Vec<(String, Board)> = Vec::new();
+    for path in &files {
+        let name = path.file_stem().unwrap_or_default().to_string_lossy().to_string();
+        let boards = load_boards(path)?;
+        let multiple = boards.len() > 1;
+        for (index, board) in boards.into_iter().enumerate() {
+            let name = if multiple { format!("{}#{}", name, index) } else { name.clone() };
+            match rules.validate(&board) {
+                Ok(()) => entrants.push((name, board)),
+                Err(e) => eprintln!("skipping {}: {}", name, e),
+            }
+        }
+    }
+    if entrants.len() < 2 {
+        return Err("a tournament needs at least two valid boards".to_string());
+    }
+
+    let mut standings: Vec<Standing> = entrants
+        .iter()
+        .map(|(name, _)| Standing { name: name.clone(), ..Standing::default() })
+        .collect();
+
+    for i in 0..entrants.len() {
+        for j in (i + 1)..entrants.len() {
+            let (player, opponent) = (&entrants[i].1, &entrants[j].1);
+            if player.size != opponent.size {
+                continue;
+            }
+            let mut game_board = rules.game_board(player.size);
+            game_board.process_turn(player, opponent);
+            let (p, o) = (game_board.player_score, game_board.opponent_score);
+
+            standings[i].points_for += p;
+            standings[i].points_against += o;
+            standings[j].points_for += o;
+            standings[j].points_against += p;
+            match p.cmp(&o) {
+                std::cmp::Ordering::Greater => {
+                    standings[i].wins += 1;
+                    standings[j].losses += 1;
+                }
+                std::cmp::Ordering::Less => {
+                    standings[i].losses += 1;
+                    standings[j].wins += 1;
+                }
+                std::cmp::Ordering::Equal => {
+                    standings[i].draws += 1;
+                    standings[j].draws += 1;
+                }
+            }
+        }
+    }
+
+    standings.sort_by(|a, b| {
+        (b.wins, b.points_for - b.points_against).cmp(&(a.wins, a.points_for - a.points_against))
+    });
+    let width = standings.iter().map(|s| s.name.len()).max().unwrap_or(0).max(5);
+    write_output(|out| {
+        writeln!(out, "{:<width$}  {:>3} {:>3} {:>3} {:>5} {:>5}", "Board", "W", "L", "D", "For", "Agst")?;
+        for s in &standings {
+            writeln!(
+                out,
+                "{:<width$}  {:>3} {:>3} {:>3} {:>5} {:>5}",
+                s.name, s.wins, s.losses, s.draws, s.points_for, s.points_against
+            )?;
+        }
+        Ok(())
+    })
+}
+
+fn enumerate(args: &[String]) -> Result<(), String> {
+    let mut size = None;
+    let mut max_steps = None;
+    let mut count_only = false;
+
+    let (rules, args) = take_rules(args)?;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--size" => size = Some(parse_number(args.next(), "--size")?),
+            "--max-steps" => max_steps = Some(parse_number(args.next(), "--max-steps")?),
+            "--count" => count_only = true,
+            other => return Err(format!("unknown argument `{}`", other)),
+        }
+    }
+    let size = match (size, &rules.layout) {
+        (Some(size), Some(layout)) if size != layout.size() => {
+            return Err(format!("--size {} does not match the {}×{} layout", size, layout.size(), layout.size()));
+        }
+        (_, Some(layout)) => layout.size(),
+        (Some(size), None) => size,
+        (None, None) => return Err("enumerate needs --size or --layout".to_string()),
+    };
+    // Enough to visit every square once and then leave the board
+    let max_steps = max_steps.unwrap_or(size * size + 1);
+
+    let boards = match &rules.layout {
+        Some(layout) => enumerate_boards_on(layout, max_steps, &rules.ruleset.movement),
+        None => enumerate_boards(size, max_steps, &rules.ruleset.movement),
+    };
+    write_output(|out| {
+        if count_only {
+            return writeln!(out, "{}", boards.len());
+        }
+        for board in &boards {
+            serde_json::to_writer(&mut *out, board)?;
+            writeln!(out)?;
+        }
+        Ok(())
+    })
+}
+
+fn train(args: &[String]) -> Result<(), String> {
+    let mut size = None;
+    let mut max_steps = None;
+    let mut iterations = 2000;
+    let mut out = None;
+
+    let (rules, args) = take_rules(args)?;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--size" => size = Some(parse_number(args.next(), "--size")?),
+            "--max-steps" => max_steps = Some(parse_number(args.next(), "--max-steps")?),
+            "--iterations" => iterations = parse_number(args.next(), "--iterations")?,
+            "--out" => out = Some(PathBuf::from(args.next().ok_or("--out needs a value")?)),
+            other => return Err(format!("unknown argument `{}`", other)),
+        }
+    }
+    let out = out.ok_or("train needs --out")?;
+    let layout = match (size, &rules.layout) {
+        (Some(size), Some(layout)) if size != layout.size() => {
+            return Err(format!("--size {} does not match the {}×{} layout", size, layout.size(), layout.size()));
+        }
+        (_, Some(layout)) => layout.clone(),
+        (Some(size), None) => Layout::open(size),
+        (None, None) => return Err("train needs --size or --layout".to_string()),
+    };
+    // The same boards the CPU personas build their libraries from
+    let max_steps = max_steps.unwrap_or(layout.size() + 3);
+    let boards = enumerate_boards_on(&layout, max_steps, &rules.ruleset.movement);
+    if boards.is_empty() {
+        return Err("no legal boards to train on".to_string());
+    }
+
+    let mut book = if out.exists() {
+        let data = fs::read_to_string(&out).map_err(|e| format!("{}: {}", out.display(), e))?;
+        serde_json::from_str(&data).map_err(|e| format!("{}: not a strategy book: {}", out.display(), e))?
+    } else {
+        StrategyBook::default()
+    };
+    let training = strategy::train(rules.ruleset, rules.layout.as_ref(), &boards, iterations);
+    println!(
+        "{} boards, {} played; expected margin {:.3}, equilibrium gap {:.4}",
+        boards.len(),
+        training.strategy.boards.len(),
+        training.strategy.value,
+        training.gap
+    );
+    book.insert(training.strategy);
+    let json = serde_json::to_string(&book).map_err(|e| e.to_string())?;
+    fs::write(&out, json).map_err(|e| format!("{}: {}", out.display(), e))?;
+    println!("wrote {}", out.display());
+    Ok(())
+}
+
+fn export(args: &[String]) -> Result<(), String> {
+    let mut files = Vec::new();
+    let mut out = None;
+    let mut width = 400;
+    let mut theme = Theme::Dark;
+
+    let (rules, args) = take_rules(args)?;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--out" => out = Some(args.next().ok_or("--out needs a value")?.clone()),
+            "--width" => {
+                width = u32::try_from(parse_number(args.next(), "--width")?)
+                    .map_err(|_| format!("--width expects at most {} pixels", u32::MAX))?
+            }
+            "--theme" => {
+                theme = match args.next().map(String::as_str) {
+                    Some("dark") => Theme::Dark,
+                    Some("light") => Theme::Light,
+                    Some("color-blind") => Theme::ColorBlind,
+                    _ => return Err("--theme expects dark, light or color-blind".to_string()),
+                }
+            }
+            other => files.push(other.to_string()),
+        }
+    }
+    let out = PathBuf::from(out.ok_or("export needs --out")?);
+    let is_gif = out.extension().is_some_and(|ext| ext == "gif");
+    let options = RenderOptions::default().with_theme(theme);
+
+    let bytes = match files.as_slice() {
+        [board_path] if !is_gif => {
+            let board = load_single_board(Path::new(board_path), &rules)?;
+            export::board_png(&board, rules.layout.as_ref(), &options, width)
+        }
+        [player_path, opponent_path] => {
+            let player = load_single_board(Path::new(player_path), &rules)?;
+            let opponent = load_single_board(Path::new(opponent_path), &rules)?;
+            if player.size != opponent.size {
+                return Err(format!("board sizes differ ({} vs {})", player.size, opponent.size));
+            }
+            let mut game_board = rules.game_board(player.size);
+            game_board.process_turn(&player, &opponent);
+            if is_gif {
+                export::replay_gif(&game_board, &options, width)
+            } else {
+                export::round_png(&game_board, &options, width)
+            }
+        }
+        [_] => return Err("a GIF replay needs a player file and an opponent file".to_string()),
+        _ => return Err("export needs one board file, or a player and an opponent file".to_string()),
+    }
+    .map_err(|e| e.to_string())?;
+
+    fs::write(&out, bytes).map_err(|e| format!("{}: {}", out.display(), e))?;
+    println!("wrote {}", out.display());
+    Ok(())
+}
+
+/// Runs `write` against a locked stdout. A reader that stops early, like
+/// `head`, closes the pipe, which is not an error.
+fn write_output(write: impl FnOnce(&mut StdoutLock) -> io::Result<()>) -> Result<(), String> {
+    let mut out = io::stdout().lock();
+    match write(&mut out).and_then(|()| out.flush()) {
+        Err(e) if e.kind() == ErrorKind::BrokenPipe => Ok(()),
+        result => result.map_err(|e| format!("writing output: {}", e)),
+    }
+}
+
+fn parse_number(value: Option<&String>, flag: &str) -> Result<usize, String> {
+    value
+        .ok_or_else(|| format!("{} needs a value", flag))?
+        .parse()
+        .map_err(|_| format!("{} expects a number", flag))
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let Some((command, rest)) = args.split_first() else {
+        eprintln!("{}", USAGE);
+        return ExitCode::FAILURE;
+    };
+
+    let result = match command.as_str() {
+        "validate" => validate(rest),
+        "simulate" => simulate(rest),
+        "tournament" => tournament(rest),
+        "enumerate" => enumerate(rest),
+        "train" => train(rest),
+        "export" => export(rest),
+        "help" | "--help" | "-h" => {
+            println!("{}", USAGE);
+            Ok(())
+        }
+        other => Err(format!("unknown command `{}`\n\n{}", other, USAGE)),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
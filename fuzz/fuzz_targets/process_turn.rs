@@ -0,0 +1,27 @@
+#![no_main]
+
+//! Feeds arbitrary serialized board pairs to the validator and the round
+//! resolver. Neither may panic, whatever the boards contain.
+
+use libfuzzer_sys::fuzz_target;
+use spaces_game::engine::board::Board;
+use spaces_game::engine::game_board::GameBoard;
+use spaces_game::engine::movement::MovementPreset;
+
+fuzz_target!(|data: &[u8]| {
+    let Ok((player, opponent)) = serde_json::from_slice::<(Board, Board)>(data) else {
+        return;
+    };
+    // Sizes are only bounded by the input, so keep allocations sane
+    if player.size > 64 || opponent.size > 64 {
+        return;
+    }
+    for preset in MovementPreset::ALL {
+        let _ = player.validate(&preset.movement());
+        let _ = opponent.validate(&preset.movement());
+    }
+
+    let mut game_board = GameBoard::new(player.size);
+    game_board.process_turn(&player, &opponent);
+    let _ = game_board.replay_steps();
+});
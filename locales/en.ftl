@@ -0,0 +1,288 @@
+# English messages; every other bundle translates these ids.
+
+## Home screen
+
+greeting-prompt = Hi! What's your name?
+greeting = Hello, { $name }!
+greeting-submit = Hello
+edit-profile = Edit Profile
+saved-match = Match against { $opponent } in progress: { $played } of { $rounds ->
+        [one] { $rounds } round
+       *[other] { $rounds } rounds
+    } played, { $playerScore }–{ $opponentScore }. Finish or abandon it to start another.
+resume = Resume
+abandon = Abandon
+abandon-title = Counts as a loss
+opponents = Opponents
+opponent-computer = C
+opponent-human = H
+play = Play
+confirm-removal = Confirm Removal
+confirm-removal-text = Are you sure you want to remove { $name } from your opponents list?
+cancel = Cancel
+remove = Remove
+boards = Boards
+create-board = + Create New Board
+
+## Profile
+
+username = Username
+default-speed = Default Game Speed
+board-theme = Board Theme
+default-rules = Default Rules
+language = Language
+manage-opponents = Manage Opponents
+add-opponent = + Add Opponent
+opponent-name = Name
+avatar-glyph = Badge letter
+avatar-color = Badge color
+color-blue = Blue
+color-green = Green
+color-red = Red
+color-orange = Orange
+color-purple = Purple
+color-teal = Teal
+color-pink = Pink
+color-slate = Slate
+notes = Notes
+preferred-speed = Speed
+speed-default = My default speed
+own-rules = Own rules for this opponent
+rounds = Rounds:
+opponent-added = Added { $date }
+save = Save
+forfeited = { $count } forfeited
+save-changes = Save Changes
+
+speed-lightning = Lightning! (1s to choose)
+speed-quick = Quick! (5s to choose)
+speed-relaxed = Relaxed (10s to choose)
+speed-chill = Totally Chill (no limit)
+speed-custom = Custom
+timing-selection = Seconds to choose a board (0 for no limit)
+timing-reveal = Seconds to reveal both boards
+timing-results = Seconds to show each round's result (0 to wait for you)
+
+theme-dark = Dark
+theme-light = Light
+theme-color-blind = Color-blind safe
+
+## Rules, movement, maps and opponents
+
+variant-classic = Classic
+variant-classic-description = Forward moves and the goal score 1, traps and collisions cost 1.
+variant-fading-traps = Fading traps
+variant-fading-traps-description = Traps stay armed for 3 steps, then disarm.
+variant-sideways = Sideways scoring
+variant-sideways-description = Sideways moves score 1 as well.
+variant-bumpers = Bumpers
+variant-bumpers-description = Collisions knock pieces back instead of ending the round.
+variant-big-goal = Big goal
+variant-big-goal-description = Reaching the goal scores 3.
+
+movement-classic = Classic
+movement-diagonal = Diagonal steps
+movement-backward = Backward steps
+movement-jumps = Two-square jumps
+movement-long-traps = Trap range 2
+movement-free = Anything goes
+
+layout-open = Open
+layout-open-description = No tiles; boards of any size.
+layout-pillar = Pillar
+layout-pillar-description = 3×3 with a wall in the middle.
+layout-minefield = Minefield
+layout-minefield-description = 3×3 with a bonus square between two neutral traps.
+layout-wormhole = Wormhole
+layout-wormhole-description = 3×3 with a teleporter in each corner of one diagonal.
+layout-crossroads = Crossroads
+layout-crossroads-description = 4×4 with walls, bonus squares and two teleporter pairs.
+
+persona-cpu-description = Plays any legal board at random.
+persona-trapper-description = Lays traps as early as it can.
+persona-sprinter-description = Runs straight for the goal.
+persona-mirror-description = Plays your last board back at you.
+persona-adaptive-description = Learns which boards you play and counters them.
+persona-expert-description = Mixes its boards so that no choice of yours can exploit them.
+
+## Board creator
+
+movement = Movement:
+map = Map:
+trapped = You're trapped! Reset and try again
+final-move = Final Move
+board-grid = Board. Arrow keys pick a square; M or Enter moves or starts there, T lays a trap, F makes the final move.
+cell = Row { $row }, column { $column }: { $content ->
+        [piece] your piece
+        [trap] trap
+        [wall] wall
+        [neutral-trap] neutral trap
+        [bonus] bonus square
+        [teleporter] teleporter
+       *[empty] empty
+    }{ $actions ->
+        [start] , start here
+        [move-trap] , move or trap
+        [move] , move
+        [trap] , trap
+       *[none] { "" }
+    }
+start = Start
+move = Move
+trap = Trap
+choose-start = Choose a starting square
+board-complete = Board complete!
+creator-help = Select a highlighted square to move your piece or place a trap, or use the arrow keys with M, T and F.
+board-preview = Board Preview
+
+## Saved boards and decks
+
+favorite = Favorite
+decks = Decks
+deck-summary = { $count ->
+        [one] { $count } board
+       *[other] { $count } boards
+    } · { $rule }
+edit = Edit
+delete = Delete
+new-deck = + New Deck
+deck-draft-help = Click boards above to add or remove them ({ $count } in the deck).
+deck-name = Deck name
+save-deck = Save Deck
+deck-rule-open = Every board, every round
+deck-rule-single-use = Each board once per match
+deck-rule-hand = Hand of { $size } each round
+deck-error-no-playable = none of the deck's boards can be played in this match
+deck-error-too-few = the deck has { $playable } playable boards for a match of { $rounds } rounds
+
+## Match
+
+board-chooser = Your boards. Press 1 to 9 to pick one.
+board-choice = Board { $number }: { $description }
+board-choice-hinted = Board { $number }, expected { $points } points: { $description }
+hint-title = Points this board is expected to score against the boards your opponent has played
+hint-points = ~{ $points } pts
+rematch-waiting = Waiting for { $name } to answer…
+rematch-wanted = { $name } wants to play again!
+play-again = Play Again
+decline-rematch = Naur
+match-over-announcement = Match over. Final score: you { $player }, opponent { $opponent }.
+time-up = Time is up.
+time-left-announcement = { $seconds ->
+        [one] { $seconds } second
+       *[other] { $seconds } seconds
+    } left to choose a board.
+round-of = Round { $round } of { $rounds }
+forfeit = Forfeit the match
+keep-playing = Keep playing
+exit-game = Exit Game
+exit-game-title = Matches in progress are saved and can be resumed from the home screen
+rules = Rules:
+ranked = Ranked
+ranked-title = Ranked matches are played without hints
+deck = Deck:
+all-boards = All boards
+hints = Hints
+hints-title = Rank your boards by the points they can expect against your opponent's boards
+select-board = Select your board
+seconds-left = { $seconds ->
+        [one] { $seconds } second left!
+       *[other] { $seconds } seconds left!
+    }
+waiting-for-board = Waiting for { $name } to choose a board…
+hand-over = Pass the device to { $name }; your board stays hidden.
+take-turn = I'm { $name }, show my boards
+choose-as = { $name }, choose your board
+save-png = Save PNG
+save-gif = Save Replay GIF
+round-points = { $name } (Round):
+next-board = Choose your next board
+see-final-score = See Final Score
+pause = Pause
+pause-title = Stop the clock until you resume
+paused = Paused
+next-round-in = { $seconds ->
+        [one] Next round in { $seconds } second
+       *[other] Next round in { $seconds } seconds
+    }
+final-score-in = { $seconds ->
+        [one] Final score in { $seconds } second
+       *[other] Final score in { $seconds } seconds
+    }
+rematch-declined = { $name } declined to play again.
+back-to-main = Back to Main Screen
+
+## Installing and updating
+
+install = Install
+install-title = Add the game to your home screen or apps; it plays offline
+update-ready = A new version of the game is ready.
+update-reload = Reload
+update-later = Later
+
+## Descriptions for screen readers
+
+describe-empty = Empty board
+describe-start = Start { $square }
+describe-move = { $direction } to { $square } on step { $step }
+describe-teleport = { $direction } to { $square } on step { $step } and teleport to { $exit }
+describe-trap = trap at { $square } on step { $step }
+describe-goal = reach the goal on step { $step }
+describe-direction = { $verb ->
+        [jump] jump
+       *[move] move
+    } { $way ->
+        [up] up
+        [down] down
+        [left] left
+        [right] right
+        [up-left] up-left
+        [up-right] up-right
+        [down-left] down-left
+        [down-right] down-right
+       *[none] in place
+    }
+
+square = row { $row }, column { $column }
+square-in-row = { $row ->
+        [top] top
+        [middle] middle
+       *[bottom] bottom
+    } row, column { $column }
+square-in-column = row { $row }, { $column ->
+        [left] left
+        [middle] middle
+       *[right] right
+    } column
+square-top-left = top-left
+square-top-middle = top-middle
+square-top-right = top-right
+square-middle-left = middle-left
+square-middle-middle = the centre
+square-middle-right = middle-right
+square-bottom-left = bottom-left
+square-bottom-middle = bottom-middle
+square-bottom-right = bottom-right
+
+round-goal = { $side ->
+        [player] You
+       *[opponent] Opponent
+    } reached the goal on step { $step }
+round-trap = { $side ->
+        [player] You hit the opponent's
+       *[opponent] Opponent hit your
+    } trap at { $square } on step { $step }
+round-neutral-trap = { $side ->
+        [player] You
+       *[opponent] Opponent
+    } hit a neutral trap at { $square } on step { $step }
+round-collision = The pieces collided at { $square } on step { $step }
+round-bounced = { $side ->
+        [player] You
+       *[opponent] Opponent
+    } bounced back to { $square } on step { $step }
+round-teleported = { $side ->
+        [player] You
+       *[opponent] Opponent
+    } teleported to { $square } on step { $step }
+round-score = Round score: you { $player }, opponent { $opponent }.
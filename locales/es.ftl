@@ -0,0 +1,291 @@
+# Mensajes en español; los ids son los de en.ftl.
+
+## Pantalla de inicio
+
+greeting-prompt = ¡Hola! ¿Cómo te llamas?
+greeting = ¡Hola, { $name }!
+greeting-submit = Hola
+edit-profile = Editar perfil
+saved-match = Partida contra { $opponent } en curso: { $played } de { $rounds ->
+        [one] { $rounds } ronda jugada
+       *[other] { $rounds } rondas jugadas
+    }, { $playerScore }–{ $opponentScore }. Termínala o abandónala para empezar otra.
+resume = Reanudar
+abandon = Abandonar
+abandon-title = Cuenta como derrota
+opponents = Rivales
+opponent-computer = C
+opponent-human = H
+play = Jugar
+confirm-removal = Confirmar eliminación
+confirm-removal-text = ¿Seguro que quieres quitar a { $name } de tu lista de rivales?
+cancel = Cancelar
+remove = Quitar
+boards = Tableros
+create-board = + Crear tablero nuevo
+
+## Perfil
+
+username = Nombre de usuario
+default-speed = Velocidad de juego predeterminada
+board-theme = Tema del tablero
+default-rules = Reglas predeterminadas
+language = Idioma
+manage-opponents = Gestionar rivales
+add-opponent = + Añadir rival
+opponent-name = Nombre
+avatar-glyph = Letra de la insignia
+avatar-color = Color de la insignia
+color-blue = Azul
+color-green = Verde
+color-red = Rojo
+color-orange = Naranja
+color-purple = Morado
+color-teal = Verde azulado
+color-pink = Rosa
+color-slate = Pizarra
+notes = Notas
+preferred-speed = Velocidad
+speed-default = Mi velocidad predeterminada
+own-rules = Reglas propias para este rival
+rounds = Rondas:
+opponent-added = Añadido el { $date }
+save = Guardar
+forfeited = { $count ->
+        [one] { $count } abandonada
+       *[other] { $count } abandonadas
+    }
+save-changes = Guardar cambios
+
+speed-lightning = ¡Relámpago! (1 s para elegir)
+speed-quick = ¡Rápida! (5 s para elegir)
+speed-relaxed = Tranquila (10 s para elegir)
+speed-chill = Sin prisa (sin límite)
+speed-custom = Personalizada
+timing-selection = Segundos para elegir tablero (0 sin límite)
+timing-reveal = Segundos para mostrar ambos tableros
+timing-results = Segundos para mostrar el resultado de cada ronda (0 para esperarte)
+
+theme-dark = Oscuro
+theme-light = Claro
+theme-color-blind = Apto para daltonismo
+
+## Reglas, movimiento, mapas y rivales
+
+variant-classic = Clásicas
+variant-classic-description = Avanzar y llegar a la meta suman 1; las trampas y los choques restan 1.
+variant-fading-traps = Trampas pasajeras
+variant-fading-traps-description = Las trampas siguen armadas 3 pasos y luego se desactivan.
+variant-sideways = Puntos de lado
+variant-sideways-description = Los movimientos laterales también suman 1.
+variant-bumpers = Rebotes
+variant-bumpers-description = Los choques hacen retroceder las piezas en vez de terminar la ronda.
+variant-big-goal = Gran meta
+variant-big-goal-description = Llegar a la meta suma 3.
+
+movement-classic = Clásico
+movement-diagonal = Pasos en diagonal
+movement-backward = Pasos hacia atrás
+movement-jumps = Saltos de dos casillas
+movement-long-traps = Trampas a distancia 2
+movement-free = Todo vale
+
+layout-open = Abierto
+layout-open-description = Sin casillas especiales; tableros de cualquier tamaño.
+layout-pillar = Pilar
+layout-pillar-description = 3×3 con un muro en el centro.
+layout-minefield = Campo minado
+layout-minefield-description = 3×3 con una casilla de bonificación entre dos trampas neutrales.
+layout-wormhole = Agujero de gusano
+layout-wormhole-description = 3×3 con un teletransportador en cada esquina de una diagonal.
+layout-crossroads = Encrucijada
+layout-crossroads-description = 4×4 con muros, casillas de bonificación y dos pares de teletransportadores.
+
+persona-cpu-description = Juega cualquier tablero válido al azar.
+persona-trapper-description = Pone trampas lo antes posible.
+persona-sprinter-description = Corre directo a la meta.
+persona-mirror-description = Te devuelve tu último tablero.
+persona-adaptive-description = Aprende qué tableros juegas y los contrarresta.
+persona-expert-description = Mezcla sus tableros para que ninguna elección tuya pueda aprovecharse de ellos.
+
+## Creador de tableros
+
+movement = Movimiento:
+map = Mapa:
+trapped = ¡Estás atrapado! Reinicia e inténtalo de nuevo
+final-move = Movimiento final
+board-grid = Tablero. Las flechas eligen una casilla; M o Intro mueve o empieza allí, T pone una trampa, F hace el movimiento final.
+cell = Fila { $row }, columna { $column }: { $content ->
+        [piece] tu pieza
+        [trap] trampa
+        [wall] muro
+        [neutral-trap] trampa neutral
+        [bonus] casilla de bonificación
+        [teleporter] teletransportador
+       *[empty] vacía
+    }{ $actions ->
+        [start] , empezar aquí
+        [move-trap] , mover o poner trampa
+        [move] , mover
+        [trap] , poner trampa
+       *[none] { "" }
+    }
+start = Inicio
+move = Mover
+trap = Trampa
+choose-start = Elige una casilla de salida
+board-complete = ¡Tablero completo!
+creator-help = Elige una casilla resaltada para mover tu pieza o poner una trampa, o usa las flechas con M, T y F.
+board-preview = Vista previa del tablero
+
+## Tableros guardados y mazos
+
+favorite = Favorito
+decks = Mazos
+deck-summary = { $count ->
+        [one] { $count } tablero
+       *[other] { $count } tableros
+    } · { $rule }
+edit = Editar
+delete = Borrar
+new-deck = + Mazo nuevo
+deck-draft-help = Haz clic en los tableros de arriba para añadirlos o quitarlos ({ $count } en el mazo).
+deck-name = Nombre del mazo
+save-deck = Guardar mazo
+deck-rule-open = Todos los tableros, cada ronda
+deck-rule-single-use = Cada tablero una vez por partida
+deck-rule-hand = Mano de { $size } en cada ronda
+deck-error-no-playable = ninguno de los tableros del mazo se puede jugar en esta partida
+deck-error-too-few = el mazo tiene { $playable } tableros jugables para una partida de { $rounds } rondas
+
+## Partida
+
+board-chooser = Tus tableros. Pulsa del 1 al 9 para elegir uno.
+board-choice = Tablero { $number }: { $description }
+board-choice-hinted = Tablero { $number }, { $points } puntos previstos: { $description }
+hint-title = Puntos que se espera que consiga este tablero contra los tableros que ha jugado tu rival
+hint-points = ~{ $points } pts
+rematch-waiting = Esperando la respuesta de { $name }…
+rematch-wanted = ¡{ $name } quiere volver a jugar!
+play-again = Jugar otra vez
+decline-rematch = Naa
+match-over-announcement = Fin de la partida. Resultado final: tú { $player }, rival { $opponent }.
+time-up = Se acabó el tiempo.
+time-left-announcement = { $seconds ->
+        [one] Queda { $seconds } segundo
+       *[other] Quedan { $seconds } segundos
+    } para elegir un tablero.
+round-of = Ronda { $round } de { $rounds }
+forfeit = Rendirse
+keep-playing = Seguir jugando
+exit-game = Salir de la partida
+exit-game-title = Las partidas en curso se guardan y se pueden reanudar desde la pantalla de inicio
+rules = Reglas:
+ranked = Clasificatoria
+ranked-title = Las partidas clasificatorias se juegan sin pistas
+deck = Mazo:
+all-boards = Todos los tableros
+hints = Pistas
+hints-title = Ordena tus tableros por los puntos que pueden conseguir contra los tableros de tu rival
+select-board = Elige tu tablero
+seconds-left = { $seconds ->
+        [one] ¡Queda { $seconds } segundo!
+       *[other] ¡Quedan { $seconds } segundos!
+    }
+waiting-for-board = Esperando a que { $name } elija un tablero…
+hand-over = Pasa el dispositivo a { $name }; tu tablero sigue oculto.
+take-turn = Soy { $name }, mostrar mis tableros
+choose-as = { $name }, elige tu tablero
+save-png = Guardar PNG
+save-gif = Guardar GIF de la repetición
+round-points = { $name } (ronda):
+next-board = Elige tu siguiente tablero
+see-final-score = Ver el resultado final
+pause = Pausar
+pause-title = Detén el reloj hasta que reanudes
+paused = En pausa
+next-round-in = { $seconds ->
+        [one] Siguiente ronda en { $seconds } segundo
+       *[other] Siguiente ronda en { $seconds } segundos
+    }
+final-score-in = { $seconds ->
+        [one] Resultado final en { $seconds } segundo
+       *[other] Resultado final en { $seconds } segundos
+    }
+rematch-declined = { $name } no quiere volver a jugar.
+back-to-main = Volver a la pantalla principal
+
+## Instalación y actualizaciones
+
+install = Instalar
+install-title = Añade el juego a tu pantalla de inicio o a tus aplicaciones; funciona sin conexión
+update-ready = Hay una versión nueva del juego.
+update-reload = Recargar
+update-later = Más tarde
+
+## Descripciones para lectores de pantalla
+
+describe-empty = Tablero vacío
+describe-start = Empezar en { $square }
+describe-move = { $direction } hasta { $square } en el paso { $step }
+describe-teleport = { $direction } hasta { $square } en el paso { $step } y teletransportarse hasta { $exit }
+describe-trap = trampa en { $square } en el paso { $step }
+describe-goal = llegar a la meta en el paso { $step }
+describe-direction = { $verb ->
+        [jump] saltar
+       *[move] mover
+    } { $way ->
+        [up] hacia arriba
+        [down] hacia abajo
+        [left] a la izquierda
+        [right] a la derecha
+        [up-left] arriba a la izquierda
+        [up-right] arriba a la derecha
+        [down-left] abajo a la izquierda
+        [down-right] abajo a la derecha
+       *[none] sin moverse
+    }
+
+square = la fila { $row }, columna { $column }
+square-in-row = la fila { $row ->
+        [top] superior
+        [middle] central
+       *[bottom] inferior
+    }, columna { $column }
+square-in-column = la fila { $row }, columna { $column ->
+        [left] izquierda
+        [middle] central
+       *[right] derecha
+    }
+square-top-left = la esquina superior izquierda
+square-top-middle = el centro de la fila superior
+square-top-right = la esquina superior derecha
+square-middle-left = el centro de la columna izquierda
+square-middle-middle = el centro
+square-middle-right = el centro de la columna derecha
+square-bottom-left = la esquina inferior izquierda
+square-bottom-middle = el centro de la fila inferior
+square-bottom-right = la esquina inferior derecha
+
+round-goal = { $side ->
+        [player] Llegaste
+       *[opponent] El rival llegó
+    } a la meta en el paso { $step }
+round-trap = { $side ->
+        [player] Caíste en una trampa del rival
+       *[opponent] El rival cayó en una trampa tuya
+    } en { $square } en el paso { $step }
+round-neutral-trap = { $side ->
+        [player] Caíste
+       *[opponent] El rival cayó
+    } en una trampa neutral en { $square } en el paso { $step }
+round-collision = Las piezas chocaron en { $square } en el paso { $step }
+round-bounced = { $side ->
+        [player] Rebotaste
+       *[opponent] El rival rebotó
+    } hasta { $square } en el paso { $step }
+round-teleported = { $side ->
+        [player] Te teletransportaste
+       *[opponent] El rival se teletransportó
+    } hasta { $square } en el paso { $step }
+round-score = Puntos de la ronda: tú { $player }, rival { $opponent }.
@@ -0,0 +1,39 @@
+// Trunk post-build hook: writes sw.js into the build from the template next
+// to this file, precaching every file of the build under a version that
+// changes whenever any of them does.
+
+import { createHash } from "node:crypto";
+import { readdirSync, readFileSync, writeFileSync } from "node:fs";
+import { dirname, join, relative, sep } from "node:path";
+import { fileURLToPath } from "node:url";
+
+const staging = process.env.TRUNK_STAGING_DIR;
+if (!staging) {
+  console.error("TRUNK_STAGING_DIR is not set; this runs as a hook of `trunk build`");
+  process.exit(1);
+}
+
+const files = (dir) =>
+  readdirSync(dir, { withFileTypes: true }).flatMap((entry) =>
+    entry.isDirectory() ? files(join(dir, entry.name)) : [join(dir, entry.name)],
+  );
+
+const built = files(staging)
+  .map((file) => relative(staging, file).split(sep).join("/"))
+  .filter((file) => file !== "sw.js")
+  .sort();
+
+const hash = createHash("sha256");
+for (const file of built) {
+  hash.update(file);
+  hash.update(readFileSync(join(staging, file)));
+}
+const version = hash.digest("hex").slice(0, 16);
+const precache = ["./", ...built.map((file) => `./${file}`)];
+
+const template = readFileSync(join(dirname(fileURLToPath(import.meta.url)), "sw.js"), "utf8");
+writeFileSync(
+  join(staging, "sw.js"),
+  template.replace("__VERSION__", version).replace("__PRECACHE__", JSON.stringify(precache, null, 2)),
+);
+console.log(`sw.js: version ${version}, ${precache.length} files precached`);
@@ -0,0 +1,50 @@
+// The service worker that keeps the game playable offline. This is a
+// template: the post-build hook in build-service-worker.mjs fills in the
+// build's files and a version that changes with them, and writes the result
+// next to index.html.
+
+const VERSION = "__VERSION__";
+const PRECACHE = __PRECACHE__;
+const CACHE = `spaces-game-${VERSION}`;
+
+self.addEventListener("install", (event) => {
+  event.waitUntil(caches.open(CACHE).then((cache) => cache.addAll(PRECACHE)));
+});
+
+// Older builds' caches go once this one takes over
+self.addEventListener("activate", (event) => {
+  event.waitUntil(
+    caches
+      .keys()
+      .then((keys) =>
+        Promise.all(
+          keys
+            .filter((key) => key.startsWith("spaces-game-") && key !== CACHE)
+            .map((key) => caches.delete(key)),
+        ),
+      )
+      .then(() => self.clients.claim()),
+  );
+});
+
+// A new build waits until the page says the player is ready to reload
+self.addEventListener("message", (event) => {
+  if (event.data === "skip-waiting") {
+    self.skipWaiting();
+  }
+});
+
+// Everything the game needs is in the cache; pages are the cached
+// index.html, and anything else falls through to the network
+self.addEventListener("fetch", (event) => {
+  const request = event.request;
+  if (request.method !== "GET" || new URL(request.url).origin !== self.location.origin) {
+    return;
+  }
+  event.respondWith(
+    caches.open(CACHE).then(async (cache) => {
+      const key = request.mode === "navigate" ? "./index.html" : request;
+      return (await cache.match(key, { ignoreSearch: true })) || fetch(request);
+    }),
+  );
+});
@@ -2,71 +2,47 @@ use std::time::Duration;
 
 use leptos::*;
 use leptos::prelude::*;
-use serde::{Serialize, Deserialize};
 
-use crate::components::utils::{generate_thumbnail, save_board};
-use crate::components::saved_boards::get_board_trigger;
-
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
-pub enum CellContent {
-    Empty,
-    Player,
-    Trap,
-    Final,
-}
+pub use spaces_game::engine::board::{Board, CellContent, SavedBoard};
+use spaces_game::engine::board::{find_player, has_valid_moves};
+use spaces_game::engine::layout::{Layout, LayoutPreset, Tile};
+use spaces_game::engine::movement::MovementPreset;
+use spaces_game::render::describe::describe_board;
 
-#[derive(Clone, Serialize, Deserialize, PartialEq)]
-pub struct Board {
-    pub grid: Vec<Vec<CellContent>>,
-    pub size: usize,
-    pub sequence: Vec<(usize, usize, CellContent)>  // Replace moves and traps
-}
-
-impl Board {
-    pub fn new(size: usize) -> Self {
-        Board {
-            grid: vec![vec![CellContent::Empty; size]; size],
-            size,
-            sequence: Vec::new(),
-        }
-    }
-}
-
-#[derive(Clone, Serialize, Deserialize, PartialEq)]
-pub struct SavedBoard {
-    pub board: Board,
-    pub thumbnail: String,
-}
+use crate::components::utils::{generate_thumbnail, get_locale, save_board, t, t_with};
+use crate::components::saved_boards::get_board_trigger;
 
 fn reset_board(
     board: &RwSignal<Board>,
     current_turn: &RwSignal<usize>,
     finished: &RwSignal<bool>
 ) {
-    board.set(Board::new(2));
+    board.set(Board::new(board.get_untracked().size));
     current_turn.set(0);
     finished.set(false);
 }
 
-fn has_valid_moves(board: &Board) -> bool {
-    if let Some((player_row, player_col)) = find_player(board) {
-        // First check if player is in top row - they always have the final move available
-        if player_row == 0 {
-            return true;
-        }
-        
-        // Check available moves in adjacent squares
-        for i in 0..board.size {
-            for j in 0..board.size {
-                if matches!(board.grid[i][j], CellContent::Empty) 
-                    && is_adjacent(player_row, player_col, i, j) 
-                    && i <= player_row {
-                    return true;
-                }
-            }
-        }
+/// Plays the piece's next step from `from`: a move, coming out of the other
+/// end of a teleporter, or a trap.
+fn play_step(board: &mut Board, layout: &Layout, from: (usize, usize), (row, col): (usize, usize), content: CellContent) {
+    if content == CellContent::Player {
+        let (to_row, to_col) = layout.partner(row, col).unwrap_or((row, col));
+        board.grid[from.0][from.1] = CellContent::Empty;
+        board.grid[to_row][to_col] = CellContent::Player;
+    } else {
+        board.grid[row][col] = content.clone();
+    }
+    board.sequence.push((row, col, content));
+}
+
+fn tile_symbol(tile: Tile) -> String {
+    match tile {
+        Tile::Open => " ".to_string(),
+        Tile::Wall => "▦".to_string(),
+        Tile::NeutralTrap => "☒".to_string(),
+        Tile::Bonus => "★".to_string(),
+        Tile::Teleporter(letter) => letter.to_ascii_uppercase().to_string(),
     }
-    false
 }
 
 #[component]
@@ -76,10 +52,17 @@ pub fn BoardCreator(
     let board = RwSignal::new(Board::new(2));
     let current_turn = RwSignal::new(0);
     let finished = RwSignal::new(false);
+    let movement = RwSignal::new(MovementPreset::Classic);
+    let layout = RwSignal::new(LayoutPreset::Open);
+    // Open boards keep the classic 2×2 grid
+    let map = move || layout.get().layout().unwrap_or_else(|| Layout::open(2));
 
     let handle_cell_click = move |row: usize, col: usize| {
         let mut current_board = board.get();
         if current_turn.get() == 0 && row == current_board.size - 1 {
+            if !matches!(map().tile(row, col), Tile::Open | Tile::Bonus) {
+                return;
+            }
             // Initial move logic remains the same
             current_board.grid[row][col] = CellContent::Player;
             current_board.sequence.push((row, col, CellContent::Player));
@@ -89,24 +72,24 @@ pub fn BoardCreator(
             let player_pos = find_player(&current_board);
             if let Some((player_row, player_col)) = player_pos {
                 // Modified this condition to allow final move after placing trap
-                if row == usize::MAX || is_adjacent(player_row, player_col, row, col) {
+                let from = (player_row, player_col);
+                if row == usize::MAX
+                    || current_board.can_place(&map(), &movement.get().movement(), from, (row, col), &CellContent::Player)
+                {
                     if row == usize::MAX {  // Final move logic
                         current_board.sequence.push((0, player_col, CellContent::Final));
                         current_board.grid[player_row][player_col] = CellContent::Empty;
                         board.set(current_board.clone());  // Clone here before it's moved
                         
                         finished.set(true);
-                        let _ = save_board(current_board);  // Original is moved here
+                        let _ = save_board(current_board, layout.get().layout());  // Original is moved here
                         get_board_trigger().update(|v| *v = !*v);
                         
                         set_timeout(move || {
                             reset_board(&board, &current_turn, &finished);
                         }, Duration::from_millis(333));
                     } else {
-                        // Regular move logic remains the same
-                        current_board.grid[player_row][player_col] = CellContent::Empty;
-                        current_board.grid[row][col] = CellContent::Player;
-                        current_board.sequence.push((row, col, CellContent::Player));
+                        play_step(&mut current_board, &map(), from, (row, col), CellContent::Player);
                         board.set(current_board);
                     }
                     current_turn.update(|t| *t += 1);
@@ -115,31 +98,120 @@ pub fn BoardCreator(
         }
     };
 
-    let rows = move || (0..2).collect::<Vec<_>>();
-    let cols = move || (0..2).collect::<Vec<_>>();
+    // Traps the square at (row, col), if a trap can go there
+    let place_trap = move |row: usize, col: usize| {
+        let mut current_board = board.get();
+        if finished.get() {
+            return;
+        }
+        if let Some(from) = find_player(&current_board) {
+            if current_board.can_place(&map(), &movement.get().movement(), from, (row, col), &CellContent::Trap) {
+                play_step(&mut current_board, &map(), from, (row, col), CellContent::Trap);
+                board.set(current_board);
+                current_turn.update(|t| *t += 1);
+            }
+        }
+    };
+
+    // The square the keyboard acts on; it goes back to the piece after every
+    // step, so one arrow press targets the square next to it
+    let cursor = RwSignal::new((1, 0));
+    let grid_focused = RwSignal::new(false);
+    Effect::new(move |_| {
+        let current_board = board.get();
+        let size = current_board.size;
+        cursor.set(find_player(&current_board).unwrap_or((size - 1, 0)));
+    });
+
+    let handle_key = move |ev: ev::KeyboardEvent| {
+        let size = board.get_untracked().size;
+        let (row, col) = cursor.get_untracked();
+        match ev.key().as_str() {
+            "ArrowUp" => cursor.set((row.saturating_sub(1), col)),
+            "ArrowDown" => cursor.set(((row + 1).min(size - 1), col)),
+            "ArrowLeft" => cursor.set((row, col.saturating_sub(1))),
+            "ArrowRight" => cursor.set((row, (col + 1).min(size - 1))),
+            "m" | "M" | "Enter" | " " => handle_cell_click(row, col),
+            "t" | "T" => place_trap(row, col),
+            "f" | "F" => {
+                if matches!(find_player(&board.get_untracked()), Some((0, _))) {
+                    handle_cell_click(usize::MAX, 0);
+                }
+            }
+            _ => return,
+        }
+        ev.prevent_default();
+    };
+
+    let rows = move || (0..board.get().size).collect::<Vec<_>>();
+    let cols = move || (0..board.get().size).collect::<Vec<_>>();
 
     view! {
         <div class="flex flex-col gap-4">
+            <label class="flex items-center gap-2 text-sm text-gray-300">
+                {move || t("movement")}
+                <select
+                    class="bg-slate-700 rounded px-2 py-1"
+                    disabled=move || current_turn.get() != 0
+                    on:change=move |ev| {
+                        let key = event_target_value(&ev);
+                        if let Some(preset) = MovementPreset::ALL.into_iter().find(|p| p.key() == key) {
+                            movement.set(preset);
+                        }
+                    }
+                >
+                    {MovementPreset::ALL.into_iter().map(|preset| view! {
+                        <option value=preset.key() selected=move || movement.get() == preset>
+                            {move || t(&format!("movement-{}", preset.key()))}
+                        </option>
+                    }).collect_view()}
+                </select>
+            </label>
+            <label class="flex items-center gap-2 text-sm text-gray-300">
+                {move || t("map")}
+                <select
+                    class="bg-slate-700 rounded px-2 py-1"
+                    disabled=move || current_turn.get() != 0
+                    on:change=move |ev| {
+                        let key = event_target_value(&ev);
+                        if let Some(preset) = LayoutPreset::ALL.into_iter().find(|p| p.key() == key) {
+                            layout.set(preset);
+                            board.set(Board::new(map().size()));
+                        }
+                    }
+                >
+                    {LayoutPreset::ALL.into_iter().map(|preset| view! {
+                        <option
+                            value=preset.key()
+                            title=move || t(&format!("layout-{}-description", preset.key()))
+                            selected=move || layout.get() == preset
+                        >
+                            {move || t(&format!("layout-{}", preset.key()))}
+                        </option>
+                    }).collect_view()}
+                </select>
+            </label>
             {move || {
                 let player_pos = find_player(&board.get());
                 if let Some((row, _col)) = player_pos {
-                    let has_valid_moves = has_valid_moves(&board.get());
+                    let has_valid_moves = has_valid_moves(&board.get(), &movement.get().movement(), &map());
                     if !has_valid_moves {
                         view! {
                             <button
                                 class="w-full h-8 bg-red-600 hover:bg-red-700 rounded mb-2"
                                 on:click=move |_| reset_board(&board, &current_turn, &finished)
                             >
-                                "You're trapped! Reset and try again"
+                                {t("trapped")}
                             </button>
                         }.into_any()
                     } else if row == 0 {
                         view! {
                             <button
                                 class="w-full h-8 bg-green-600 hover:bg-green-700 rounded mb-2"
+                                aria-keyshortcuts="F"
                                 on:click=move |_| handle_cell_click(usize::MAX, 0)
                             >
-                                "Final Move"
+                                {t("final-move")}
                             </button>
                         }.into_any()
                     } else {
@@ -149,84 +221,148 @@ pub fn BoardCreator(
                     view! { <div class="h-8 mb-2"></div> }.into_any()
                 }
             }}        
-            <div class="grid grid-cols-2 gap-1 bg-slate-800 p-2 rounded">
+            <div
+                role="grid"
+                tabindex="0"
+                aria-label=move || t("board-grid")
+                aria-keyshortcuts="ArrowUp ArrowDown ArrowLeft ArrowRight M T F Enter"
+                class="grid gap-1 bg-slate-800 p-2 rounded w-fit focus:outline-none focus-visible:ring-2 focus-visible:ring-blue-400"
+                style=move || format!("grid-template-columns: repeat({}, minmax(0, 1fr))", board.get().size)
+                on:keydown=handle_key
+                on:focus=move |_| grid_focused.set(true)
+                on:blur=move |_| grid_focused.set(false)
+            >
                 <For
                     each=rows
                     key=|row| *row
                     children=move |row| {
                         view! {
+                            // The cells are laid out by the parent grid
+                            <div role="row" class="contents">
                             <For
                                 each=cols
                                 key=|col| *col
-                                children=move |col| {                                    
+                                children=move |col| {
+                                    // What can be done on the square right now
+                                    let actions = move || {
+                                        let current_board = board.get();
+                                        let tile = map().tile(row, col);
+                                        if current_turn.get() == 0 {
+                                            let can_start = row == current_board.size - 1 && matches!(tile, Tile::Open | Tile::Bonus);
+                                            return (can_start, false, false);
+                                        }
+                                        let (tiles, movement) = (map(), movement.get().movement());
+                                        match find_player(&current_board) {
+                                            Some(from) if !finished.get() => (
+                                                false,
+                                                current_board.can_place(&tiles, &movement, from, (row, col), &CellContent::Player),
+                                                current_board.can_place(&tiles, &movement, from, (row, col), &CellContent::Trap),
+                                            ),
+                                            _ => (false, false, false),
+                                        }
+                                    };
+                                    let targeted = move || cursor.get() == (row, col);
+                                    let describe = move || {
+                                        let current_board = board.get();
+                                        let content = match current_board.grid[row][col] {
+                                            CellContent::Player => "piece",
+                                            CellContent::Trap => "trap",
+                                            _ => match map().tile(row, col) {
+                                                Tile::Wall => "wall",
+                                                Tile::NeutralTrap => "neutral-trap",
+                                                Tile::Bonus => "bonus",
+                                                Tile::Teleporter(_) => "teleporter",
+                                                Tile::Open => "empty",
+                                            },
+                                        };
+                                        let options = match actions() {
+                                            (true, _, _) => "start",
+                                            (_, true, true) => "move-trap",
+                                            (_, true, false) => "move",
+                                            (_, false, true) => "trap",
+                                            _ => "none",
+                                        };
+                                        t_with("cell", &[
+                                            ("row", (row + 1).into()),
+                                            ("column", (col + 1).into()),
+                                            ("content", content.into()),
+                                            ("actions", options.into()),
+                                        ])
+                                    };
                                     view! {
-                                        <button
-                                        class="w-16 h-16 flex items-center justify-center bg-slate-700 hover:bg-slate-600 text-2xl"
-                                        disabled=move || {
-                                            if current_turn.get() == 0 {
-                                                row != board.get().size - 1
-                                            } else {
-                                                let player_pos = find_player(&board.get());
-                                                !matches!(player_pos, Some((p_row, p_col)) if is_adjacent(p_row, p_col, row, col))
-                                            }
+                                        <div
+                                        role="gridcell"
+                                        aria-label=describe
+                                        aria-selected=move || targeted().to_string()
+                                        aria-disabled=move || (actions() == (false, false, false)).to_string()
+                                        class="w-16 h-16 flex items-center justify-center bg-slate-700 text-2xl rounded-sm"
+                                        class=("hover:bg-slate-600", move || actions() != (false, false, false))
+                                        class=("cursor-pointer", move || actions() != (false, false, false))
+                                        class=("opacity-50", move || actions() == (false, false, false))
+                                        class=("ring-2", move || targeted() && grid_focused.get())
+                                        class=("ring-yellow-400", move || targeted() && grid_focused.get())
+                                        on:click=move |_| {
+                                            cursor.set((row, col));
+                                            handle_cell_click(row, col);
                                         }
-                                        on:click=move |_| handle_cell_click(row, col)
                                     >
                                     {move || {
-                                        if current_turn.get() == 0 && row == board.get().size - 1 {
-                                            view! { <span>"Start"</span> }.into_any()
-                                        } else if let Some((p_row, p_col)) = find_player(&board.get()) {
-                                            match board.get().grid[row][col] {
-                                                CellContent::Empty if is_adjacent(p_row, p_col, row, col) && row <= p_row => 
+                                        let current_board = board.get();
+                                        let tile = map().tile(row, col);
+                                        let (can_start, can_move, can_trap) = actions();
+                                        if can_start {
+                                            view! { <span>{t("start")}</span> }.into_any()
+                                        } else {
+                                            match current_board.grid[row][col] {
+                                                CellContent::Empty if can_move || can_trap =>
                                                 view! {
                                                     <div class="flex flex-col gap-1">
+                                                        // The keyboard uses M and T instead
                                                         <button class="px-2 py-1 bg-blue-600 rounded text-sm"
+                                                            tabindex="-1"
+                                                            aria-keyshortcuts="M"
+                                                            class:hidden=!can_move
                                                             on:click=move |ev| {
                                                                 ev.stop_propagation();
-                                                                let mut current_board = board.get();
-                                                                current_board.grid[row][col] = CellContent::Player;
-                                                                current_board.grid[p_row][p_col] = CellContent::Empty;
-                                                                current_board.sequence.push((row, col, CellContent::Player));
-                                                                board.set(current_board);
-                                                                current_turn.update(|t| *t += 1);
+                                                                cursor.set((row, col));
+                                                                handle_cell_click(row, col);
                                                             }
-                                                        >"Move"</button>
+                                                        >{t("move")}</button>
                                                         <button class="px-2 py-1 bg-red-600 rounded text-sm"
+                                                            tabindex="-1"
+                                                            aria-keyshortcuts="T"
+                                                            class:hidden=!can_trap
                                                             on:click=move |ev| {
                                                                 ev.stop_propagation();
-                                                                let mut current_board = board.get();
-                                                                current_board.grid[row][col] = CellContent::Trap;
-                                                                current_board.sequence.push((row, col, CellContent::Trap));
-                                                                board.set(current_board);
-                                                                current_turn.update(|t| *t += 1);
+                                                                cursor.set((row, col));
+                                                                place_trap(row, col);
                                                             }
-                                                        >"Trap"</button>
+                                                        >{t("trap")}</button>
                                                     </div>
                                                 }.into_any(),
                                                     CellContent::Final => view! { <span>" "</span> }.into_any(),
-                                                    CellContent::Empty => view! { <span>" "</span> }.into_any(),
-                                                    CellContent::Player => view! { <span>"○"</span> }.into_any(),
-                                                    CellContent::Trap => view! { <span>"×"</span> }.into_any(),
+                                                    CellContent::Empty => view! { <span aria-hidden="true">{tile_symbol(tile)}</span> }.into_any(),
+                                                    CellContent::Player => view! { <span aria-hidden="true">"○"</span> }.into_any(),
+                                                    CellContent::Trap => view! { <span aria-hidden="true">"×"</span> }.into_any(),
                                             }
-                                        } else {
-                                            view! { <span>" "</span> }.into_any()
                                         }
                                     }}
-                                    </button>
+                                    </div>
                                     }
                                 }
                             />
+                            </div>
                         }
                     }
                 />
             </div>
-            <div class="text-gray-300">
+            <div class="text-gray-300" aria-live="polite">
                 {move || if current_turn.get() == 0 {
-                    "Choose a starting square"
+                    t("choose-start")
                 } else if finished.get() {
-                    "Board complete!"
+                    t("board-complete")
                 } else {
-                    "Select an adjascent square to move your piece or place a trap."
+                    t("creator-help")
                 }}
             </div>
             <div class="flex gap-2">
@@ -234,15 +370,15 @@ pub fn BoardCreator(
                     class="px-4 py-2 bg-gray-600 rounded hover:bg-gray-700"
                     on:click=move |_| on_cancel.run(())
                 >
-                    "Cancel"
+                    {move || t("cancel")}
                 </button>
             </div>
             {move || finished.get().then(|| view! {
                 <div class="mt-4">
-                    <h3 class="text-lg font-bold mb-2">"Board Preview"</h3>
+                    <h3 class="text-lg font-bold mb-2">{t("board-preview")}</h3>
                     <img 
-                        src=move || generate_thumbnail(&board.get())
-                        alt="Board thumbnail" 
+                        src=move || generate_thumbnail(&board.get(), layout.get().layout().as_ref())
+                        alt=move || describe_board(&board.get(), layout.get().layout().as_ref(), get_locale().get())
                         class="w-24 h-24 rounded border border-slate-700"
                     />
                 </div>
@@ -250,20 +386,3 @@ pub fn BoardCreator(
         </div>
     }
 }
-
-fn find_player(board: &Board) -> Option<(usize, usize)> {
-    for i in 0..board.size {
-        for j in 0..board.size {
-            if matches!(board.grid[i][j], CellContent::Player) {
-                return Some((i, j));
-            }
-        }
-    }
-    None
-}
-
-fn is_adjacent(x1: usize, y1: usize, x2: usize, y2: usize) -> bool {
-    let dx = if x1 >= x2 { x1 - x2 } else { x2 - x1 };
-    let dy = if y1 >= y2 { y1 - y2 } else { y2 - y1 };
-    (dx == 1 && dy == 0) || (dx == 0 && dy == 1)
-}
\ No newline at end of file
@@ -1,411 +1,1058 @@
 use leptos::*;
 use leptos::prelude::*;
 use leptos::callback::Callback;
-use crate::update_opponent_stats;
+use leptos::wasm_bindgen::JsCast;
+use crate::{opponent_record, record_forfeit, update_opponent_stats};
 use crate::components::opponent::OpponentType;
-use crate::components::utils::{generate_thumbnail, generate_opponent_thumbnail};
+use crate::components::utils::{
+    clear_match, download_round_png, download_round_replay, expert_book, get_locale, load_decks,
+    generate_opponent_thumbnail, generate_round_image, generate_thumbnail, load_opponent_model, load_player_model,
+    save_match, save_opponent_model, save_player_model, t, t_with
+};
+use crate::components::saved_boards::rule_label;
 
-use super::board::SavedBoard;
+use super::board::{Board, SavedBoard};
 use super::opponent::Opponent;
-use super::game_board::GameBoard;
+use spaces_game::engine::deck::{Deck, DeckError};
+use spaces_game::engine::game_board::Side;
+use spaces_game::engine::layout::LayoutPreset;
+use spaces_game::engine::match_state::{MatchConfig, MatchEvent, MatchPhase, MatchState};
+use spaces_game::engine::movement::MovementPreset;
+use spaces_game::engine::rules::Variant;
+pub use spaces_game::engine::timing::GameSpeed;
+use spaces_game::render::describe::{describe_board, describe_opponent_board, describe_round};
 use serde::{Serialize, Deserialize};
+use std::sync::Arc;
 use std::time::Duration;
 use super::utils::load_saved_boards;
-use rand; 
+use rand;
 
-#[derive(Clone, Serialize, Deserialize, PartialEq)]
-pub enum GamePhase {
-    SelectingBoards,
-    ShowingResults,
-}
-
-#[derive(Clone, Serialize, Deserialize, PartialEq)]
-pub enum GameSpeed {
-    Lightning, 
-    Quick,    
-    Relaxed,  
-    Chill,    
-}
-
-#[derive(Clone)]
+/// A match in progress. It is saved to storage after every round, so it can
+/// be resumed after a reload.
+#[derive(Clone, Serialize, Deserialize)]
 pub struct GameState {
     pub player1: String,
     pub player2: Option<Opponent>,
-    pub current_round: usize,
-    pub player1_score: i32,
-    pub player2_score: i32,
-    pub player1_board: Option<SavedBoard>,
-    pub player2_board: Option<SavedBoard>,
-    pub game_board: Option<GameBoard>,  // Add this
-    pub phase: GamePhase,
     pub speed: GameSpeed,
+    /// Where the match stands; only changed through `MatchState::apply`.
+    pub match_state: MatchState,
+    /// The deck the player picked in the lobby; every saved board if none.
+    #[serde(default)]
+    pub deck: Option<Deck>,
+    /// The deck's boards on offer for the next round.
+    #[serde(default)]
+    pub hand: Vec<Board>,
+    /// The CPU's boards, with the rules and board size they were built for.
+    #[serde(skip)]
+    cpu_library: Option<(MatchConfig, usize, Arc<Vec<Board>>)>,
 }
 
 impl GameState {
-    pub fn new(player_name: String, opponent: Opponent) -> Self {
+    pub fn new(player_name: String, opponent: Opponent, speed: GameSpeed, config: MatchConfig) -> Self {
         GameState {
             player1: player_name,
             player2: Some(opponent),
-            current_round: 1,
-            player1_score: 0,
-            player2_score: 0,
-            player1_board: None,
-            player2_board: None,
-            game_board: None,  // Add this
-            phase: GamePhase::SelectingBoards,
-            speed: GameSpeed::Relaxed,
+            speed,
+            match_state: MatchState::new(config),
+            deck: None,
+            hand: Vec::new(),
+            cpu_library: None,
+        }
+    }
+
+    /// The boards a CPU opponent plays `size` boards from in this match,
+    /// built the first time they are needed and again only if the rules or
+    /// the size change; `None` against a human.
+    fn cpu_library(&mut self, size: usize) -> Option<Arc<Vec<Board>>> {
+        let opponent = self.player2.as_ref().filter(|o| o.opponent_type == OpponentType::Computer)?;
+        let config = *self.match_state.config();
+        match &self.cpu_library {
+            Some((built_for, built_size, library)) if *built_for == config && *built_size == size => Some(library.clone()),
+            _ => {
+                let library = Arc::new(opponent.persona.library(&config, size));
+                self.cpu_library = Some((config, size, library.clone()));
+                Some(library)
+            }
+        }
+    }
+
+    /// Deals the deck's boards for the next round, after those played so far.
+    fn deal(&mut self) {
+        let played: Vec<Board> = self.match_state.rounds().iter().map(|round| round.player.clone()).collect();
+        self.hand = match &self.deck {
+            Some(deck) => deck.deal(self.match_state.config(), &played, &mut rand::thread_rng()),
+            None => Vec::new(),
+        };
+    }
+
+    /// Whether the player may play `board` next round under the deck's rule.
+    fn may_play(&self, board: &Board) -> bool {
+        self.deck.is_none() || self.hand.contains(board)
+    }
+
+    /// Restores the round on show in a match loaded from storage.
+    pub fn resumed(mut self) -> Self {
+        self.match_state = self.match_state.resumed();
+        self
+    }
+
+    pub fn is_over(&self) -> bool {
+        self.match_state.is_over()
+    }
+
+    fn opponent_name(&self) -> String {
+        self.player2.as_ref().map(|p| p.name.clone()).unwrap_or_default()
+    }
+}
+
+fn is_computer(state: &GameState) -> bool {
+    matches!(state.player2.as_ref().map(|o| &o.opponent_type), Some(OpponentType::Computer))
+}
+
+/// What the game needs to act on a change of phase.
+#[derive(Clone, Copy)]
+struct MatchHandle {
+    game_state: RwSignal<GameState>,
+    set_timer: WriteSignal<i32>,
+    /// Seconds until the match moves on by itself, while it will.
+    advance_in: RwSignal<Option<u32>>,
+    /// Whether the player has stopped the clock.
+    paused: RwSignal<bool>,
+    on_stats_update: Callback<()>,
+}
+
+impl MatchHandle {
+    /// Applies `event` to the match and starts whatever the new phase needs:
+    /// the choosing timer, the countdown to the end of the reveal or of the
+    /// round's result, saving the match or the CPU's answer to a rematch.
+    fn dispatch(self, event: MatchEvent) {
+        let game_state = self.game_state;
+        // The game may have been closed while a timeout was pending
+        let Some(result) = game_state.try_update(|state| state.match_state.apply(event)) else {
+            return;
+        };
+        if let Err(e) = result {
+            web_sys::console::log_1(&format!("Ignored match event: {}", e).into());
+            return;
+        }
+        // A new hand for each round, dealt as soon as its board can be chosen
+        let phase = game_state.with_untracked(|state| state.match_state.phase());
+        let next_round = game_state.with_untracked(|state| !state.match_state.is_last_round());
+        if phase == MatchPhase::Lobby || (phase == MatchPhase::RoundSummary && next_round) {
+            game_state.update(GameState::deal);
+        }
+        let state = game_state.get_untracked();
+        let timing = state.speed.timing();
+        match state.match_state.phase() {
+            MatchPhase::Lobby | MatchPhase::Selecting => self.set_timer.set(timing.selection.unwrap_or(0) as i32),
+            MatchPhase::Revealing => {
+                if let Some(game_board) = state.match_state.game_board() {
+                    for event in &game_board.events {
+                        web_sys::console::log_1(&event.to_string().into());
+                    }
+                }
+            }
+            MatchPhase::RoundSummary => {
+                // Every round teaches the CPUs a little more about the player,
+                // and the hints about the opponent
+                if let Some(round) = state.match_state.rounds().last() {
+                    let mut model = load_player_model(&state.player1);
+                    model.observe(&round.player);
+                    save_player_model(&state.player1, &model);
+                    if let Some(opponent) = &state.player2 {
+                        let mut model = load_opponent_model(&opponent.id);
+                        model.observe(&round.opponent);
+                        save_opponent_model(&opponent.id, &model);
+                    }
+                }
+                save_match(&state);
+            }
+            // The result counts as soon as the last round is in, whatever
+            // comes of a rematch
+            MatchPhase::MatchOver => {
+                if let Some(opponent) = &state.player2 {
+                    let (player_score, opponent_score) = state.match_state.scores();
+                    let _ = update_opponent_stats(&opponent.id, player_score > opponent_score);
+                    self.on_stats_update.run(());
+                }
+                clear_match();
+            }
+            MatchPhase::RematchPending { by: Side::Player } if is_computer(&state) => {
+                // The CPU takes a moment to make up its mind
+                let answer = match &state.player2 {
+                    Some(opponent) => {
+                        let record = opponent_record(&opponent.id);
+                        opponent.persona.accepts_rematch(&state.match_state, record, &mut rand::thread_rng())
+                    }
+                    None => true,
+                };
+                let event = match answer {
+                    true => MatchEvent::RematchRequested { side: Side::Opponent },
+                    false => MatchEvent::RematchDeclined { side: Side::Opponent },
+                };
+                set_timeout(move || self.dispatch(event), Duration::from_secs(1));
+            }
+            MatchPhase::AwaitingOpponent | MatchPhase::RematchPending { .. } | MatchPhase::RematchDeclined { .. } => {}
+        }
+        self.schedule(timing.auto_advance(&state.match_state));
+    }
+
+    /// Starts counting down to `next`, the event the match moves on by
+    /// itself; one due straight away is applied now.
+    fn schedule(self, next: Option<(u32, MatchEvent)>) {
+        match next {
+            Some((0, event)) => self.dispatch(event),
+            next => self.advance_in.set(next.map(|(seconds, _)| seconds)),
+        }
+    }
+
+    /// Counts a second off the time to choose and the wait for the match to
+    /// move on, unless the clock is paused.
+    fn tick(self) {
+        // The game may have been closed since the last tick
+        if self.paused.try_get_untracked().is_none_or(|paused| paused) {
+            return;
+        }
+        let (phase, next) = self.game_state.with_untracked(|state| {
+            (state.match_state.phase(), state.speed.timing().auto_advance(&state.match_state))
+        });
+        if matches!(phase, MatchPhase::Lobby | MatchPhase::Selecting) {
+            self.set_timer.update(|t| *t = (*t - 1).max(0));
+        }
+        match self.advance_in.get_untracked() {
+            Some(seconds) if seconds > 1 => self.advance_in.set(Some(seconds - 1)),
+            Some(_) => {
+                self.advance_in.set(None);
+                if let Some((_, event)) = next {
+                    self.dispatch(event);
+                }
+            }
+            None => {}
+        }
+    }
+}
+
+/// Why a deck cannot see the match through, in the player's language.
+fn deck_problem(problem: &DeckError) -> String {
+    match *problem {
+        DeckError::NoPlayableBoards => t("deck-error-no-playable"),
+        DeckError::TooFewBoards { playable, rounds } => {
+            t_with("deck-error-too-few", &[("playable", playable.into()), ("rounds", rounds.into())])
         }
     }
 }
 
-fn select_random_board(boards: Vec<SavedBoard>) -> Option<SavedBoard> {
-    use rand::seq::SliceRandom;
-    let mut rng = rand::thread_rng();
-    boards.choose(&mut rng).cloned()
+/// How many of the best-ranked boards the hints highlight.
+const TOP_PICKS: usize = 3;
+
+/// Whether a key press is typing into a form control rather than a shortcut.
+fn typing(ev: &ev::KeyboardEvent) -> bool {
+    ev.target()
+        .and_then(|target| target.dyn_into::<web_sys::Element>().ok())
+        .is_some_and(|element| matches!(element.tag_name().as_str(), "INPUT" | "SELECT" | "TEXTAREA"))
+}
+
+/// The saved boards to pick from, each with the points it is expected to
+/// score when hints are on; hinted boards come best first. The number keys
+/// pick the first nine.
+#[component]
+fn BoardChooser(
+    #[prop(into)] boards: Signal<Vec<(SavedBoard, Option<f64>)>>,
+    #[prop(into)] on_choose: Callback<Board>,
+) -> impl IntoView {
+    let shortcuts = window_event_listener(ev::keydown, move |ev| {
+        let Some(digit) = ev.key().chars().next().and_then(|c| c.to_digit(10)).filter(|d| *d > 0) else {
+            return;
+        };
+        if ev.key().len() != 1 || ev.ctrl_key() || ev.alt_key() || ev.meta_key() || typing(&ev) {
+            return;
+        }
+        if let Some((saved, _)) = boards.get_untracked().into_iter().nth(digit as usize - 1) {
+            ev.prevent_default();
+            on_choose.run(saved.board);
+        }
+    });
+    on_cleanup(move || shortcuts.remove());
+
+    view! {
+        <div
+            role="group"
+            aria-label=move || t("board-chooser")
+            class="grid grid-cols-4 gap-4 max-w-xl mx-auto"
+        >
+            <For
+                each=move || boards.get().into_iter().enumerate()
+                key=|(rank, (board, hint))| (*rank, board.thumbnail.clone(), hint.map(f64::to_bits))
+                children=move |(rank, (board, hint)): (usize, (SavedBoard, Option<f64>))| {
+                    let (preview, preview_layout) = (board.board.clone(), board.layout.clone());
+                    let top_pick = hint.is_some() && rank < TOP_PICKS;
+                    let shortcut = (rank < 9).then(|| (rank + 1).to_string());
+                    let description = describe_board(&board.board, board.layout.as_ref(), get_locale().get());
+                    let label = match hint {
+                        Some(points) => t_with("board-choice-hinted", &[
+                            ("number", (rank + 1).into()),
+                            ("points", format!("{:.1}", points).into()),
+                            ("description", description.into()),
+                        ]),
+                        None => t_with("board-choice", &[("number", (rank + 1).into()), ("description", description.into())]),
+                    };
+                    view! {
+                        <div class="relative flex flex-col items-center gap-1">
+                            {shortcut.clone().map(|key| view! {
+                                <span
+                                    aria-hidden="true"
+                                    class="absolute -top-2 -left-2 bg-slate-600 rounded-full w-5 h-5 text-xs flex items-center justify-center"
+                                >
+                                    {key}
+                                </span>
+                            })}
+                            <button
+                                aria-label=label
+                                aria-keyshortcuts=shortcut
+                                class="w-24 h-24 rounded border transition-colors focus:outline-none focus-visible:ring-2 focus-visible:ring-blue-400"
+                                class=("border-slate-700", !top_pick)
+                                class=("hover:border-blue-500", !top_pick)
+                                class=("border-green-400", top_pick)
+                                class=("ring-2", top_pick)
+                                class=("ring-green-400", top_pick)
+                                on:click=move |_| on_choose.run(board.board.clone())
+                            >
+                                <img
+                                    src=move || generate_thumbnail(&preview, preview_layout.as_ref())
+                                    alt=""
+                                    class="w-full h-full rounded"
+                                />
+                            </button>
+                            {hint.map(|points| view! {
+                                <span
+                                    class="text-xs"
+                                    class=("text-green-400", top_pick)
+                                    class=("text-gray-400", !top_pick)
+                                    title=t("hint-title")
+                                >
+                                    {t_with("hint-points", &[("points", format!("{:.1}", points).into())])}
+                                </span>
+                            })}
+                        </div>
+                    }
+                }
+            />
+        </div>
+    }
 }
 
 #[component]
 pub fn Game(
     #[prop(into)] player_name: String,
     #[prop(into)] opponent: Opponent,
-    #[prop(into)] speed: GameSpeed,  
+    #[prop(into)] speed: GameSpeed,
+    #[prop(into)] variant: Variant,
     #[prop(into)] on_exit: Callback<()>,
     #[prop(into)] on_stats_update: Callback<()>,
+    /// A saved match to pick up instead of starting a new one.
+    #[prop(optional_no_strip)] resume: Option<GameState>,
 ) -> impl IntoView {
-    let game_state = RwSignal::new({
-        let mut state = GameState::new(player_name, opponent);
-        state.speed = speed;  
-        state
-    });
+    let game_state = RwSignal::new(resume.unwrap_or_else(|| {
+        // The rules the player keeps for this opponent, else their defaults
+        let config = opponent.config.unwrap_or(MatchConfig { variant, ..MatchConfig::default() });
+        GameState::new(player_name, opponent, speed, config)
+    }));
+    let confirming_abandon = RwSignal::new(false);
     let boards = Memo::new(|_| load_saved_boards().unwrap_or_default());
-    let (timer, set_timer) = signal(match game_state.get().speed {
-        GameSpeed::Lightning => 1,
-        GameSpeed::Quick => 5,
-        GameSpeed::Relaxed => 10,
-        GameSpeed::Chill => 999999, // Effectively no limit
-    });
-
-    // Update timer every second
-    if timer.get() > 0 {
-        set_interval(
-            move || {
-                set_timer.update(|t| *t = (*t - 1).max(0));
-            },
-            Duration::from_secs(1),
+    let decks = load_decks();
+    // Saved boards built for the match's map that can be played under its
+    // movement rules and, with a deck, are in this round's hand
+    let playable_boards = move || {
+        let (config, allowed) = game_state.with(|state| {
+            let config = *state.match_state.config();
+            let deck_ready = state.deck.as_ref().is_none_or(|deck| deck.check(&config).is_ok());
+            let allowed: Vec<bool> = boards.get().iter().map(|saved| deck_ready && state.may_play(&saved.board)).collect();
+            (config, allowed)
+        });
+        let layout = config.layout.layout();
+        boards
+            .get()
+            .into_iter()
+            .zip(allowed)
+            .filter(|(saved, allowed)| *allowed && saved.layout == layout && config.check(&saved.board).is_ok())
+            .map(|(saved, _)| saved)
+            .collect::<Vec<_>>()
+    };
+    // What a hot-seat opponent picks from: the saved boards that suit the
+    // match, whatever the player's deck holds
+    let opponent_boards = move || {
+        let config = game_state.with(|state| *state.match_state.config());
+        let layout = config.layout.layout();
+        boards
+            .get()
+            .into_iter()
+            .filter(|saved| saved.layout == layout && config.check(&saved.board).is_ok())
+            .map(|saved| (saved, None))
+            .collect::<Vec<_>>()
+    };
+    let show_hints = RwSignal::new(false);
+    // The playable boards, ranked by the points each can expect against the
+    // opponent's boards in this match and before when hints are on; never in
+    // a ranked match
+    let hinted_boards = move || {
+        let boards = playable_boards();
+        let (config, opponent_id) = game_state.with(|state| {
+            (*state.match_state.config(), state.player2.as_ref().map(|opponent| opponent.id.clone()))
+        });
+        let opponent_id = opponent_id.filter(|_| show_hints.get() && !config.ranked);
+        let Some(opponent_id) = opponent_id else {
+            return boards.into_iter().map(|saved| (saved, None)).collect::<Vec<_>>();
+        };
+        let candidates: Vec<Board> = boards.iter().map(|saved| saved.board.clone()).collect();
+        let ranked = load_opponent_model(&opponent_id).rank_replies(&config, &candidates);
+        let mut hinted: Vec<_> = ranked.iter().map(|&(index, points)| (boards[index].clone(), Some(points))).collect();
+        // Boards the opponent has never met follow, unranked
+        hinted.extend(
+            boards
+                .iter()
+                .enumerate()
+                .filter(|(index, _)| !ranked.iter().any(|(ranked_index, _)| ranked_index == index))
+                .map(|(_, saved)| (saved.clone(), None)),
         );
+        hinted
+    };
+    let timing = game_state.get_untracked().speed.timing();
+    let (timer, set_timer) = signal(timing.selection.unwrap_or(0) as i32);
+    let advance_in = RwSignal::new(None);
+    let paused = RwSignal::new(false);
+    let handle = MatchHandle { game_state, set_timer, advance_in, paused, on_stats_update };
+
+    // A resumed match picks its countdown up where it was saved
+    handle.schedule(game_state.with_untracked(|state| timing.auto_advance(&state.match_state)));
+    if let Ok(interval) = set_interval_with_handle(move || handle.tick(), Duration::from_secs(1)) {
+        on_cleanup(move || interval.clear());
     }
+
+    // The totals at the side, and the round's points waiting to be added to
+    // them while its result is on show
+    let totals = move || {
+        game_state.with(|state| {
+            let (player, opponent) = state.match_state.scores();
+            match (state.match_state.phase(), state.match_state.game_board()) {
+                (MatchPhase::RoundSummary, Some(round)) => (
+                    (player - round.player_score, opponent - round.opponent_score),
+                    Some((round.player_score, round.opponent_score)),
+                ),
+                _ => ((player, opponent), None),
+            }
+        })
+    };
+
+    let configure = move |change: &dyn Fn(&mut MatchConfig)| {
+        let mut config = *game_state.get_untracked().match_state.config();
+        change(&mut config);
+        handle.dispatch(MatchEvent::Configure(config));
+    };
+
+    // Plays the player's board, starting the match or the next round first
+    // if need be; a CPU picks its board straight after, from the rounds
+    // before this one and what it has learned about the player, and a
+    // hot-seat opponent once the device has been passed to them
+    let choose_board = move |board: Board| {
+        if !game_state.with_untracked(|state| state.may_play(&board)) {
+            return;
+        }
+        match game_state.with_untracked(|state| state.match_state.phase()) {
+            MatchPhase::Lobby => handle.dispatch(MatchEvent::Start),
+            MatchPhase::RoundSummary => handle.dispatch(MatchEvent::Continue),
+            _ => {}
+        }
+        let size = board.size;
+        handle.dispatch(MatchEvent::BoardChosen { side: Side::Player, board });
+        let library = game_state.try_update_untracked(|state| state.cpu_library(size)).flatten();
+        let state = game_state.get_untracked();
+        if let (Some(opponent), Some(library)) = (state.player2.as_ref(), library) {
+            let config = state.match_state.config();
+            let history = state.match_state.rounds();
+            let model = load_player_model(&state.player1);
+            let book = expert_book();
+            let mut rng = rand::thread_rng();
+            if let Some(cpu_board) = opponent.persona.choose(config, &library, history, &model, &book, &mut rng) {
+                handle.dispatch(MatchEvent::BoardChosen { side: Side::Opponent, board: cpu_board });
+            }
+        }
+    };
+
+    // Whether a hot-seat opponent has the device and is choosing their board
+    let opponent_choosing = RwSignal::new(false);
+    let choose_opponent_board = move |board: Board| {
+        opponent_choosing.set(false);
+        handle.dispatch(MatchEvent::BoardChosen { side: Side::Opponent, board });
+    };
+
+    // The rematch question for one side: its answer once given, or the
+    // buttons to give it
+    let rematch_answer = move |side: Side| {
+        let state = game_state.get();
+        let (name, other) = match side {
+            Side::Player => (state.player1.clone(), state.opponent_name()),
+            Side::Opponent => (state.opponent_name(), state.player1.clone()),
+        };
+        match state.match_state.phase() {
+            MatchPhase::RematchPending { by } if by == side => {
+                let text = match side {
+                    Side::Player => t_with("rematch-waiting", &[("name", other.into())]),
+                    Side::Opponent => t_with("rematch-wanted", &[("name", name.into())]),
+                };
+                view! { <div class="text-gray-300">{text}</div> }.into_any()
+            }
+            _ => view! {
+                <div class="flex items-center gap-4">
+                    {(side == Side::Opponent).then(|| view! { <span class="text-gray-300">{name}</span> })}
+                    <button
+                        class="px-4 py-2 bg-blue-600 hover:bg-blue-700 rounded"
+                        on:click=move |_| handle.dispatch(MatchEvent::RematchRequested { side })
+                    >
+                        {t("play-again")}
+                    </button>
+                    <button
+                        class="px-4 py-2 bg-gray-600 hover:bg-gray-700 rounded"
+                        on:click=move |_| {
+                            handle.dispatch(MatchEvent::RematchDeclined { side });
+                            if side == Side::Player {
+                                on_exit.run(());
+                            }
+                        }
+                    >
+                        {t("decline-rematch")}
+                    </button>
+                </div>
+            }.into_any(),
+        }
+    };
+
+    // The two boards of the round, side by side
+    let chosen_boards = move || {
+        let state = game_state.get();
+        let layout = state.match_state.config().layout.layout();
+        let player_board = state.match_state.board(Side::Player).cloned();
+        let opponent_board = state.match_state.board(Side::Opponent).cloned();
+        view! {
+            <div class="flex gap-8 items-start">
+                // Player's board
+                <div class="text-center">
+                    <h3 class="text-sm font-bold mb-2">
+                        <div class="flex items-center justify-center gap-2">
+                            {state.player1.clone()}
+                        </div>
+                    </h3>
+                    {player_board.map(|board| view! {
+                        <img
+                            src=generate_thumbnail(&board, layout.as_ref())
+                            alt=describe_board(&board, layout.as_ref(), get_locale().get())
+                            class="w-32 h-32 rounded border border-slate-700"
+                        />
+                    })}
+                </div>
+
+                // Opponent's board
+                <div class="text-center">
+                    <h3 class="text-sm font-bold mb-2">
+                        <div class="flex items-center justify-center gap-2">
+                            {state.opponent_name()}
+                        </div>
+                    </h3>
+                    {opponent_board.map(|board| view! {
+                        <img
+                            src=generate_opponent_thumbnail(&board, layout.as_ref())
+                            alt=describe_opponent_board(&board, layout.as_ref(), get_locale().get())
+                            class="w-32 h-32 rounded border border-slate-700"
+                        />
+                    })}
+                </div>
+            </div>
+        }
+    };
+
+    // What screen readers are told: each round's outcome once it has been
+    // shown, the final score, and warnings as the time to choose runs out
+    let announcement = Memo::new(move |_| {
+        let state = game_state.get();
+        let (player_score, opponent_score) = state.match_state.scores();
+        match state.match_state.phase() {
+            MatchPhase::RoundSummary => state
+                .match_state
+                .game_board()
+                .map(|game_board| describe_round(game_board, get_locale().get()))
+                .unwrap_or_default(),
+            MatchPhase::MatchOver => {
+                t_with("match-over-announcement", &[("player", player_score.into()), ("opponent", opponent_score.into())])
+            }
+            MatchPhase::Lobby | MatchPhase::Selecting if state.speed.timing().selection.is_some() => match timer.get() {
+                0 => t("time-up"),
+                seconds @ (1 | 3 | 5 | 10) => t_with("time-left-announcement", &[("seconds", seconds.into())]),
+                _ => String::new(),
+            },
+            _ => String::new(),
+        }
+    });
+
     view! {
         <div class="fixed inset-0 bg-black bg-opacity-50 flex items-center justify-center z-50">
             <div class="bg-slate-800 p-6 rounded-lg shadow-xl max-w-4xl w-full mx-4 text-white">
+                <div class="sr-only" role="status" aria-live="polite">{move || announcement.get()}</div>
                 <div class="flex justify-between items-center mb-6">
                     <h2 class="text-2xl font-bold">
-                        "Round " {move || game_state.get().current_round} " of 8"
+                        {move || game_state.with(|state| {
+                            t_with("round-of", &[
+                                ("round", state.match_state.round().into()),
+                                ("rounds", state.match_state.config().rounds.into()),
+                            ])
+                        })}
                     </h2>
-                    <button
-                        class="px-4 py-2 bg-gray-700 hover:bg-gray-600 rounded"
-                        on:click=move |_| on_exit.run(())
-                    >
-                        "Exit Game"
-                    </button>
+                    <div class="flex gap-2">
+                        {move || {
+                            let state = game_state.get();
+                            let in_progress = !state.match_state.rounds().is_empty() && !state.is_over();
+                            match (in_progress, confirming_abandon.get()) {
+                                (false, _) => view! { <span></span> }.into_any(),
+                                (true, false) => view! {
+                                    <button
+                                        class="px-4 py-2 bg-red-700 hover:bg-red-600 rounded"
+                                        on:click=move |_| confirming_abandon.set(true)
+                                    >
+                                        {t("abandon")}
+                                    </button>
+                                }.into_any(),
+                                (true, true) => view! {
+                                    <button
+                                        class="px-4 py-2 bg-red-700 hover:bg-red-600 rounded"
+                                        on:click=move |_| {
+                                            if let Some(opponent) = game_state.get_untracked().player2 {
+                                                let _ = record_forfeit(&opponent.id);
+                                            }
+                                            clear_match();
+                                            on_stats_update.run(());
+                                            on_exit.run(());
+                                        }
+                                    >
+                                        {t("forfeit")}
+                                    </button>
+                                    <button
+                                        class="px-4 py-2 bg-gray-700 hover:bg-gray-600 rounded"
+                                        on:click=move |_| confirming_abandon.set(false)
+                                    >
+                                        {t("keep-playing")}
+                                    </button>
+                                }.into_any(),
+                            }
+                        }}
+                        <button
+                            class="px-4 py-2 bg-gray-700 hover:bg-gray-600 rounded"
+                            title=move || t("exit-game-title")
+                            on:click=move |_| on_exit.run(())
+                        >
+                            {move || t("exit-game")}
+                        </button>
+                    </div>
+                </div>
+                <div class="flex justify-center items-center gap-2 text-sm text-gray-400 mb-2">
+                    {move || t("rules")}
+                    {move || {
+                        let state = game_state.get();
+                        let config = *state.match_state.config();
+                        // The rules can change until the match starts
+                        if state.match_state.phase() == MatchPhase::Lobby {
+                            view! {
+                                <select
+                                    class="px-2 py-1 rounded bg-slate-700 border border-slate-600 text-white"
+                                    on:change=move |ev| {
+                                        let value = event_target_value(&ev);
+                                        if let Some(variant) = Variant::ALL.into_iter().find(|v| v.key() == value) {
+                                            configure(&|config| config.variant = variant);
+                                        }
+                                    }
+                                >
+                                    {Variant::ALL.into_iter().map(|variant| view! {
+                                        <option
+                                            value=variant.key()
+                                            selected=config.variant == variant
+                                            title=t(&format!("variant-{}-description", variant.key()))
+                                            class="text-white bg-slate-700"
+                                        >
+                                            {t(&format!("variant-{}", variant.key()))}
+                                        </option>
+                                    }).collect_view()}
+                                </select>
+                            }.into_any()
+                        } else {
+                            view! {
+                                <span title=t(&format!("variant-{}-description", config.variant.key()))>
+                                    {t(&format!("variant-{}", config.variant.key()))}
+                                </span>
+                            }.into_any()
+                        }
+                    }}
+                    {move || t("movement")}
+                    {move || {
+                        let state = game_state.get();
+                        let config = *state.match_state.config();
+                        if state.match_state.phase() == MatchPhase::Lobby {
+                            view! {
+                                <select
+                                    class="px-2 py-1 rounded bg-slate-700 border border-slate-600 text-white"
+                                    on:change=move |ev| {
+                                        let value = event_target_value(&ev);
+                                        if let Some(preset) = MovementPreset::ALL.into_iter().find(|p| p.key() == value) {
+                                            configure(&|config| config.movement = preset);
+                                        }
+                                    }
+                                >
+                                    {MovementPreset::ALL.into_iter().map(|preset| view! {
+                                        <option
+                                            value=preset.key()
+                                            selected=config.movement == preset
+                                            class="text-white bg-slate-700"
+                                        >
+                                            {t(&format!("movement-{}", preset.key()))}
+                                        </option>
+                                    }).collect_view()}
+                                </select>
+                            }.into_any()
+                        } else {
+                            view! { <span>{t(&format!("movement-{}", config.movement.key()))}</span> }.into_any()
+                        }
+                    }}
+                    {move || t("map")}
+                    {move || {
+                        let state = game_state.get();
+                        let config = *state.match_state.config();
+                        if state.match_state.phase() == MatchPhase::Lobby {
+                            view! {
+                                <select
+                                    class="px-2 py-1 rounded bg-slate-700 border border-slate-600 text-white"
+                                    on:change=move |ev| {
+                                        let value = event_target_value(&ev);
+                                        if let Some(preset) = LayoutPreset::ALL.into_iter().find(|p| p.key() == value) {
+                                            configure(&|config| config.layout = preset);
+                                        }
+                                    }
+                                >
+                                    {LayoutPreset::ALL.into_iter().map(|preset| view! {
+                                        <option
+                                            value=preset.key()
+                                            selected=config.layout == preset
+                                            title=t(&format!("layout-{}-description", preset.key()))
+                                            class="text-white bg-slate-700"
+                                        >
+                                            {t(&format!("layout-{}", preset.key()))}
+                                        </option>
+                                    }).collect_view()}
+                                </select>
+                            }.into_any()
+                        } else {
+                            view! {
+                                <span title=t(&format!("layout-{}-description", config.layout.key()))>
+                                    {t(&format!("layout-{}", config.layout.key()))}
+                                </span>
+                            }.into_any()
+                        }
+                    }}
+                    {move || {
+                        let state = game_state.get();
+                        let ranked = state.match_state.config().ranked;
+                        if state.match_state.phase() == MatchPhase::Lobby {
+                            view! {
+                                <label class="flex items-center gap-1" title=t("ranked-title")>
+                                    <input
+                                        type="checkbox"
+                                        prop:checked=ranked
+                                        on:change=move |ev| {
+                                            let checked = event_target_checked(&ev);
+                                            configure(&|config| config.ranked = checked);
+                                        }
+                                    />
+                                    {t("ranked")}
+                                </label>
+                            }.into_any()
+                        } else {
+                            ranked.then(|| view! { <span>{t("ranked")}</span> }).into_any()
+                        }
+                    }}
+                    {move || t("deck")}
+                    {move || {
+                        let state = game_state.get();
+                        let deck = state.deck.clone();
+                        let problem = deck.as_ref().and_then(|deck| deck.check(state.match_state.config()).err());
+                        let picker = if state.match_state.phase() == MatchPhase::Lobby {
+                            let decks = decks.clone();
+                            let options = decks.clone();
+                            let selected = deck.as_ref().map(|deck| deck.name.clone()).unwrap_or_default();
+                            view! {
+                                <select
+                                    class="px-2 py-1 rounded bg-slate-700 border border-slate-600 text-white"
+                                    on:change=move |ev| {
+                                        let name = event_target_value(&ev);
+                                        let deck = decks.iter().find(|deck| deck.name == name).cloned();
+                                        game_state.update(|state| {
+                                            state.deck = deck;
+                                            state.deal();
+                                        });
+                                    }
+                                >
+                                    <option value="" selected=selected.is_empty() class="text-white bg-slate-700">
+                                        {t("all-boards")}
+                                    </option>
+                                    {options.into_iter().map(|deck| view! {
+                                        <option
+                                            value=deck.name.clone()
+                                            selected=deck.name == selected
+                                            title=rule_label(deck.rule)
+                                            class="text-white bg-slate-700"
+                                        >
+                                            {deck.name.clone()}
+                                        </option>
+                                    }).collect_view()}
+                                </select>
+                            }.into_any()
+                        } else {
+                            let name = deck.as_ref().map_or_else(|| t("all-boards"), |deck| deck.name.clone());
+                            let rule = deck.as_ref().map(|deck| rule_label(deck.rule));
+                            view! { <span title=rule>{name}</span> }.into_any()
+                        };
+                        view! {
+                            {picker}
+                            {problem.map(|problem| view! { <span class="text-red-400">{deck_problem(&problem)}</span> })}
+                        }
+                    }}
+                    {move || (!game_state.get().match_state.config().ranked).then(|| view! {
+                        <label
+                            class="flex items-center gap-1"
+                            title=t("hints-title")
+                        >
+                            <input
+                                type="checkbox"
+                                prop:checked=move || show_hints.get()
+                                on:change=move |ev| show_hints.set(event_target_checked(&ev))
+                            />
+                            {t("hints")}
+                        </label>
+                    })}
                 </div>
                 <div class="flex justify-between text-xl mb-4">
                     <div>
                         {move || game_state.get().player1}
                         ": "
                         <span class="font-bold">
-                            {move || game_state.get().player1_score}
+                            {move || totals().0.0}
                         </span>
+                        {move || totals().1.map(|(points, _)| view! {
+                            <span class="ml-2 text-base text-green-400">{format!("{:+}", points)}</span>
+                        })}
                     </div>
+                    {move || {
+                        let (phase, over) = game_state.with(|state| (state.match_state.phase(), state.is_over()));
+                        (phase != MatchPhase::Lobby && !over).then(|| view! {
+                            <button
+                                class="px-3 py-1 bg-slate-700 hover:bg-slate-600 rounded text-base"
+                                title=t("pause-title")
+                                aria-pressed=move || paused.get().to_string()
+                                on:click=move |_| paused.update(|paused| *paused = !*paused)
+                            >
+                                {move || if paused.get() { t("resume") } else { t("pause") }}
+                            </button>
+                        })
+                    }}
                     <div>
-                        {move || game_state.get().player2.as_ref().map(|p| p.name.clone()).unwrap_or_default()}
+                        {move || game_state.get().opponent_name()}
                         ": "
                         <span class="font-bold">
-                            {move || game_state.get().player2_score}
+                            {move || totals().0.1}
                         </span>
+                        {move || totals().1.map(|(_, points)| view! {
+                            <span class="ml-2 text-base text-green-400">{format!("{:+}", points)}</span>
+                        })}
                     </div>
                 </div>
-                {move || match game_state.get().phase {
-                    GamePhase::SelectingBoards => view! {
+                {move || match game_state.get().match_state.phase() {
+                    MatchPhase::Lobby | MatchPhase::Selecting => view! {
                         <div class="mt-8">
                             <div class="flex flex-col items-center mb-4">
                                 <h3 class="text-xl font-bold mb-2">
-                                    "Select your board"
+                                    {move || t("select-board")}
                                 </h3>
                                 {move || {
-                                    let current_speed = game_state.get().speed;
+                                    let timed = game_state.get().speed.timing().selection.is_some();
                                     let current_time = timer.get();
-                                    (current_speed != GameSpeed::Chill).then(|| view! {
+                                    timed.then(|| view! {
                                         <div class="font-mono text-lg text-orange-400 bg-slate-700 px-4 py-1 rounded-md">
-                                            {move || format!("{} seconds left!", current_time)}
+                                            {move || if paused.get() {
+                                                t("paused")
+                                            } else {
+                                                t_with("seconds-left", &[("seconds", current_time.into())])
+                                            }}
                                         </div>
                                     })
                                 }}
                             </div>
-                            <div class="grid grid-cols-4 gap-4 max-w-xl mx-auto">
-                                <For
-                                    each=move || boards.get()
-                                    key=|board| board.thumbnail.clone()
-                                    children=move |board: SavedBoard| {
-                                        view! {
-                                            <button
-                                                class="w-24 h-24 rounded border border-slate-700 hover:border-blue-500 transition-colors"
-                                                on:click=move |_| {
-                                                    let mut current_state = game_state.get();
-                                                    current_state.player1_board = Some(board.clone());
-                                                    
-                                                    // Select random board for CPU opponent
-                                                    if let Some(ref opponent) = current_state.player2 {
-                                                        if matches!(opponent.opponent_type, OpponentType::Computer) {
-                                                            let available_boards = boards.get();
-                                                            if let Some(cpu_board) = select_random_board(available_boards) {
-                                                                current_state.player2_board = Some(cpu_board);
-                                                            }
-                                                        }
-                                                    }
-                                                    
-                                                    current_state.phase = GamePhase::ShowingResults;
-                                                    game_state.set(current_state);
-                                                }
-                                            >
-                                                <img 
-                                                    src=board.thumbnail.clone()
-                                                    alt="Board option" 
-                                                    class="w-full h-full rounded"
-                                                />
-                                            </button>
-                                        }
-                                    }
-                                />
-                            </div>
+                            <BoardChooser boards=Signal::derive(hinted_boards) on_choose=Callback::new(choose_board)/>
                         </div>
                     }.into_any(),
-                    GamePhase::ShowingResults => view! {
-                        <div class="flex flex-col items-center gap-6">
-                            // Thumbnails row
-                            <div class="flex gap-8 items-start">
-                                // Player's board
-                                <div class="text-center">
-                                    <h3 class="text-sm font-bold mb-2">
-                                        <div class="flex items-center justify-center gap-2">
-                                            {move || game_state.get().player1}
-                                        </div>
-                                    </h3>
-                                    {move || game_state.get().player1_board.as_ref().map(|board| view! {
-                                        <img 
-                                            src=generate_thumbnail(&board.board)
-                                            alt="Player board" 
-                                            class="w-32 h-32 rounded border border-slate-700"
-                                        />
-                                    })}
+                    MatchPhase::AwaitingOpponent if is_computer(&game_state.get()) => view! {
+                        <div class="mt-8 text-center text-gray-300">
+                            {move || t_with("waiting-for-board", &[("name", game_state.get().opponent_name().into())])}
+                        </div>
+                    }.into_any(),
+                    // A hot-seat opponent chooses on the same screen, after
+                    // the player's board is out of sight
+                    MatchPhase::AwaitingOpponent => {
+                        let name = game_state.get().opponent_name();
+                        match opponent_choosing.get() {
+                            false => view! {
+                                <div class="mt-8 flex flex-col items-center gap-4">
+                                    <div class="text-gray-300">{t_with("hand-over", &[("name", name.clone().into())])}</div>
+                                    <button
+                                        class="px-4 py-2 bg-blue-600 hover:bg-blue-700 rounded"
+                                        on:click=move |_| opponent_choosing.set(true)
+                                    >
+                                        {t_with("take-turn", &[("name", name.into())])}
+                                    </button>
                                 </div>
-                
-                                // Opponent's board
-                                <div class="text-center">
-                                    <h3 class="text-sm font-bold mb-2">
-                                        <div class="flex items-center justify-center gap-2">
-                                            {move || game_state.get().player2.as_ref().map(|p| p.name.clone()).unwrap_or_default()}
-                                        </div>
+                            }.into_any(),
+                            true => view! {
+                                <div class="mt-8">
+                                    <h3 class="text-xl font-bold mb-4 text-center">
+                                        {t_with("choose-as", &[("name", name.into())])}
                                     </h3>
-                                    {move || game_state.get().player2_board.as_ref().map(|board| view! {
-                                        <img 
-                                            src=generate_opponent_thumbnail(&board.board)
-                                            alt="Opponent board" 
-                                            class="w-32 h-32 rounded border border-slate-700"
-                                        />
-                                    })}
+                                    <BoardChooser boards=Signal::derive(opponent_boards) on_choose=Callback::new(choose_opponent_board)/>
                                 </div>
-                            </div>
-                
+                            }.into_any(),
+                        }
+                    }
+                    // Only the chosen boards until the reveal is over
+                    MatchPhase::Revealing => view! {
+                        <div class="flex flex-col items-center gap-6">
+                            {chosen_boards}
+                        </div>
+                    }.into_any(),
+                    MatchPhase::RoundSummary
+                    | MatchPhase::MatchOver
+                    | MatchPhase::RematchPending { .. }
+                    | MatchPhase::RematchDeclined { .. } => view! {
+                        <div class="flex flex-col items-center gap-6">
+                            {chosen_boards}
+
                             // Game board view
                             <div class="text-center">
-                                {move || {
-                                    let state = game_state.get();
-                                    if let (Some(board1), Some(board2)) = (&state.player1_board, &state.player2_board) {
-                                        // Initialize game board if not exists
-                                        if state.game_board.is_none() {
-                                            let mut game_board = GameBoard::new(2);
-                                            game_board.process_turn(&board1.board, &board2.board);
-                                            
-                                            // Update total scores
-                                            let mut current_state = state.clone();
-                                            current_state.player1_score += game_board.player_score;
-                                            current_state.player2_score += game_board.opponent_score;
-                                            current_state.game_board = Some(game_board);
-                                            game_state.set(current_state);
-                                        }
-                                        if let Some(game_board) = &state.game_board {
-                                            view! {
-                                                <div class="flex flex-col items-center gap-2">
-                                                <img 
-                                                    src=game_board.generate_board_svg()
-                                                    alt="Game board" 
-                                                    class="w-96 h-96 rounded border border-slate-700"
-                                                />
+                                {move || game_state.get().match_state.game_board().cloned().map(|game_board| {
+                                    let png_board = game_board.clone();
+                                    let gif_board = game_board.clone();
+                                    view! {
+                                        <div class="flex flex-col items-center gap-2">
+                                            <img
+                                                src=generate_round_image(&game_board)
+                                                alt=describe_round(&game_board, get_locale().get())
+                                                class="w-96 h-96 rounded border border-slate-700"
+                                            />
+                                            <div class="flex gap-4 text-sm">
+                                                <button
+                                                    class="text-blue-400 hover:text-blue-300"
+                                                    on:click=move |_| download_round_png(&png_board)
+                                                >
+                                                    {t("save-png")}
+                                                </button>
+                                                <button
+                                                    class="text-blue-400 hover:text-blue-300"
+                                                    on:click=move |_| download_round_replay(&gif_board)
+                                                >
+                                                    {t("save-gif")}
+                                                </button>
                                             </div>
-                                            }.into_any()
-                                        } else {
-                                            view! { <div>"Loading..."</div> }.into_any()
-                                        }
-                                    } else {
-                                        view! { <div>"Loading..."</div> }.into_any()
+                                        </div>
                                     }
-                                }}
-                            </div>                
+                                })}
+                            </div>
                             // Round scores display
                             <div class="mt-4 flex justify-center gap-8">
                                 {move || {
                                     let state = game_state.get();
-                                    if let Some(game_board) = &state.game_board {
-                                        view! {
-                                            <div class="flex justify-center gap-8">
-                                                <div class="text-lg">
-                                                    {state.player1.clone()} 
-                                                    {" (Round): "}
-                                                    <span class="font-bold">
-                                                        {game_board.player_score}
-                                                    </span>
-                                                </div>
-                                                <div class="text-lg">
-                                                    {state.player2.as_ref().map(|p| p.name.clone()).unwrap_or_default()} 
-                                                    {" (Round): "}
-                                                    <span class="font-bold">
-                                                        {game_board.opponent_score}
-                                                    </span>
-                                                </div>
+                                    state.match_state.game_board().map(|game_board| view! {
+                                        <div class="flex justify-center gap-8">
+                                            <div class="text-lg">
+                                                {t_with("round-points", &[("name", state.player1.clone().into())])}
+                                                " "
+                                                <span class="font-bold">
+                                                    {game_board.player_score}
+                                                </span>
                                             </div>
-                                        }.into_any()
-                                    } else {
-                                        view! { <div>"Calculating scores..."</div> }.into_any()
-                                    }
-                                }}
-                            </div>
-                
-                            // Total scores display
-                            <div class="mt-2 flex justify-center gap-8 text-sm text-gray-400">
-                                {move || {
-                                    let state = game_state.get();
-                                    view! {
-                                        <>
-                                            <div>
-                                                "Total: "
-                                                <span class="font-bold">{state.player1_score}</span>
+                                            <div class="text-lg">
+                                                {t_with("round-points", &[("name", state.opponent_name().into())])}
+                                                " "
+                                                <span class="font-bold">
+                                                    {game_board.opponent_score}
+                                                </span>
                                             </div>
-                                            <div>
-                                                "Total: "
-                                                <span class="font-bold">{state.player2_score}</span>
-                                            </div>
-                                        </>
-                                    }
+                                        </div>
+                                    })
                                 }}
                             </div>
-                
-                            // Next round button
+
+                            // What comes next: a countdown while the match
+                            // moves on by itself, or the way to move it on
                             {move || {
-                                let current_state = game_state.get();
-                                if current_state.current_round < 8 {
-                                    view! {            
+                                let state = game_state.get();
+                                let counting = advance_in.get().filter(|_| !paused.get());
+                                match state.match_state.phase() {
+                                    MatchPhase::RoundSummary if counting.is_some() => {
+                                        let seconds = counting.unwrap_or_default();
+                                        let id = if state.match_state.is_last_round() { "final-score-in" } else { "next-round-in" };
+                                        view! {
+                                            <div class="mt-4 text-lg text-gray-300">
+                                                {t_with(id, &[("seconds", seconds.into())])}
+                                            </div>
+                                        }.into_any()
+                                    }
+                                    MatchPhase::RoundSummary if !state.match_state.is_last_round() => view! {
                                         <div class="mt-4">
                                             <h3 class="text-xl font-bold mb-2 text-center">
-                                                "Choose your next board"
+                                                {t("next-board")}
                                             </h3>
-                                            <div class="grid grid-cols-4 gap-4 max-w-xl mx-auto">
-                                                <For
-                                                    each=move || boards.get()
-                                                    key=|board| board.thumbnail.clone()
-                                                    children=move |board: SavedBoard| {
-                                                        view! {
-                                                            <button
-                                                                class="w-24 h-24 rounded border border-slate-700 hover:border-blue-500 transition-colors"
-                                                                on:click=move |_| {
-                                                                    let mut current_state = game_state.get();
-                                                                    current_state.current_round += 1;
-                                                                    current_state.player1_board = Some(board.clone());
-                                                                    
-                                                                    // Select random board for CPU opponent
-                                                                    if let Some(ref opponent) = current_state.player2 {
-                                                                        if matches!(opponent.opponent_type, OpponentType::Computer) {
-                                                                            let available_boards = boards.get();
-                                                                            if let Some(cpu_board) = select_random_board(available_boards) {
-                                                                                current_state.player2_board = Some(cpu_board);
-                                                                            }
-                                                                        }
-                                                                    }
-                                                                    
-                                                                    current_state.phase = GamePhase::ShowingResults;
-                                                                    current_state.game_board = None;
-                                                                    set_timer.set(match current_state.speed {
-                                                                        GameSpeed::Lightning => 1,
-                                                                        GameSpeed::Quick => 5,
-                                                                        GameSpeed::Relaxed => 10,
-                                                                        GameSpeed::Chill => 999999,
-                                                                    });
-                                                                    game_state.set(current_state);
-                                                                }
-                                                            >
-                                                                <img 
-                                                                    src=board.thumbnail.clone()
-                                                                    alt="Board option" 
-                                                                    class="w-full h-full rounded"
-                                                                />
-                                                            </button>
-                                                        }
-                                                    }
-                                                />
-                                            </div>
+                                            <BoardChooser boards=Signal::derive(hinted_boards) on_choose=Callback::new(choose_board)/>
                                         </div>
-                                    }.into_any()
-                                } else {
-                                    let current_state_clone = current_state.clone(); // Clone here for the second button
-                                    view! {
-                                        <div class="flex gap-4">
-                                            <button
-                                                class="px-4 py-2 bg-blue-600 hover:bg-blue-700 rounded"
-                                                on:click=move |_| {
-                                                    // Record the game result
-                                                    if let Some(opponent) = current_state.player2.clone() {
-                                                        let won = current_state.player1_score > current_state.player2_score;
-                                                        let _ = update_opponent_stats(&opponent.id, won);
-                                                    }
-                                                    // Start new game
-                                                    let mut new_state = GameState::new(
-                                                        current_state.player1.clone(),
-                                                        current_state.player2.clone().unwrap()
-                                                    );
-                                                    new_state.speed = current_state.speed.clone();
-                                                    game_state.set(new_state);
-                                                    // Reset timer
-                                                    set_timer.set(match current_state.speed {
-                                                        GameSpeed::Lightning => 1,
-                                                        GameSpeed::Quick => 5,
-                                                        GameSpeed::Relaxed => 10,
-                                                        GameSpeed::Chill => 999999,
-                                                    });
-                                                }
-                                            >
-                                                "Play Again"
-                                            </button>
+                                    }.into_any(),
+                                    MatchPhase::RoundSummary => view! {
+                                        <button
+                                            class="px-4 py-2 bg-blue-600 hover:bg-blue-700 rounded"
+                                            on:click=move |_| handle.dispatch(MatchEvent::Continue)
+                                        >
+                                            {t("see-final-score")}
+                                        </button>
+                                    }.into_any(),
+                                    MatchPhase::MatchOver | MatchPhase::RematchPending { .. } => {
+                                        // A hot-seat opponent answers on the same screen
+                                        let hot_seat = !is_computer(&state);
+                                        view! {
+                                            <div class="flex flex-col items-center gap-2">
+                                                {rematch_answer(Side::Player)}
+                                                {hot_seat.then(|| rematch_answer(Side::Opponent))}
+                                            </div>
+                                        }.into_any()
+                                    }
+                                    MatchPhase::RematchDeclined { by: Side::Opponent } => view! {
+                                        <div class="flex flex-col items-center gap-2">
+                                            <div class="text-gray-300">
+                                                {t_with("rematch-declined", &[("name", state.opponent_name().into())])}
+                                            </div>
                                             <button
                                                 class="px-4 py-2 bg-gray-600 hover:bg-gray-700 rounded"
-                                                on:click=move |_| {
-                                                    // Record the game result before exiting
-                                                    if let Some(opponent) = current_state_clone.player2.clone() {
-                                                        let won = current_state_clone.player1_score > current_state_clone.player2_score;
-                                                        let _ = update_opponent_stats(&opponent.id, won);
-                                                        on_stats_update.run(());
-                                                    }
-                                                    on_exit.run(());
-                                                }
+                                                on:click=move |_| on_exit.run(())
                                             >
-                                                "I'm Done"
+                                                {t("back-to-main")}
                                             </button>
                                         </div>
-                                    }.into_any()
+                                    }.into_any(),
+                                    _ => view! { <span></span> }.into_any(),
                                 }
                             }}
-                            </div>
-                            }.into_any(),
-                        }}
+                        </div>
+                    }.into_any(),
+                }}
             </div>
         </div>
     }
-}
\ No newline at end of file
+}
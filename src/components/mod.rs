@@ -1,6 +1,6 @@
 pub mod opponent;
+pub mod pwa;
 pub mod board;
 pub mod game;
-pub mod game_board;
 pub mod saved_boards;
 pub mod utils;
\ No newline at end of file
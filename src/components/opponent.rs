@@ -1,34 +1,16 @@
-use serde::{Serialize, Deserialize};
+use leptos::prelude::*;
+use spaces_game::engine::layout::LayoutPreset;
+use spaces_game::engine::match_state::MatchConfig;
+use spaces_game::engine::movement::MovementPreset;
+use spaces_game::engine::opponent::migrate;
+pub use spaces_game::engine::opponent::{AvatarColor, Opponent, OpponentType};
+use spaces_game::engine::rules::Variant;
+use spaces_game::engine::timing::GameSpeed;
+use web_sys::js_sys::Date;
+use web_sys::wasm_bindgen::JsValue;
 use web_sys::window;
 
-#[derive(Clone, Serialize, Deserialize, PartialEq)]
-pub enum OpponentType {
-    Human,
-    Computer,
-}
-
-#[derive(Clone, Serialize, Deserialize, PartialEq)]
-pub struct Opponent {
-    pub name: String,
-    pub id: String,
-    pub opponent_type: OpponentType,
-}
-
-impl Opponent {
-    pub fn new(name: String, opponent_type: OpponentType) -> Self {
-        Opponent {
-            id: format!("{}_{}", 
-                match opponent_type {
-                    OpponentType::Human => "human",
-                    OpponentType::Computer => "cpu",
-                },
-                name.to_lowercase().replace(" ", "_")
-            ),
-            name,
-            opponent_type,
-        }
-    }
-}
+use super::utils::{get_locale, load_match, rename_opponent_model, save_match, t, t_with};
 
 pub fn delete_opponent(id: &str) -> Result<(), serde_json::Error> {
     let storage = window().unwrap().local_storage().unwrap().unwrap();
@@ -59,4 +41,291 @@ pub fn load_opponents() -> Option<Vec<Opponent>> {
     let storage = window().unwrap().local_storage().unwrap().unwrap();
     let data = storage.get_item("saved_opponents").ok()??;
     serde_json::from_str(&data).ok()
-}
\ No newline at end of file
+}
+/// Saves the changes to `opponent`, or adds it if it is new.
+pub fn update_opponent(opponent: Opponent) -> Result<(), serde_json::Error> {
+    let storage = window().unwrap().local_storage().unwrap().unwrap();
+    let mut saved_opponents = load_opponents().unwrap_or_default();
+    match saved_opponents.iter_mut().find(|o| o.id == opponent.id) {
+        Some(saved) => *saved = opponent,
+        None => saved_opponents.push(opponent),
+    }
+    let json = serde_json::to_string(&saved_opponents)?;
+    storage.set_item("saved_opponents", &json).unwrap();
+    Ok(())
+}
+
+/// Moves human opponents saved under name-based IDs to unique ones, along
+/// with their models and any match in progress against them. Returns each
+/// `(old, new)` pair for the stats to follow.
+pub fn migrate_opponent_ids() -> Result<Vec<(String, String)>, serde_json::Error> {
+    let Some(mut saved_opponents) = load_opponents() else {
+        return Ok(Vec::new());
+    };
+    let moved = migrate(&mut saved_opponents, &mut rand::thread_rng());
+    if moved.is_empty() {
+        return Ok(moved);
+    }
+    let storage = window().unwrap().local_storage().unwrap().unwrap();
+    let json = serde_json::to_string(&saved_opponents)?;
+    storage.set_item("saved_opponents", &json).unwrap();
+    for (old, new) in &moved {
+        rename_opponent_model(old, new);
+    }
+    if let Some(mut state) = load_match() {
+        if let Some(opponent) = state.player2.as_mut() {
+            if let Some((_, new)) = moved.iter().find(|(old, _)| *old == opponent.id) {
+                opponent.id = new.clone();
+                save_match(&state);
+            }
+        }
+    }
+    Ok(moved)
+}
+
+/// The opponent's badge: their glyph on their color.
+#[component]
+pub fn OpponentAvatar(opponent: Opponent) -> impl IntoView {
+    let computer = opponent.opponent_type == OpponentType::Computer;
+    view! {
+        <span
+            class="w-6 h-6 shrink-0 rounded-full flex items-center justify-center text-xs font-bold text-white"
+            style=format!("background-color: {}", opponent.avatar.color.hex())
+            title=move || t(if computer { "opponent-computer" } else { "opponent-human" })
+            aria-hidden="true"
+        >
+            {opponent.glyph().to_string()}
+        </span>
+    }
+}
+
+/// When `created` was, as a date in the player's language.
+fn added_on(created: u64) -> String {
+    let date = Date::new(&JsValue::from_f64(created as f64));
+    date.to_locale_date_string(get_locale().get_untracked().code(), &JsValue::UNDEFINED).into()
+}
+
+/// Edits an opponent's name, badge, notes and the settings the player keeps
+/// for them; `on_save` gets the changed opponent, with its ID untouched.
+#[component]
+pub fn OpponentEditor(
+    opponent: Opponent,
+    /// The rules the player's own matches start with, offered when the
+    /// opponent gets rules of their own.
+    default_variant: Variant,
+    #[prop(into)] on_save: Callback<Opponent>,
+    #[prop(into)] on_cancel: Callback<()>,
+) -> impl IntoView {
+    let created = opponent.created;
+    let draft = RwSignal::new(opponent);
+    let field = "w-full px-3 py-1 rounded bg-slate-700 border border-slate-600 text-white";
+    let config = move || draft.with(|opponent| opponent.config);
+    let set_config = move |change: &dyn Fn(&mut MatchConfig)| {
+        draft.update(|opponent| {
+            if let Some(config) = opponent.config.as_mut() {
+                change(config);
+            }
+        });
+    };
+
+    view! {
+        <div class="flex flex-col gap-3 p-3 bg-slate-900 rounded">
+            <label class="block text-sm">
+                {t("opponent-name")}
+                <input
+                    type="text"
+                    class=field
+                    prop:value=move || draft.with(|opponent| opponent.name.clone())
+                    on:input=move |ev| draft.update(|opponent| opponent.name = event_target_value(&ev))
+                />
+            </label>
+            <div class="flex items-end gap-3">
+                <label class="block text-sm">
+                    {t("avatar-glyph")}
+                    <input
+                        type="text"
+                        maxlength="2"
+                        class="w-16 px-3 py-1 rounded bg-slate-700 border border-slate-600 text-white"
+                        prop:value=move || draft.with(|opponent| opponent.avatar.glyph.map(String::from).unwrap_or_default())
+                        on:input=move |ev| {
+                            let glyph = event_target_value(&ev).trim().chars().next();
+                            draft.update(|opponent| opponent.avatar.glyph = glyph);
+                        }
+                    />
+                </label>
+                <div role="radiogroup" aria-label=t("avatar-color") class="flex gap-1 pb-1">
+                    {AvatarColor::ALL.into_iter().map(|color| view! {
+                        <button
+                            type="button"
+                            role="radio"
+                            aria-checked=move || (draft.with(|opponent| opponent.avatar.color) == color).to_string()
+                            aria-label=t(&format!("color-{}", color.key()))
+                            title=t(&format!("color-{}", color.key()))
+                            class="w-6 h-6 rounded-full border-2"
+                            class=("border-white", move || draft.with(|opponent| opponent.avatar.color) == color)
+                            class=("border-transparent", move || draft.with(|opponent| opponent.avatar.color) != color)
+                            style=format!("background-color: {}", color.hex())
+                            on:click=move |_| draft.update(|opponent| opponent.avatar.color = color)
+                        ></button>
+                    }).collect_view()}
+                </div>
+                {move || view! { <OpponentAvatar opponent=draft.get()/> }}
+            </div>
+            <label class="block text-sm">
+                {t("notes")}
+                <textarea
+                    rows="2"
+                    class=field
+                    prop:value=move || draft.with(|opponent| opponent.notes.clone())
+                    on:input=move |ev| draft.update(|opponent| opponent.notes = event_target_value(&ev))
+                ></textarea>
+            </label>
+            <label class="block text-sm">
+                {t("preferred-speed")}
+                <select
+                    class=field
+                    on:change=move |ev| {
+                        let key = event_target_value(&ev);
+                        draft.update(|opponent| {
+                            // A custom speed can only be kept, not picked here
+                            if opponent.speed.is_none_or(|speed| speed.key() != key) {
+                                opponent.speed = GameSpeed::PRESETS.into_iter().find(|speed| speed.key() == key);
+                            }
+                        });
+                    }
+                >
+                    <option value="" selected=move || draft.with(|opponent| opponent.speed.is_none()) class="bg-slate-700">
+                        {t("speed-default")}
+                    </option>
+                    {
+                        let custom = draft.with_untracked(|opponent| opponent.speed.filter(|speed| matches!(speed, GameSpeed::Custom(_))));
+                        GameSpeed::PRESETS.into_iter().chain(custom).map(|speed| view! {
+                            <option
+                                value=speed.key()
+                                selected=move || draft.with(|opponent| opponent.speed.map(|s| s.key())) == Some(speed.key())
+                                class="bg-slate-700"
+                            >
+                                {t(&format!("speed-{}", speed.key()))}
+                            </option>
+                        }).collect_view()
+                    }
+                </select>
+            </label>
+            <label class="flex items-center gap-2 text-sm">
+                <input
+                    type="checkbox"
+                    prop:checked=move || config().is_some()
+                    on:change=move |ev| {
+                        let own = event_target_checked(&ev);
+                        draft.update(|opponent| {
+                            opponent.config = own.then_some(MatchConfig { variant: default_variant, ..MatchConfig::default() });
+                        });
+                    }
+                />
+                {t("own-rules")}
+            </label>
+            {move || config().map(|config| view! {
+                <div class="grid grid-cols-2 gap-2 text-sm">
+                    <label>
+                        {t("rules")}
+                        <select
+                            class=field
+                            on:change=move |ev| {
+                                let value = event_target_value(&ev);
+                                if let Some(variant) = Variant::ALL.into_iter().find(|v| v.key() == value) {
+                                    set_config(&|config| config.variant = variant);
+                                }
+                            }
+                        >
+                            {Variant::ALL.into_iter().map(|variant| view! {
+                                <option value=variant.key() selected=config.variant == variant class="bg-slate-700">
+                                    {t(&format!("variant-{}", variant.key()))}
+                                </option>
+                            }).collect_view()}
+                        </select>
+                    </label>
+                    <label>
+                        {t("movement")}
+                        <select
+                            class=field
+                            on:change=move |ev| {
+                                let value = event_target_value(&ev);
+                                if let Some(preset) = MovementPreset::ALL.into_iter().find(|p| p.key() == value) {
+                                    set_config(&|config| config.movement = preset);
+                                }
+                            }
+                        >
+                            {MovementPreset::ALL.into_iter().map(|preset| view! {
+                                <option value=preset.key() selected=config.movement == preset class="bg-slate-700">
+                                    {t(&format!("movement-{}", preset.key()))}
+                                </option>
+                            }).collect_view()}
+                        </select>
+                    </label>
+                    <label>
+                        {t("map")}
+                        <select
+                            class=field
+                            on:change=move |ev| {
+                                let value = event_target_value(&ev);
+                                if let Some(preset) = LayoutPreset::ALL.into_iter().find(|p| p.key() == value) {
+                                    set_config(&|config| config.layout = preset);
+                                }
+                            }
+                        >
+                            {LayoutPreset::ALL.into_iter().map(|preset| view! {
+                                <option value=preset.key() selected=config.layout == preset class="bg-slate-700">
+                                    {t(&format!("layout-{}", preset.key()))}
+                                </option>
+                            }).collect_view()}
+                        </select>
+                    </label>
+                    <label>
+                        {t("rounds")}
+                        <input
+                            type="number"
+                            min="1"
+                            max="20"
+                            class=field
+                            prop:value=config.rounds.to_string()
+                            on:change=move |ev| {
+                                let rounds = event_target_value(&ev).trim().parse().unwrap_or(config.rounds).clamp(1, 20);
+                                set_config(&|config| config.rounds = rounds);
+                            }
+                        />
+                    </label>
+                    <label class="flex items-center gap-2" title=t("ranked-title")>
+                        <input
+                            type="checkbox"
+                            prop:checked=config.ranked
+                            on:change=move |ev| {
+                                let ranked = event_target_checked(&ev);
+                                set_config(&|config| config.ranked = ranked);
+                            }
+                        />
+                        {t("ranked")}
+                    </label>
+                </div>
+            })}
+            {created.map(|created| view! {
+                <div class="text-xs text-gray-500">{t_with("opponent-added", &[("date", added_on(created).into())])}</div>
+            })}
+            <div class="flex justify-end gap-2">
+                <button class="px-3 py-1 bg-gray-700 hover:bg-gray-600 rounded text-sm" on:click=move |_| on_cancel.run(())>
+                    {t("cancel")}
+                </button>
+                <button
+                    class="px-3 py-1 bg-blue-600 hover:bg-blue-700 rounded text-sm disabled:opacity-50"
+                    disabled=move || draft.with(|opponent| opponent.name.trim().is_empty())
+                    on:click=move |_| {
+                        let mut opponent = draft.get_untracked();
+                        opponent.name = opponent.name.trim().to_string();
+                        on_save.run(opponent);
+                    }
+                >
+                    {t("save")}
+                </button>
+            </div>
+        </div>
+    }
+}
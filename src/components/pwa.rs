@@ -0,0 +1,171 @@
+//! Installing the game and keeping it playable offline: the service worker
+//! Trunk's post-build hook writes as `sw.js`, and the prompts to install the
+//! game or reload into a newly deployed build.
+
+use std::cell::RefCell;
+
+use leptos::prelude::*;
+use web_sys::js_sys::{Function, Reflect};
+use web_sys::wasm_bindgen::closure::Closure;
+use web_sys::wasm_bindgen::{JsCast, JsValue};
+use web_sys::{window, Event, ServiceWorkerContainer, ServiceWorkerRegistration, ServiceWorkerState};
+
+use crate::components::utils::t;
+
+/// The worker, next to `index.html` so that it covers the whole game.
+const WORKER: &str = "sw.js";
+
+thread_local! {
+    /// The browser's install prompt, held back until the player asks for it.
+    static INSTALL_PROMPT: RefCell<Option<Event>> = const { RefCell::new(None) };
+}
+
+/// The page's service workers; `None` where the browser has none, as on
+/// plain-HTTP origins.
+fn service_workers() -> Option<ServiceWorkerContainer> {
+    let navigator = window()?.navigator();
+    Reflect::has(&navigator, &JsValue::from_str("serviceWorker"))
+        .unwrap_or(false)
+        .then(|| navigator.service_worker())
+}
+
+/// Whether the page is already run by a service worker, so that a newly
+/// installed one is an update rather than the first install.
+fn has_controller() -> bool {
+    service_workers().and_then(|container| container.controller()).is_some()
+}
+
+/// Runs `f` once `promise` resolves.
+fn then(promise: &web_sys::js_sys::Promise, f: impl FnMut(JsValue) + 'static) {
+    let callback = Closure::<dyn FnMut(JsValue)>::new(f);
+    let _ = promise.then(&callback);
+    callback.forget();
+}
+
+/// Sets `handler` as an event handler through `set`, for the page's lifetime.
+fn on(set: impl FnOnce(Option<&Function>), handler: impl FnMut() + 'static) {
+    let callback = Closure::<dyn FnMut()>::new(handler);
+    set(Some(callback.as_ref().unchecked_ref()));
+    callback.forget();
+}
+
+/// Registers the service worker, and sets `update_ready` once a newer build
+/// has installed and is waiting to take over. Debug builds skip it, so that
+/// `trunk serve` never serves a stale build.
+fn register_service_worker(update_ready: RwSignal<bool>) {
+    if cfg!(debug_assertions) {
+        return;
+    }
+    let Some(container) = service_workers() else {
+        return;
+    };
+    then(&container.register(WORKER), move |registration| {
+        let Ok(registration) = registration.dyn_into::<ServiceWorkerRegistration>() else {
+            return;
+        };
+        // A build fetched on an earlier visit may be waiting already
+        if registration.waiting().is_some() && has_controller() {
+            update_ready.set(true);
+        }
+        let watched = registration.clone();
+        on(|f| registration.set_onupdatefound(f), move || {
+            let Some(worker) = watched.installing() else {
+                return;
+            };
+            let installing = worker.clone();
+            on(|f| worker.set_onstatechange(f), move || {
+                if installing.state() == ServiceWorkerState::Installed && has_controller() {
+                    update_ready.set(true);
+                }
+            });
+        });
+    });
+}
+
+/// Lets the waiting build take over and reloads the page into it.
+fn apply_update() {
+    let Some(container) = service_workers() else {
+        return;
+    };
+    on(|f| container.set_oncontrollerchange(f), || {
+        if let Some(window) = window() {
+            let _ = window.location().reload();
+        }
+    });
+    then(&container.get_registration(), |registration| {
+        let waiting = registration.dyn_into::<ServiceWorkerRegistration>().ok().and_then(|r| r.waiting());
+        if let Some(worker) = waiting {
+            let _ = worker.post_message(&JsValue::from_str("skip-waiting"));
+        }
+    });
+}
+
+/// Listens for the browser offering to install the game, and sets
+/// `installable` while it does.
+fn watch_install_prompt(installable: RwSignal<bool>) {
+    let Some(window) = window() else {
+        return;
+    };
+    let offered = Closure::<dyn FnMut(Event)>::new(move |ev: Event| {
+        // Keep the mini-infobar away; the game offers its own button
+        ev.prevent_default();
+        INSTALL_PROMPT.with(|prompt| *prompt.borrow_mut() = Some(ev));
+        installable.set(true);
+    });
+    let installed = Closure::<dyn FnMut(Event)>::new(move |_: Event| {
+        INSTALL_PROMPT.with(|prompt| *prompt.borrow_mut() = None);
+        installable.set(false);
+    });
+    let _ = window.add_event_listener_with_callback("beforeinstallprompt", offered.as_ref().unchecked_ref());
+    let _ = window.add_event_listener_with_callback("appinstalled", installed.as_ref().unchecked_ref());
+    offered.forget();
+    installed.forget();
+}
+
+/// Shows the browser's install prompt, which can only be shown once.
+fn install() {
+    let Some(prompt) = INSTALL_PROMPT.with(|prompt| prompt.borrow_mut().take()) else {
+        return;
+    };
+    if let Ok(show) = Reflect::get(&prompt, &JsValue::from_str("prompt")).and_then(|f| f.dyn_into::<Function>()) {
+        let _ = show.call0(&prompt);
+    }
+}
+
+/// Offers to install the game when the browser allows it, and to reload once
+/// a newer build is ready.
+#[component]
+pub fn AppPrompts() -> impl IntoView {
+    let update_ready = RwSignal::new(false);
+    let installable = RwSignal::new(false);
+    register_service_worker(update_ready);
+    watch_install_prompt(installable);
+
+    view! {
+        <div class="fixed bottom-4 right-4 flex flex-col items-end gap-2 z-50">
+            {move || update_ready.get().then(|| view! {
+                <div role="status" class="flex items-center gap-4 px-4 py-2 bg-slate-800 border border-blue-600 rounded shadow-lg">
+                    <span>{t("update-ready")}</span>
+                    <button class="px-3 py-1 bg-blue-600 hover:bg-blue-700 rounded text-sm" on:click=move |_| apply_update()>
+                        {t("update-reload")}
+                    </button>
+                    <button class="text-sm text-gray-400 hover:text-gray-300" on:click=move |_| update_ready.set(false)>
+                        {t("update-later")}
+                    </button>
+                </div>
+            })}
+            {move || installable.get().then(|| view! {
+                <button
+                    class="px-3 py-1 bg-slate-700 hover:bg-slate-600 rounded text-sm shadow-lg"
+                    title=t("install-title")
+                    on:click=move |_| {
+                        install();
+                        installable.set(false);
+                    }
+                >
+                    {t("install")}
+                </button>
+            })}
+        </div>
+    }
+}
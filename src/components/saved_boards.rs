@@ -1,7 +1,9 @@
 use leptos::*;
 use leptos::prelude::*;
+use spaces_game::engine::deck::{Deck, DeckRule, FAVORITES};
+use spaces_game::render::describe::describe_board;
 
-use crate::components::utils::{delete_board,generate_thumbnail,load_saved_boards};
+use crate::components::utils::{delete_board,generate_thumbnail,get_locale,load_decks,load_saved_boards,save_decks,t,t_with};
 use crate::components::board::SavedBoard;
 
 pub static BOARD_TRIGGER: std::sync::OnceLock<RwSignal<bool>> = std::sync::OnceLock::new();
@@ -10,6 +12,14 @@ pub fn get_board_trigger() -> RwSignal<bool> {
     *BOARD_TRIGGER.get_or_init(|| RwSignal::new(false))
 }
 
+/// A deck rule in the player's language.
+pub fn rule_label(rule: DeckRule) -> String {
+    match rule {
+        DeckRule::Hand(size) => t_with("deck-rule-hand", &[("size", size.into())]),
+        rule => t(&format!("deck-rule-{}", rule.key())),
+    }
+}
+
 #[component]
 pub fn SavedBoards() -> impl IntoView {
     let trigger = get_board_trigger();
@@ -18,25 +28,103 @@ pub fn SavedBoards() -> impl IntoView {
         trigger.get();
         load_saved_boards().unwrap_or_default()
     });
+    let decks = RwSignal::new(load_decks());
+    // The deck being put together, if any; clicking a board adds or removes it
+    let draft = RwSignal::new(None::<Deck>);
+    // The name the deck being edited was saved under
+    let editing = StoredValue::new(None::<String>);
+    let drafting = Memo::new(move |_| draft.with(Option::is_some));
+    let start_draft = move |deck: Deck, saved_as: Option<String>| {
+        editing.set_value(saved_as);
+        draft.set(Some(deck));
+    };
 
     let delete = move |index: usize| {
         let _ = delete_board(index);
         trigger.update(|v| *v = !*v);  // Toggle to trigger refresh
     };
 
+    let update_decks = move |change: &dyn Fn(&mut Vec<Deck>)| {
+        decks.update(|decks| change(decks));
+        let _ = save_decks(&decks.get_untracked());
+    };
+
+    let toggle_favorite = move |saved: &SavedBoard| {
+        update_decks(&|decks| {
+            if !decks.iter().any(|deck| deck.name == FAVORITES) {
+                decks.insert(0, Deck::new(FAVORITES, DeckRule::Open));
+            }
+            if let Some(favorites) = decks.iter_mut().find(|deck| deck.name == FAVORITES) {
+                favorites.toggle(&saved.board);
+            }
+        });
+    };
+
+    let save_draft = move |_| {
+        let Some(deck) = draft.get_untracked() else { return };
+        let name = deck.name.trim().to_string();
+        if name.is_empty() || deck.boards.is_empty() {
+            return;
+        }
+        let saved_as = editing.get_value();
+        update_decks(&|decks| {
+            let deck = Deck { name: name.clone(), ..deck.clone() };
+            // A renamed deck replaces the one it was saved as
+            decks.retain(|d| Some(&d.name) != saved_as.as_ref() || d.name == deck.name);
+            match decks.iter_mut().find(|d| d.name == deck.name) {
+                Some(existing) => *existing = deck,
+                None => decks.push(deck),
+            }
+        });
+        draft.set(None);
+    };
+
     view! {
         <div class="grid grid-cols-4 gap-4 mt-4">
             <For
                 each=move || boards.get()
-                key=|board| generate_thumbnail(&board.board)
+                key=|board| board.thumbnail.clone()
                 children=move |board: SavedBoard| {
+                    let favorite = {
+                        let board = board.board.clone();
+                        move || decks.with(|decks| decks.iter().any(|d| d.name == FAVORITES && d.contains(&board)))
+                    };
+                    let in_draft = {
+                        let board = board.board.clone();
+                        move || draft.with(|draft| draft.as_ref().is_some_and(|d| d.contains(&board)))
+                    };
+                    let picked = board.board.clone();
+                    let starred = board.clone();
                     view! {
                         <div class="relative">
-                            <img 
-                                src=board.thumbnail.clone()
-                                alt="Saved board" 
+                            <img
+                                src={
+                                    let (board, layout) = (board.board.clone(), board.layout.clone());
+                                    move || generate_thumbnail(&board, layout.as_ref())
+                                }
+                                alt={
+                                    let (board, layout) = (board.board.clone(), board.layout.clone());
+                                    move || describe_board(&board, layout.as_ref(), get_locale().get())
+                                }
+                                title=board.layout.as_ref().map(|layout| layout.name().to_string())
                                 class="w-24 h-24 rounded border border-slate-700"
+                                class=("ring-2", in_draft.clone())
+                                class=("ring-blue-500", in_draft)
+                                class=("cursor-pointer", move || draft.with(Option::is_some))
+                                on:click=move |_| draft.update(|draft| {
+                                    if let Some(deck) = draft {
+                                        deck.toggle(&picked);
+                                    }
+                                })
                             />
+                            <button
+                                class="absolute -top-2 -left-2 bg-slate-700 hover:bg-slate-600 rounded-full w-6 h-6 flex items-center justify-center"
+                                class=("text-yellow-400", favorite.clone())
+                                title=move || t("favorite")
+                                on:click=move |_| toggle_favorite(&starred)
+                            >
+                                {let favorite = favorite.clone(); move || if favorite() { "★" } else { "☆" }}
+                            </button>
                             <button
                                 class="absolute -top-2 -right-2 bg-red-600 hover:bg-red-700 rounded-full w-6 h-6 flex items-center justify-center"
                                 on:click=move |_| {
@@ -52,5 +140,107 @@ pub fn SavedBoards() -> impl IntoView {
                 }
             />
         </div>
+        <div class="mt-6">
+            <h3 class="text-xl font-bold mb-2">{move || t("decks")}</h3>
+            <For
+                each=move || decks.get()
+                key=|deck| (deck.name.clone(), deck.boards.len(), deck.rule)
+                children=move |deck: Deck| {
+                    let name = deck.name.clone();
+                    let edited = deck.clone();
+                    view! {
+                        <div class="flex items-center gap-2 text-sm mb-1">
+                            <span class="font-bold">{deck.name.clone()}</span>
+                            <span class="text-gray-400">
+                                {
+                                    let (count, rule) = (deck.boards.len(), deck.rule);
+                                    move || t_with("deck-summary", &[("count", count.into()), ("rule", rule_label(rule).into())])
+                                }
+                            </span>
+                            <button
+                                class="text-blue-400 hover:text-blue-300"
+                                on:click=move |_| start_draft(edited.clone(), Some(edited.name.clone()))
+                            >
+                                {move || t("edit")}
+                            </button>
+                            <button
+                                class="text-red-400 hover:text-red-300"
+                                on:click=move |_| update_decks(&|decks| decks.retain(|d| d.name != name))
+                            >
+                                {move || t("delete")}
+                            </button>
+                        </div>
+                    }
+                }
+            />
+            {move || if !drafting.get() {
+                view! {
+                    <a
+                        href="#"
+                        class="text-blue-400 hover:text-blue-300 block mt-2"
+                        on:click=move |ev| {
+                            ev.prevent_default();
+                            start_draft(Deck::new("", DeckRule::Open), None);
+                        }
+                    >
+                        {t("new-deck")}
+                    </a>
+                }.into_any()
+            } else {
+                let draft_value = move |read: fn(&Deck) -> String| {
+                    draft.with(|draft| draft.as_ref().map(read).unwrap_or_default())
+                };
+                view! {
+                    <div class="flex flex-col gap-2 mt-2 p-2 rounded border border-slate-700">
+                        <span class="text-sm text-gray-400">
+                            {move || {
+                                let count = draft.with(|draft| draft.as_ref().map_or(0, |deck| deck.boards.len()));
+                                t_with("deck-draft-help", &[("count", count.into())])
+                            }}
+                        </span>
+                        <input
+                            type="text"
+                            placeholder=move || t("deck-name")
+                            class="px-2 py-1 rounded bg-slate-700 border border-slate-600 text-white"
+                            prop:value=move || draft_value(|deck| deck.name.clone())
+                            on:input=move |ev| {
+                                let name = event_target_value(&ev);
+                                draft.update(|draft| if let Some(deck) = draft { deck.name = name });
+                            }
+                        />
+                        <select
+                            class="px-2 py-1 rounded bg-slate-700 border border-slate-600 text-white"
+                            on:change=move |ev| {
+                                let key = event_target_value(&ev);
+                                if let Some(rule) = DeckRule::ALL.into_iter().find(|rule| rule.key() == key) {
+                                    draft.update(|draft| if let Some(deck) = draft { deck.rule = rule });
+                                }
+                            }
+                        >
+                            {DeckRule::ALL.into_iter().map(|rule| view! {
+                                <option
+                                    value=rule.key()
+                                    selected=move || draft_value(|deck| deck.rule.key().to_string()) == rule.key()
+                                    class="text-white bg-slate-700"
+                                >
+                                    {move || rule_label(rule)}
+                                </option>
+                            }).collect_view()}
+                        </select>
+                        <div class="flex gap-2">
+                            <button class="px-4 py-2 bg-blue-600 hover:bg-blue-700 rounded" on:click=save_draft>
+                                {move || t("save-deck")}
+                            </button>
+                            <button
+                                class="px-4 py-2 bg-gray-700 hover:bg-gray-600 rounded"
+                                on:click=move |_| draft.set(None)
+                            >
+                                {move || t("cancel")}
+                            </button>
+                        </div>
+                    </div>
+                }.into_any()
+            }}
+        </div>
     }
-}
\ No newline at end of file
+}
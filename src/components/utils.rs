@@ -1,121 +1,58 @@
+use std::collections::HashMap;
 use web_sys::window;
 use leptos::prelude::*;
-use std::fmt::Write;
-use super::board::{Board, CellContent, SavedBoard};
-
-pub fn generate_opponent_thumbnail(board: &Board) -> String {
-    let mut svg = String::from(r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 100 100">
-            <rect width="100" height="100" fill="rgb(30, 41, 59)"/>
-            <g transform="translate(5,5)">"#);
-
-    // Draw grid
-    for i in 0..board.grid.len() {
-        for j in 0..board.grid[i].len() {
-            let x = j as f32 * 45.0;
-            let y = i as f32 * 45.0;
-            let _ = write!(
-                svg,
-                r#"<rect x="{}" y="{}" width="40" height="40" fill="rgb(51, 65, 85)"/>"#,
-                x, y
-            );
-        }
-    }
+use spaces_game::engine::deck::Deck;
+use spaces_game::engine::game_board::GameBoard;
+use spaces_game::engine::layout::Layout;
+use spaces_game::engine::player_model::PlayerModel;
+use spaces_game::engine::strategy::StrategyBook;
+use spaces_game::export;
+use spaces_game::i18n::{translate, Arg, Locale};
+use spaces_game::render::svg::{self, RenderOptions, Theme};
+use super::board::{Board, SavedBoard};
+use super::game::GameState;
+
+pub static BOARD_THEME: std::sync::OnceLock<RwSignal<Theme>> = std::sync::OnceLock::new();
+
+pub fn get_board_theme() -> RwSignal<Theme> {
+    *BOARD_THEME.get_or_init(|| RwSignal::new(Theme::default()))
+}
 
-    // Draw pieces and traps with rotation
-    for (idx, &(i, j, ref content)) in board.sequence.iter().enumerate() {
-        // Rotate the position 180 degrees
-        let size = board.grid.len();
-        let (rot_i, rot_j) = (size - 1 - i, size - 1 - j);
-        let x = rot_j as f32 * 45.0;
-        let y = rot_i as f32 * 45.0;
-
-        // Skip the final move (when piece reaches bottom row in rotated view)
-        if rot_i == size - 1 && idx == board.sequence.len() - 1 {
-            continue;
-        }
+pub static LOCALE: std::sync::OnceLock<RwSignal<Locale>> = std::sync::OnceLock::new();
 
-        match content {
-            CellContent::Player => {
-                let _ = write!(
-                    svg,
-                    r#"<circle cx="{:.0}" cy="{:.0}" r="15" fill="rgb(147, 51, 234)"/>
-                    <text x="{:.0}" y="{:.0}" font-size="16" fill="white" text-anchor="middle" dy=".3em">{}</text>"#,
-                    x + 20.0, y + 20.0, x + 20.0, y + 20.0, idx + 1
-                );
-            },
-            CellContent::Trap => {
-                let _ = write!(
-                    svg,
-                    r#"<path d="M{} {} l30 30 m0 -30 l-30 30" stroke="rgb(249, 115, 22)" stroke-width="4" opacity="0.6"/>"
-                    <text x="{:.0}" y="{:.0}" font-size="16" fill="rgb(249, 115, 22)" text-anchor="middle" dy=".3em">{}</text>"#,
-                    x + 5.0, y + 5.0, x + 35.0, y + 20.0, idx + 1
-                );
-            },
-            _ => {}
-        }
-    }
+pub fn get_locale() -> RwSignal<Locale> {
+    *LOCALE.get_or_init(|| RwSignal::new(Locale::default()))
+}
 
-    svg.push_str("</g></svg>");
-    format!(r#"data:image/svg+xml,{}"#, urlencoding::encode(&svg))
-}
-
-pub fn generate_thumbnail(board: &Board) -> String {
-    let mut svg = String::from(r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 100 100">
-            <rect width="100" height="100" fill="rgb(30, 41, 59)"/>
-            <g transform="translate(5,5)">"#);
-
-    // Draw grid
-    for i in 0..board.grid.len() {
-        for j in 0..board.grid[i].len() {
-            let x = j as f32 * 45.0;
-            let y = i as f32 * 45.0;
-            let _ = write!(
-                svg,
-                r#"<rect x="{}" y="{}" width="40" height="40" fill="rgb(51, 65, 85)"/>"#,
-                x, y
-            );
-        }
-    }
+/// Message `id` in the player's language.
+pub fn t(id: &str) -> String {
+    translate(get_locale().get(), id, &[])
+}
 
-    // Draw pieces and traps
-    for (idx, &(i, j, ref content)) in board.sequence.iter().enumerate() {
-        let x = j as f32 * 45.0;
-        let y = i as f32 * 45.0;
+/// Message `id` with `args` in the player's language.
+pub fn t_with(id: &str, args: &[(&str, Arg)]) -> String {
+    translate(get_locale().get(), id, args)
+}
 
-        // Skip the final move (when piece reaches top row)
-        if i == 0 && idx == board.sequence.len() - 1 {
-            continue;
-        }
+pub fn generate_opponent_thumbnail(board: &Board, layout: Option<&Layout>) -> String {
+    let options = RenderOptions::opponent().with_theme(get_board_theme().get());
+    svg::data_uri(&svg::board_svg_on(board, layout, &options))
+}
 
-        match content {
-            CellContent::Player => {
-                let _ = write!(
-                    svg,
-                    r#"<circle cx="{:.0}" cy="{:.0}" r="15" fill="rgb(37, 99, 235)"/>
-                       <text x="{:.0}" y="{:.0}" font-size="16" fill="white" text-anchor="middle" dy=".3em">{}</text>"#,
-                    x + 20.0, y + 20.0, x + 20.0, y + 20.0, idx + 1
-                );
-            },
-            CellContent::Trap => {
-                let _ = write!(
-                    svg,
-                    r#"<path d="M{} {} l30 30 m0 -30 l-30 30" stroke="rgb(220, 38, 38)" stroke-width="4" opacity="0.6"/>"
-                    <text x="{:.0}" y="{:.0}" font-size="16" fill="rgb(220, 38, 38)" text-anchor="middle" dy=".3em">{}</text>"#,
-                    x + 5.0, y + 5.0, x + 35.0, y + 20.0, idx + 1
-                );
-            },
-            _ => {},
-        }
-    }
+pub fn generate_thumbnail(board: &Board, layout: Option<&Layout>) -> String {
+    let options = RenderOptions::default().with_theme(get_board_theme().get());
+    svg::data_uri(&svg::board_svg_on(board, layout, &options))
+}
 
-    svg.push_str("</g></svg>");
-    format!(r#"data:image/svg+xml,{}"#, urlencoding::encode(&svg))
+pub fn generate_round_image(game_board: &GameBoard) -> String {
+    let options = RenderOptions::default().with_theme(get_board_theme().get());
+    svg::data_uri(&svg::round_svg(game_board, &options))
 }
 
-pub fn save_board(board: Board) -> Result<Vec<SavedBoard>, serde_json::Error> {
+pub fn save_board(board: Board, layout: Option<Layout>) -> Result<Vec<SavedBoard>, serde_json::Error> {
     let storage = window().unwrap().local_storage().unwrap().unwrap();
-    let thumbnail = generate_thumbnail(&board);
-    let saved_board = SavedBoard { board, thumbnail };    
+    let thumbnail = generate_thumbnail(&board, layout.as_ref());
+    let saved_board = SavedBoard { board, thumbnail, layout };    
 
     // Load existing boards first
     let mut saved_boards = load_saved_boards().unwrap_or_default();
@@ -142,3 +79,142 @@ pub fn delete_board(index: usize) -> Result<(), serde_json::Error> {
     storage.set_item("saved_boards", &json).unwrap();
     Ok(())
 }
+
+pub fn load_decks() -> Vec<Deck> {
+    let storage = window().unwrap().local_storage().unwrap().unwrap();
+    storage
+        .get_item("decks")
+        .ok()
+        .flatten()
+        .and_then(|data| serde_json::from_str(&data).ok())
+        .unwrap_or_default()
+}
+
+pub fn save_decks(decks: &[Deck]) -> Result<(), serde_json::Error> {
+    let storage = window().unwrap().local_storage().unwrap().unwrap();
+    let json = serde_json::to_string(decks)?;
+    storage.set_item("decks", &json).unwrap();
+    Ok(())
+}
+
+/// Keeps the match in progress so it survives a reload.
+pub fn save_match(state: &GameState) {
+    let storage = window().unwrap().local_storage().unwrap().unwrap();
+    match serde_json::to_string(state) {
+        Ok(json) => storage.set_item("current_match", &json).unwrap(),
+        Err(e) => web_sys::console::log_1(&format!("Failed to save match: {}", e).into()),
+    }
+}
+
+pub fn load_match() -> Option<GameState> {
+    let storage = window().unwrap().local_storage().unwrap().unwrap();
+    let data = storage.get_item("current_match").ok()??;
+    serde_json::from_str::<GameState>(&data).ok().map(GameState::resumed)
+}
+
+pub fn clear_match() {
+    let storage = window().unwrap().local_storage().unwrap().unwrap();
+    storage.remove_item("current_match").unwrap();
+}
+
+/// The models stored under `key`, by player name or opponent id.
+fn load_models(key: &str) -> HashMap<String, PlayerModel> {
+    let storage = window().unwrap().local_storage().unwrap().unwrap();
+    storage
+        .get_item(key)
+        .ok()
+        .flatten()
+        .and_then(|data| serde_json::from_str(&data).ok())
+        .unwrap_or_default()
+}
+
+fn save_model(key: &str, name: &str, model: &PlayerModel) {
+    let storage = window().unwrap().local_storage().unwrap().unwrap();
+    let mut models = load_models(key);
+    models.insert(name.to_string(), model.clone());
+    match serde_json::to_string(&models) {
+        Ok(json) => storage.set_item(key, &json).unwrap(),
+        Err(e) => web_sys::console::log_1(&format!("Failed to save {}: {}", key, e).into()),
+    }
+}
+
+/// What the computer opponents have learned about the player's boards.
+pub fn load_player_model(player: &str) -> PlayerModel {
+    load_models("player_models").remove(player).unwrap_or_default()
+}
+
+pub fn save_player_model(player: &str, model: &PlayerModel) {
+    save_model("player_models", player, model);
+}
+
+/// The boards an opponent has played, for the board hints.
+pub fn load_opponent_model(opponent_id: &str) -> PlayerModel {
+    load_models("opponent_models").remove(opponent_id).unwrap_or_default()
+}
+
+pub fn save_opponent_model(opponent_id: &str, model: &PlayerModel) {
+    save_model("opponent_models", opponent_id, model);
+}
+
+/// Keeps what was learned about an opponent when its ID changes.
+pub fn rename_opponent_model(old_id: &str, new_id: &str) {
+    if let Some(model) = load_models("opponent_models").remove(old_id) {
+        save_model("opponent_models", new_id, &model);
+    }
+}
+
+/// The Expert CPU's strategies, trained with `spaces-cli train`.
+pub fn expert_book() -> StrategyBook {
+    serde_json::from_str(include_str!("../../strategies/expert.json")).unwrap_or_default()
+}
+
+fn base64_encode(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut out = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let n = (chunk[0] as u32) << 16
+            | (*chunk.get(1).unwrap_or(&0) as u32) << 8
+            | *chunk.get(2).unwrap_or(&0) as u32;
+        for i in 0..4 {
+            if i <= chunk.len() {
+                out.push(ALPHABET[(n >> (18 - 6 * i)) as usize & 63] as char);
+            } else {
+                out.push('=');
+            }
+        }
+    }
+    out
+}
+
+/// Hands `bytes` to the browser as a file download.
+pub fn download_file(file_name: &str, mime: &str, bytes: &[u8]) {
+    use leptos::wasm_bindgen::JsCast;
+
+    let Some(document) = window().and_then(|w| w.document()) else {
+        return;
+    };
+    let Ok(element) = document.create_element("a") else {
+        return;
+    };
+    if let Ok(anchor) = element.dyn_into::<web_sys::HtmlAnchorElement>() {
+        anchor.set_href(&format!("data:{};base64,{}", mime, base64_encode(bytes)));
+        anchor.set_download(file_name);
+        anchor.click();
+    }
+}
+
+pub fn download_round_png(game_board: &GameBoard) {
+    let options = RenderOptions::default().with_theme(get_board_theme().get_untracked());
+    match export::round_png(game_board, &options, 600) {
+        Ok(bytes) => download_file("spaces-round.png", "image/png", &bytes),
+        Err(e) => web_sys::console::log_1(&format!("Failed to export round: {}", e).into()),
+    }
+}
+
+pub fn download_round_replay(game_board: &GameBoard) {
+    let options = RenderOptions::default().with_theme(get_board_theme().get_untracked());
+    match export::replay_gif(game_board, &options, 400) {
+        Ok(bytes) => download_file("spaces-replay.gif", "image/gif", &bytes),
+        Err(e) => web_sys::console::log_1(&format!("Failed to export replay: {}", e).into()),
+    }
+}
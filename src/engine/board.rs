@@ -0,0 +1,230 @@
+use std::fmt;
+
+use serde::{Serialize, Deserialize};
+
+use super::layout::{Layout, Tile};
+use super::movement::Movement;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum CellContent {
+    Empty,
+    Player,
+    Trap,
+    Final,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Board {
+    pub grid: Vec<Vec<CellContent>>,
+    pub size: usize,
+    pub sequence: Vec<(usize, usize, CellContent)>  // Replace moves and traps
+}
+
+impl Board {
+    pub fn new(size: usize) -> Self {
+        Board {
+            grid: vec![vec![CellContent::Empty; size]; size],
+            size,
+            sequence: Vec::new(),
+        }
+    }
+
+    /// Replays the sequence with the same rules `BoardCreator` enforces under
+    /// `movement` and checks that it ends with a final move and matches the
+    /// stored grid.
+    pub fn validate(&self, movement: &Movement) -> Result<(), BoardError> {
+        // `size` is untrusted until it matches the grid, so check it before
+        // building a layout of that size
+        self.check_size()?;
+        self.validate_on(movement, &Layout::open(self.size))
+    }
+
+    /// Like `validate`, for a board built on `layout`.
+    pub fn validate_on(&self, movement: &Movement, layout: &Layout) -> Result<(), BoardError> {
+        self.check_size()?;
+        if layout.size() != self.size {
+            return Err(BoardError::LayoutSizeMismatch);
+        }
+
+        let mut grid = vec![vec![CellContent::Empty; self.size]; self.size];
+        let mut position: Option<(usize, usize)> = None;
+        let mut finished = false;
+
+        for (step, &(row, col, ref content)) in self.sequence.iter().enumerate() {
+            if finished {
+                return Err(BoardError::StepAfterFinal { step });
+            }
+            if row >= self.size || col >= self.size {
+                return Err(BoardError::OutOfBounds { step, row, col });
+            }
+            match (position, content) {
+                (None, CellContent::Player) => {
+                    if row != self.size - 1 || !matches!(layout.tile(row, col), Tile::Open | Tile::Bonus) {
+                        return Err(BoardError::InvalidStart { row, col });
+                    }
+                    grid[row][col] = CellContent::Player;
+                    position = Some((row, col));
+                }
+                (None, _) => return Err(BoardError::MissingStart),
+                (Some((player_row, player_col)), CellContent::Final) => {
+                    if player_row != 0 || row != 0 || col != player_col {
+                        return Err(BoardError::IllegalStep { step, row, col });
+                    }
+                    grid[player_row][player_col] = CellContent::Empty;
+                    finished = true;
+                }
+                (Some(from), CellContent::Player | CellContent::Trap) => {
+                    if !placeable(&grid, layout, movement, from, (row, col), content) {
+                        return Err(BoardError::IllegalStep { step, row, col });
+                    }
+                    if *content == CellContent::Player {
+                        let landing = layout.partner(row, col).unwrap_or((row, col));
+                        grid[from.0][from.1] = CellContent::Empty;
+                        grid[landing.0][landing.1] = CellContent::Player;
+                        position = Some(landing);
+                    } else {
+                        grid[row][col] = CellContent::Trap;
+                    }
+                }
+                (Some(_), CellContent::Empty) => {
+                    return Err(BoardError::IllegalStep { step, row, col });
+                }
+            }
+        }
+
+        if position.is_none() {
+            return Err(BoardError::MissingStart);
+        }
+        if !finished {
+            return Err(BoardError::Unfinished);
+        }
+        if grid != self.grid {
+            return Err(BoardError::GridMismatch);
+        }
+        Ok(())
+    }
+
+    fn check_size(&self) -> Result<(), BoardError> {
+        if self.size == 0 {
+            return Err(BoardError::EmptyBoard);
+        }
+        if self.grid.len() != self.size || self.grid.iter().any(|row| row.len() != self.size) {
+            return Err(BoardError::GridSizeMismatch);
+        }
+        Ok(())
+    }
+
+    /// Whether the piece at `from` may move to (`content` is `Player`) or
+    /// trap (`Trap`) the square `to` next.
+    pub fn can_place(
+        &self,
+        layout: &Layout,
+        movement: &Movement,
+        from: (usize, usize),
+        to: (usize, usize),
+        content: &CellContent,
+    ) -> bool {
+        placeable(&self.grid, layout, movement, from, to, content)
+    }
+}
+
+fn placeable(
+    grid: &[Vec<CellContent>],
+    layout: &Layout,
+    movement: &Movement,
+    from: (usize, usize),
+    (row, col): (usize, usize),
+    content: &CellContent,
+) -> bool {
+    let tile = layout.tile(row, col);
+    let reachable = match content {
+        CellContent::Player => {
+            movement.can_move(from, (row, col))
+                && tile.enterable()
+                // A teleporter's exit has to be free as well
+                && layout.partner(row, col).is_none_or(|(r, c)| grid[r][c] == CellContent::Empty)
+        }
+        CellContent::Trap => movement.can_trap(from, (row, col)) && tile.trappable(),
+        _ => false,
+    };
+    reachable && layout.clear_path(from, (row, col)) && grid[row][col] == CellContent::Empty
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum BoardError {
+    EmptyBoard,
+    GridSizeMismatch,
+    MissingStart,
+    LayoutSizeMismatch,
+    InvalidStart { row: usize, col: usize },
+    OutOfBounds { step: usize, row: usize, col: usize },
+    IllegalStep { step: usize, row: usize, col: usize },
+    StepAfterFinal { step: usize },
+    Unfinished,
+    GridMismatch,
+}
+
+impl fmt::Display for BoardError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BoardError::EmptyBoard => write!(f, "board has size 0"),
+            BoardError::GridSizeMismatch => write!(f, "grid dimensions do not match board size"),
+            BoardError::MissingStart => write!(f, "sequence does not start with a player move"),
+            BoardError::LayoutSizeMismatch => write!(f, "board size does not match the layout"),
+            BoardError::InvalidStart { row, col } => {
+                write!(f, "start ({}, {}) is not an open square on the bottom row", row, col)
+            }
+            BoardError::OutOfBounds { step, row, col } => {
+                write!(f, "step {}: ({}, {}) is outside the board", step, row, col)
+            }
+            BoardError::IllegalStep { step, row, col } => {
+                write!(f, "step {}: ({}, {}) is not reachable from the piece", step, row, col)
+            }
+            BoardError::StepAfterFinal { step } => write!(f, "step {}: comes after the final move", step),
+            BoardError::Unfinished => write!(f, "sequence never reaches the goal"),
+            BoardError::GridMismatch => write!(f, "grid does not match the replayed sequence"),
+        }
+    }
+}
+
+impl std::error::Error for BoardError {}
+
+#[derive(Clone, Serialize, Deserialize, PartialEq)]
+pub struct SavedBoard {
+    pub board: Board,
+    pub thumbnail: String,
+    /// The map the board was built on; `None` for an open board.
+    #[serde(default)]
+    pub layout: Option<Layout>,
+}
+
+pub fn has_valid_moves(board: &Board, movement: &Movement, layout: &Layout) -> bool {
+    if let Some(from) = find_player(board) {
+        // First check if player is in top row - they always have the final move available
+        if from.0 == 0 {
+            return true;
+        }
+
+        // Check for a square to move to or trap
+        return movement
+            .move_targets(board.size, from)
+            .into_iter()
+            .any(|to| board.can_place(layout, movement, from, to, &CellContent::Player))
+            || movement
+                .trap_targets(board.size, from)
+                .into_iter()
+                .any(|to| board.can_place(layout, movement, from, to, &CellContent::Trap));
+    }
+    false
+}
+
+pub fn find_player(board: &Board) -> Option<(usize, usize)> {
+    for i in 0..board.size {
+        for j in 0..board.size {
+            if matches!(board.grid[i][j], CellContent::Player) {
+                return Some((i, j));
+            }
+        }
+    }
+    None
+}
@@ -0,0 +1,137 @@
+//! Decks: named subsets of the saved boards, picked before a match, with a
+//! rule for which of their boards are offered each round.
+
+use std::fmt;
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+use serde::{Serialize, Deserialize};
+
+use super::board::Board;
+use super::match_state::MatchConfig;
+
+/// Boards in a hand unless the deck says otherwise.
+pub const HAND_SIZE: usize = 4;
+
+/// The name of the deck the saved boards' stars add to.
+pub const FAVORITES: &str = "Favorites";
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum DeckRule {
+    /// Every board of the deck, every round.
+    #[default]
+    Open,
+    /// Each board may be played once per match.
+    SingleUse,
+    /// A hand of this many boards, drawn at random each round.
+    Hand(usize),
+}
+
+impl DeckRule {
+    pub const ALL: [DeckRule; 3] = [DeckRule::Open, DeckRule::SingleUse, DeckRule::Hand(HAND_SIZE)];
+
+    /// The value the rule is stored under in the deck settings.
+    pub fn key(&self) -> &'static str {
+        match self {
+            DeckRule::Open => "open",
+            DeckRule::SingleUse => "single-use",
+            DeckRule::Hand(_) => "hand",
+        }
+    }
+
+    pub fn label(&self) -> String {
+        match self {
+            DeckRule::Open => "Every board, every round".to_string(),
+            DeckRule::SingleUse => "Each board once per match".to_string(),
+            DeckRule::Hand(size) => format!("Hand of {} each round", size),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum DeckError {
+    /// None of the deck's boards can be played in the match.
+    NoPlayableBoards,
+    /// A single-use deck runs out before the match ends.
+    TooFewBoards { playable: usize, rounds: usize },
+}
+
+impl fmt::Display for DeckError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DeckError::NoPlayableBoards => write!(f, "none of the deck's boards can be played in this match"),
+            DeckError::TooFewBoards { playable, rounds } => {
+                write!(f, "the deck has {} playable boards for a match of {} rounds", playable, rounds)
+            }
+        }
+    }
+}
+
+impl std::error::Error for DeckError {}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Deck {
+    pub name: String,
+    pub boards: Vec<Board>,
+    #[serde(default)]
+    pub rule: DeckRule,
+}
+
+impl Deck {
+    pub fn new(name: impl Into<String>, rule: DeckRule) -> Self {
+        Deck { name: name.into(), boards: Vec::new(), rule }
+    }
+
+    pub fn contains(&self, board: &Board) -> bool {
+        self.boards.contains(board)
+    }
+
+    /// Adds `board` if the deck does not have it, and removes it if it does.
+    pub fn toggle(&mut self, board: &Board) {
+        match self.boards.iter().position(|b| b == board) {
+            Some(index) => {
+                self.boards.remove(index);
+            }
+            None => self.boards.push(board.clone()),
+        }
+    }
+
+    /// The deck's boards that can be played in a match under `config`.
+    pub fn playable(&self, config: &MatchConfig) -> Vec<Board> {
+        self.boards.iter().filter(|board| config.check(board).is_ok()).cloned().collect()
+    }
+
+    /// Whether the deck can see a match under `config` through.
+    pub fn check(&self, config: &MatchConfig) -> Result<(), DeckError> {
+        let playable = self.playable(config).len();
+        match self.rule {
+            _ if playable == 0 => Err(DeckError::NoPlayableBoards),
+            DeckRule::SingleUse if playable < config.rounds => {
+                Err(DeckError::TooFewBoards { playable, rounds: config.rounds })
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// The boards on offer for the next round of a match under `config`,
+    /// given the boards already `played` in it.
+    pub fn deal<R: Rng + ?Sized>(&self, config: &MatchConfig, played: &[Board], rng: &mut R) -> Vec<Board> {
+        let mut available = self.playable(config);
+        match self.rule {
+            DeckRule::Open => available,
+            DeckRule::SingleUse => {
+                for board in played {
+                    if let Some(index) = available.iter().position(|b| b == board) {
+                        available.remove(index);
+                    }
+                }
+                available
+            }
+            DeckRule::Hand(size) => {
+                available.shuffle(rng);
+                available.truncate(size);
+                available
+            }
+        }
+    }
+}
@@ -0,0 +1,80 @@
+use super::board::{Board, CellContent};
+use super::layout::{Layout, Tile};
+use super::movement::Movement;
+
+/// Every board of `size` legal under `movement` whose sequence (including the
+/// final move) is at most `max_steps` long. Sideways moves can revisit emptied
+/// squares, so the step limit is what keeps the search finite.
+pub fn enumerate_boards(size: usize, max_steps: usize, movement: &Movement) -> Vec<Board> {
+    enumerate_boards_on(&Layout::open(size), max_steps, movement)
+}
+
+/// Like `enumerate_boards`, for boards built on `layout`.
+pub fn enumerate_boards_on(layout: &Layout, max_steps: usize, movement: &Movement) -> Vec<Board> {
+    let size = layout.size();
+    let mut boards = Vec::new();
+    if size == 0 || max_steps < 2 {
+        return boards;
+    }
+
+    for col in 0..size {
+        if !matches!(layout.tile(size - 1, col), Tile::Open | Tile::Bonus) {
+            continue;
+        }
+        let mut board = Board::new(size);
+        board.grid[size - 1][col] = CellContent::Player;
+        board.sequence.push((size - 1, col, CellContent::Player));
+        extend(&mut board, layout, (size - 1, col), max_steps, movement, &mut boards);
+    }
+    boards
+}
+
+fn extend(
+    board: &mut Board,
+    layout: &Layout,
+    (row, col): (usize, usize),
+    max_steps: usize,
+    movement: &Movement,
+    boards: &mut Vec<Board>,
+) {
+    if board.sequence.len() >= max_steps {
+        return;
+    }
+
+    if row == 0 {
+        let mut finished = board.clone();
+        finished.grid[0][col] = CellContent::Empty;
+        finished.sequence.push((0, col, CellContent::Final));
+        boards.push(finished);
+    }
+
+    // Every step after the final one needs room for the final move as well
+    if board.sequence.len() + 1 >= max_steps {
+        return;
+    }
+
+    for target in movement.move_targets(board.size, (row, col)) {
+        if !board.can_place(layout, movement, (row, col), target, &CellContent::Player) {
+            continue;
+        }
+        let (landing_row, landing_col) = layout.partner(target.0, target.1).unwrap_or(target);
+        board.grid[row][col] = CellContent::Empty;
+        board.grid[landing_row][landing_col] = CellContent::Player;
+        board.sequence.push((target.0, target.1, CellContent::Player));
+        extend(board, layout, (landing_row, landing_col), max_steps, movement, boards);
+        board.sequence.pop();
+        board.grid[landing_row][landing_col] = CellContent::Empty;
+        board.grid[row][col] = CellContent::Player;
+    }
+
+    for (target_row, target_col) in movement.trap_targets(board.size, (row, col)) {
+        if !board.can_place(layout, movement, (row, col), (target_row, target_col), &CellContent::Trap) {
+            continue;
+        }
+        board.grid[target_row][target_col] = CellContent::Trap;
+        board.sequence.push((target_row, target_col, CellContent::Trap));
+        extend(board, layout, (row, col), max_steps, movement, boards);
+        board.sequence.pop();
+        board.grid[target_row][target_col] = CellContent::Empty;
+    }
+}
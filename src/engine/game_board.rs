@@ -0,0 +1,557 @@
+use std::fmt;
+use serde::{Serialize, Deserialize};
+use super::board::{Board, CellContent};
+use super::layout::{Layout, Tile};
+use super::rules::{CollisionRule, Ruleset};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Side {
+    Player,
+    Opponent,
+}
+
+impl fmt::Display for Side {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Side::Player => write!(f, "Player"),
+            Side::Opponent => write!(f, "Opponent"),
+        }
+    }
+}
+
+/// Everything that happened while resolving a round, in the order it was
+/// applied. Positions are in the player's orientation.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RoundEvent {
+    Moved { side: Side, step: usize, row: usize, col: usize },
+    Teleported { side: Side, step: usize, row: usize, col: usize },
+    TrapPlaced { side: Side, step: usize, row: usize, col: usize },
+    GoalReached { side: Side, step: usize },
+    ForwardPoint { side: Side, step: usize, score: i32 },
+    SidewaysPoint { side: Side, step: usize, score: i32 },
+    GoalPoint { side: Side, step: usize, score: i32 },
+    BonusPoint { side: Side, step: usize, score: i32 },
+    Collision { step: usize, row: usize, col: usize },
+    CollisionPenalty { side: Side, step: usize, score: i32 },
+    Bounced { side: Side, step: usize, row: usize, col: usize, score: i32 },
+    TrapHit { side: Side, step: usize, row: usize, col: usize },
+    NeutralTrapHit { side: Side, step: usize, row: usize, col: usize },
+    TrapPenalty { side: Side, step: usize, score: i32 },
+    RoundEnded { step: usize },
+}
+
+impl fmt::Display for RoundEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RoundEvent::Moved { side, step, row, col } => {
+                write!(f, "Step {}: {} moving to ({}, {})", step, side, row, col)
+            }
+            RoundEvent::Teleported { side, step, row, col } => {
+                write!(f, "Step {}: {} teleported to ({}, {})", step, side, row, col)
+            }
+            RoundEvent::TrapPlaced { side, step, row, col } => {
+                write!(f, "Step {}: {} placed trap at ({}, {})", step, side, row, col)
+            }
+            RoundEvent::GoalReached { side, step } => write!(f, "Step {}: {} reached goal!", step, side),
+            RoundEvent::ForwardPoint { side, step, score } => {
+                write!(f, "Step {}: {} scored forward move point! Score now {}", step, side, score)
+            }
+            RoundEvent::SidewaysPoint { side, step, score } => {
+                write!(f, "Step {}: {} scored sideways move point! Score now {}", step, side, score)
+            }
+            RoundEvent::GoalPoint { side, step, score } => {
+                write!(f, "Step {}: {} scored goal point! Score now {}", step, side, score)
+            }
+            RoundEvent::BonusPoint { side, step, score } => {
+                write!(f, "Step {}: {} scored bonus point! Score now {}", step, side, score)
+            }
+            RoundEvent::Collision { step, row, col } => {
+                write!(f, "Step {}: COLLISION at square ({}, {})!", step, row, col)
+            }
+            RoundEvent::CollisionPenalty { side, step, score } => {
+                write!(f, "Step {}: {} lost point from collision! Score now {}", step, side, score)
+            }
+            RoundEvent::Bounced { side, step, row, col, score } => {
+                write!(f, "Step {}: {} bounced back to ({}, {})! Score now {}", step, side, row, col, score)
+            }
+            RoundEvent::TrapHit { side, step, row, col } => {
+                let owner = match side {
+                    Side::Player => "opponent",
+                    Side::Opponent => "player",
+                };
+                write!(f, "Step {}: {} hit {} trap at ({}, {})!", step, side, owner, row, col)
+            }
+            RoundEvent::NeutralTrapHit { side, step, row, col } => {
+                write!(f, "Step {}: {} hit neutral trap at ({}, {})!", step, side, row, col)
+            }
+            RoundEvent::TrapPenalty { side, step, score } => {
+                write!(f, "Step {}: {} lost point from trap! Score now {}", step, side, score)
+            }
+            RoundEvent::RoundEnded { step } => write!(f, "Step {}: Round over", step),
+        }
+    }
+}
+
+impl RoundEvent {
+    pub fn step(&self) -> usize {
+        match *self {
+            RoundEvent::Moved { step, .. }
+            | RoundEvent::Teleported { step, .. }
+            | RoundEvent::TrapPlaced { step, .. }
+            | RoundEvent::GoalReached { step, .. }
+            | RoundEvent::ForwardPoint { step, .. }
+            | RoundEvent::SidewaysPoint { step, .. }
+            | RoundEvent::GoalPoint { step, .. }
+            | RoundEvent::BonusPoint { step, .. }
+            | RoundEvent::Collision { step, .. }
+            | RoundEvent::CollisionPenalty { step, .. }
+            | RoundEvent::Bounced { step, .. }
+            | RoundEvent::TrapHit { step, .. }
+            | RoundEvent::NeutralTrapHit { step, .. }
+            | RoundEvent::TrapPenalty { step, .. }
+            | RoundEvent::RoundEnded { step } => step,
+        }
+    }
+}
+
+/// What happened on one square during a round; every value is a step index.
+#[derive(Debug, Clone)]
+pub struct Square {
+    pub player_trap_step: Option<usize>,
+    pub opponent_trap_step: Option<usize>,
+    pub player_visits: Vec<usize>,
+    pub opponent_visits: Vec<usize>,
+    pub collision_step: Option<usize>,
+    pub player_trap_hit_step: Option<usize>,
+    pub opponent_trap_hit_step: Option<usize>,
+}
+
+impl Square {
+    fn new() -> Self {
+        Square {
+            player_trap_step: None,
+            opponent_trap_step: None,
+            player_visits: Vec::new(),
+            opponent_visits: Vec::new(),
+            collision_step: None,
+            player_trap_hit_step: None,
+            opponent_trap_hit_step: None,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct GameBoard {
+    squares: Vec<Vec<Square>>,
+    pub size: usize,
+    pub ruleset: Ruleset,
+    /// The map the round is played on; `None` for an open board.
+    pub layout: Option<Layout>,
+    pub player_sequence: Vec<(usize, usize, CellContent)>,
+    pub opponent_sequence: Vec<(usize, usize, CellContent)>,
+    pub player_position: Option<(usize, usize)>,
+    pub opponent_position: Option<(usize, usize)>,
+    pub player_collision_step: Option<usize>,
+    pub opponent_collision_step: Option<usize>,
+    pub player_score: i32,
+    pub opponent_score: i32,
+    pub player_round_ended: bool,
+    pub opponent_round_ended: bool,
+    pub player_goal_reached: bool,
+    pub opponent_goal_reached: bool,
+    pub events: Vec<RoundEvent>,
+}
+    
+impl GameBoard {
+    pub fn new(size: usize) -> Self {
+        Self::with_ruleset(size, Ruleset::default())
+    }
+
+    pub fn with_ruleset(size: usize, ruleset: Ruleset) -> Self {
+        let mut squares = Vec::with_capacity(size);
+        for _ in 0..size {
+            let mut row = Vec::with_capacity(size);
+            for _ in 0..size {
+                row.push(Square::new());
+            }
+            squares.push(row);
+        }
+        GameBoard {
+            size,
+            ruleset,
+            layout: None,
+            player_sequence: Vec::new(),
+            opponent_sequence: Vec::new(),
+            player_position: None,
+            opponent_position: None,
+            player_collision_step: None,
+            opponent_collision_step: None,
+            player_score: 0,
+            opponent_score: 0,
+            squares,
+            player_round_ended: false,
+            opponent_round_ended: false,
+            player_goal_reached: false,
+            opponent_goal_reached: false,
+            events: Vec::new(),
+        }
+    }
+
+    /// A board the size of `layout`, resolved on it.
+    pub fn with_layout(ruleset: Ruleset, layout: Layout) -> Self {
+        let mut game_board = Self::with_ruleset(layout.size(), ruleset);
+        game_board.layout = Some(layout);
+        game_board
+    }
+
+    pub fn square(&self, row: usize, col: usize) -> Option<&Square> {
+        self.squares.get(row).and_then(|r| r.get(col))
+    }
+
+    /// The round as it stood after each step it ran for, re-resolved from the
+    /// stored sequences; the last entry matches `self`.
+    pub fn replay_steps(&self) -> Vec<GameBoard> {
+        let steps = self.events.last().map_or(0, |event| event.step() + 1);
+        (0..steps)
+            .map(|step| {
+                let mut board = GameBoard::with_ruleset(self.size, self.ruleset);
+                board.layout = self.layout.clone();
+                board.player_sequence = self.player_sequence.clone();
+                board.opponent_sequence = self.opponent_sequence.clone();
+                board.resolve(step);
+                board
+            })
+            .collect()
+    }
+
+    fn tile(&self, row: usize, col: usize) -> Tile {
+        self.layout.as_ref().map_or(Tile::Open, |layout| layout.tile(row, col))
+    }
+
+    /// Where a piece that moved onto (`row`, `col`) comes out, if that is a
+    /// teleporter.
+    fn teleport(&self, row: usize, col: usize) -> Option<(usize, usize)> {
+        self.layout.as_ref().and_then(|layout| layout.partner(row, col))
+    }
+
+    fn rotate_position(&self, row: usize, col: usize) -> (usize, usize) {
+        (self.size - 1 - row, self.size - 1 - col)
+    }
+    
+    pub fn process_turn(&mut self, player_board: &Board, opponent_board: &Board) {
+        self.player_sequence = player_board.sequence.clone();
+        self.opponent_sequence = opponent_board.sequence.clone();
+        self.resolve(usize::MAX);
+    }
+
+    /// Plays the stored sequences against each other, stopping after
+    /// `last_step` if the round runs that long.
+    fn resolve(&mut self, last_step: usize) {
+        let rules = self.ruleset;
+
+        // Reset game state
+        self.player_score = 0;
+        self.opponent_score = 0;
+        self.player_position = None;
+        self.opponent_position = None;
+        self.player_round_ended = false;
+        self.opponent_round_ended = false;
+        self.events.clear();
+
+        // Index of each side's next sequence entry; a bounce replays an entry
+        // so these can fall behind the step count
+        let mut player_next = 0;
+        let mut opponent_next = 0;
+        let mut bounced_last_step = false;
+        // Bonus squares each side has scored
+        let mut player_bonuses: Vec<(usize, usize)> = Vec::new();
+        let mut opponent_bonuses: Vec<(usize, usize)> = Vec::new();
+    
+        'step_loop: for step in 0..=last_step {
+            let player_playing = !self.player_round_ended && player_next < self.player_sequence.len();
+            let opponent_playing = !self.opponent_round_ended && opponent_next < self.opponent_sequence.len();
+            if !player_playing && !opponent_playing {
+                break;
+            }
+            let (player_from, player_score_from) = (self.player_position, self.player_score);
+            let (opponent_from, opponent_score_from) = (self.opponent_position, self.opponent_score);
+            let (player_bonuses_from, opponent_bonuses_from) = (player_bonuses.len(), opponent_bonuses.len());
+            let mut player_moved = false;
+            let mut opponent_moved = false;
+
+            // Process player's move
+            if player_playing {
+                let (row, col, content) = self.player_sequence[player_next].clone();
+                player_next += 1;
+                let in_bounds = row < self.size && col < self.size;
+                match content {
+                    _ if !in_bounds || (content != CellContent::Final && self.tile(row, col) == Tile::Wall) => {
+                        // A step off the board or into a wall (only possible in a malformed board) forfeits the rest of the round
+                        self.player_round_ended = true;
+                    },
+                    CellContent::Player => {
+                        self.events.push(RoundEvent::Moved { side: Side::Player, step, row, col });
+                        if let Some((prev_row, _)) = self.player_position {
+                            if prev_row > row && rules.forward_points != 0 {
+                                self.player_score += rules.forward_points * (prev_row - row) as i32;
+                                self.events.push(RoundEvent::ForwardPoint {
+                                    side: Side::Player, step, score: self.player_score
+                                });
+                            } else if prev_row == row && rules.sideways_points != 0 {
+                                self.player_score += rules.sideways_points;
+                                self.events.push(RoundEvent::SidewaysPoint {
+                                    side: Side::Player, step, score: self.player_score
+                                });
+                            }
+                        }
+                        let (row, col) = match self.teleport(row, col) {
+                            Some((to_row, to_col)) => {
+                                self.squares[row][col].player_visits.push(step);
+                                self.events.push(RoundEvent::Teleported { side: Side::Player, step, row: to_row, col: to_col });
+                                (to_row, to_col)
+                            }
+                            None => (row, col),
+                        };
+                        self.player_position = Some((row, col));
+                        self.squares[row][col].player_visits.push(step);
+                        if self.tile(row, col) == Tile::Bonus && !player_bonuses.contains(&(row, col)) {
+                            player_bonuses.push((row, col));
+                            if rules.bonus_points != 0 {
+                                self.player_score += rules.bonus_points;
+                                self.events.push(RoundEvent::BonusPoint {
+                                    side: Side::Player, step, score: self.player_score
+                                });
+                            }
+                        }
+                        player_moved = true;
+                    },
+                    CellContent::Trap => {
+                        self.events.push(RoundEvent::TrapPlaced { side: Side::Player, step, row, col });
+                        self.squares[row][col].player_trap_step = Some(step);
+                    },
+                    CellContent::Final => {
+                        self.events.push(RoundEvent::GoalReached { side: Side::Player, step });
+                        self.player_goal_reached = true;
+                        if rules.goal_points != 0 {
+                            self.player_score += rules.goal_points;
+                            self.events.push(RoundEvent::GoalPoint { side: Side::Player, step, score: self.player_score });
+                        }
+                        self.player_round_ended = true;
+                    },
+                    _ => {}
+                }
+            }
+    
+            // Process opponent's move
+            if opponent_playing {
+                let (row, col, content) = self.opponent_sequence[opponent_next].clone();
+                opponent_next += 1;
+                let in_bounds = row < self.size && col < self.size;
+                let (rot_row, rot_col) = if in_bounds { self.rotate_position(row, col) } else { (row, col) };
+                
+                match content {
+                    _ if !in_bounds || (content != CellContent::Final && self.tile(rot_row, rot_col) == Tile::Wall) => {
+                        // A step off the board or into a wall (only possible in a malformed board) forfeits the rest of the round
+                        self.opponent_round_ended = true;
+                    },
+                    CellContent::Player => {
+                        self.events.push(RoundEvent::Moved { side: Side::Opponent, step, row: rot_row, col: rot_col });
+                        if let Some((prev_row, _)) = self.opponent_position {
+                            if prev_row < rot_row && rules.forward_points != 0 {
+                                self.opponent_score += rules.forward_points * (rot_row - prev_row) as i32;
+                                self.events.push(RoundEvent::ForwardPoint {
+                                    side: Side::Opponent, step, score: self.opponent_score
+                                });
+                            } else if prev_row == rot_row && rules.sideways_points != 0 {
+                                self.opponent_score += rules.sideways_points;
+                                self.events.push(RoundEvent::SidewaysPoint {
+                                    side: Side::Opponent, step, score: self.opponent_score
+                                });
+                            }
+                        }
+                        let (rot_row, rot_col) = match self.teleport(rot_row, rot_col) {
+                            Some((to_row, to_col)) => {
+                                self.squares[rot_row][rot_col].opponent_visits.push(step);
+                                self.events.push(RoundEvent::Teleported { side: Side::Opponent, step, row: to_row, col: to_col });
+                                (to_row, to_col)
+                            }
+                            None => (rot_row, rot_col),
+                        };
+                        self.opponent_position = Some((rot_row, rot_col));
+                        self.squares[rot_row][rot_col].opponent_visits.push(step);
+                        if self.tile(rot_row, rot_col) == Tile::Bonus && !opponent_bonuses.contains(&(rot_row, rot_col)) {
+                            opponent_bonuses.push((rot_row, rot_col));
+                            if rules.bonus_points != 0 {
+                                self.opponent_score += rules.bonus_points;
+                                self.events.push(RoundEvent::BonusPoint {
+                                    side: Side::Opponent, step, score: self.opponent_score
+                                });
+                            }
+                        }
+                        opponent_moved = true;
+                    },
+                    CellContent::Trap => {
+                        self.events.push(RoundEvent::TrapPlaced { side: Side::Opponent, step, row: rot_row, col: rot_col });
+                        self.squares[rot_row][rot_col].opponent_trap_step = Some(step);
+                    },
+                    CellContent::Final => {
+                        self.events.push(RoundEvent::GoalReached { side: Side::Opponent, step });
+                        self.opponent_goal_reached = true;
+                        if rules.goal_points != 0 {
+                            self.opponent_score += rules.goal_points;
+                            self.events.push(RoundEvent::GoalPoint { side: Side::Opponent, step, score: self.opponent_score });
+                        }
+                        self.opponent_round_ended = true;
+                    },
+                    _ => {}
+                }
+            }
+    
+            // Check for collisions
+            let on_board = |position: Option<(usize, usize)>, goal_reached: bool| {
+                position.filter(|_| !goal_reached || rules.goal_piece_collides())
+            };
+            let player_on_board = on_board(self.player_position, self.player_goal_reached);
+            let opponent_on_board = on_board(self.opponent_position, self.opponent_goal_reached);
+            let mut bounced = false;
+            if let (Some(p_pos), Some(o_pos)) = (player_on_board, opponent_on_board) {
+                if p_pos == o_pos && (player_moved || opponent_moved) {
+                    self.events.push(RoundEvent::Collision { step, row: p_pos.0, col: p_pos.1 });
+                    self.squares[p_pos.0][p_pos.1].collision_step = Some(step);
+
+                    // Only pieces that came from a square can bounce back to it,
+                    // and a second collision in a row ends the round
+                    bounced = rules.collision == CollisionRule::Bounce
+                        && !bounced_last_step
+                        && (!player_moved || player_from.is_some())
+                        && (!opponent_moved || opponent_from.is_some());
+                    if bounced {
+                        if let (true, Some((row, col))) = (player_moved, player_from) {
+                            self.player_position = player_from;
+                            self.player_score = player_score_from;
+                            player_bonuses.truncate(player_bonuses_from);
+                            self.squares[row][col].player_visits.push(step);
+                            player_next -= 1;
+                            self.events.push(RoundEvent::Bounced {
+                                side: Side::Player, step, row, col, score: self.player_score
+                            });
+                        }
+                        if let (true, Some((row, col))) = (opponent_moved, opponent_from) {
+                            self.opponent_position = opponent_from;
+                            self.opponent_score = opponent_score_from;
+                            opponent_bonuses.truncate(opponent_bonuses_from);
+                            self.squares[row][col].opponent_visits.push(step);
+                            opponent_next -= 1;
+                            self.events.push(RoundEvent::Bounced {
+                                side: Side::Opponent, step, row, col, score: self.opponent_score
+                            });
+                        }
+                    }
+                    
+                    let score = rules.penalize(self.player_score, rules.collision_penalty);
+                    if score != self.player_score {
+                        self.player_score = score;
+                        self.events.push(RoundEvent::CollisionPenalty {
+                            side: Side::Player, step, score: self.player_score
+                        });
+                    }
+                    let score = rules.penalize(self.opponent_score, rules.collision_penalty);
+                    if score != self.opponent_score {
+                        self.opponent_score = score;
+                        self.events.push(RoundEvent::CollisionPenalty {
+                            side: Side::Opponent, step, score: self.opponent_score
+                        });
+                    }
+                    if !bounced {
+                        self.events.push(RoundEvent::RoundEnded { step });
+                        break 'step_loop;
+                    }
+                }
+            }
+            bounced_last_step = bounced;
+    
+            // Check for trap hits
+            if !self.player_round_ended {
+                if let Some((row, col)) = self.player_position {
+                    let opponent_trap = self.squares[row][col].opponent_trap_step
+                        .is_some_and(|trap_step| rules.trap_armed(trap_step, step));
+                    if opponent_trap || self.tile(row, col) == Tile::NeutralTrap {
+                        self.events.push(if opponent_trap {
+                            RoundEvent::TrapHit { side: Side::Player, step, row, col }
+                        } else {
+                            RoundEvent::NeutralTrapHit { side: Side::Player, step, row, col }
+                        });
+                        self.squares[row][col].player_trap_hit_step = Some(step);
+                        let score = rules.penalize(self.player_score, rules.trap_penalty);
+                        if score != self.player_score {
+                            self.player_score = score;
+                            self.events.push(RoundEvent::TrapPenalty {
+                                side: Side::Player, step, score: self.player_score
+                            });
+                        }
+                        self.player_round_ended = true;
+                    }
+                }
+            }
+    
+            if !self.opponent_round_ended {
+                if let Some((row, col)) = self.opponent_position {
+                    let player_trap = self.squares[row][col].player_trap_step
+                        .is_some_and(|trap_step| rules.trap_armed(trap_step, step));
+                    if player_trap || self.tile(row, col) == Tile::NeutralTrap {
+                        self.events.push(if player_trap {
+                            RoundEvent::TrapHit { side: Side::Opponent, step, row, col }
+                        } else {
+                            RoundEvent::NeutralTrapHit { side: Side::Opponent, step, row, col }
+                        });
+                        self.squares[row][col].opponent_trap_hit_step = Some(step);
+                        let score = rules.penalize(self.opponent_score, rules.trap_penalty);
+                        if score != self.opponent_score {
+                            self.opponent_score = score;
+                            self.events.push(RoundEvent::TrapPenalty {
+                                side: Side::Opponent, step, score: self.opponent_score
+                            });
+                        }
+                        self.opponent_round_ended = true;
+                    }
+                }
+            }
+    
+            // Stop if both players have ended their round
+            if self.player_round_ended && self.opponent_round_ended {
+                self.events.push(RoundEvent::RoundEnded { step });
+                break 'step_loop;
+            }
+    
+            // Stop if either players has reched their goal
+            if self.player_goal_reached || self.opponent_goal_reached {
+                self.events.push(RoundEvent::RoundEnded { step });
+                break 'step_loop;
+            }
+        }
+    }    
+
+}
+
+/// The inputs of a played round: everything needed to resolve it again
+/// exactly as it was played.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RoundRecord {
+    pub ruleset: Ruleset,
+    /// The map the round was played on; `None` for an open board.
+    #[serde(default)]
+    pub layout: Option<Layout>,
+    pub player: Board,
+    pub opponent: Board,
+}
+
+impl RoundRecord {
+    pub fn resolve(&self) -> GameBoard {
+        let mut game_board = match &self.layout {
+            Some(layout) => GameBoard::with_layout(self.ruleset, layout.clone()),
+            None => GameBoard::with_ruleset(self.player.size, self.ruleset),
+        };
+        game_board.process_turn(&self.player, &self.opponent);
+        game_board
+    }
+}
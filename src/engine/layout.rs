@@ -0,0 +1,302 @@
+//! Match maps: fixed tiles that every board in a match is built around and
+//! every round is resolved on.
+//!
+//! A layout is written one line per row, goal row first, one character per
+//! square:
+//!
+//! ```text
+//! #..b
+//! .a+.
+//! .+a.
+//! b..#
+//! ```
+//!
+//! `.` is an open square, `#` a wall, `x` a neutral trap, `+` a bonus square
+//! and any other letter a teleporter; each teleporter letter appears exactly
+//! twice. Layouts must be unchanged by a 180° rotation, so both players see
+//! the same map from their own side and build their boards against it.
+//!
+//! - Walls cannot be entered or trapped, and jumps and long-range traps cannot
+//!   pass over them.
+//! - A neutral trap is armed for both sides all round: a piece that stops on
+//!   one is caught like on an opponent's trap.
+//! - A bonus square scores [`bonus_points`](super::rules::Ruleset::bonus_points)
+//!   the first time each side's piece stops on it.
+//! - A piece that moves onto a teleporter comes out on the other square with
+//!   the same letter in the same step. Teleporters cannot be trapped.
+
+use std::fmt;
+
+use serde::{Serialize, Deserialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Tile {
+    Open,
+    Wall,
+    NeutralTrap,
+    Bonus,
+    Teleporter(char),
+}
+
+impl Tile {
+    fn parse(symbol: char) -> Option<Tile> {
+        match symbol {
+            '.' => Some(Tile::Open),
+            '#' => Some(Tile::Wall),
+            'x' => Some(Tile::NeutralTrap),
+            '+' => Some(Tile::Bonus),
+            letter if letter.is_ascii_alphabetic() => Some(Tile::Teleporter(letter)),
+            _ => None,
+        }
+    }
+
+    pub fn symbol(&self) -> char {
+        match self {
+            Tile::Open => '.',
+            Tile::Wall => '#',
+            Tile::NeutralTrap => 'x',
+            Tile::Bonus => '+',
+            Tile::Teleporter(letter) => *letter,
+        }
+    }
+
+    /// Whether a piece may stop here.
+    pub fn enterable(&self) -> bool {
+        !matches!(self, Tile::Wall)
+    }
+
+    /// Whether a side may place a trap here.
+    pub fn trappable(&self) -> bool {
+        matches!(self, Tile::Open | Tile::Bonus)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "LayoutFile", into = "LayoutFile")]
+pub struct Layout {
+    name: String,
+    tiles: Vec<Vec<Tile>>,
+}
+
+/// The serialized form: the name and the rows as written in a layout file.
+#[derive(Serialize, Deserialize)]
+struct LayoutFile {
+    name: String,
+    rows: Vec<String>,
+}
+
+impl TryFrom<LayoutFile> for Layout {
+    type Error = LayoutError;
+
+    fn try_from(file: LayoutFile) -> Result<Self, Self::Error> {
+        Layout::parse(&file.name, &file.rows.join("\n"))
+    }
+}
+
+impl From<Layout> for LayoutFile {
+    fn from(layout: Layout) -> Self {
+        LayoutFile { rows: layout.rows(), name: layout.name }
+    }
+}
+
+impl Layout {
+    /// A layout with nothing but open squares.
+    pub fn open(size: usize) -> Self {
+        Layout {
+            name: "Open".to_string(),
+            tiles: vec![vec![Tile::Open; size]; size],
+        }
+    }
+
+    /// Reads a layout from its text form, ignoring blank lines and
+    /// surrounding whitespace.
+    pub fn parse(name: &str, text: &str) -> Result<Self, LayoutError> {
+        let lines: Vec<&str> = text.lines().map(str::trim).filter(|line| !line.is_empty()).collect();
+        if lines.is_empty() {
+            return Err(LayoutError::Empty);
+        }
+        let size = lines.len();
+
+        let mut tiles = Vec::with_capacity(size);
+        for (row, line) in lines.iter().enumerate() {
+            if line.chars().count() != size {
+                return Err(LayoutError::NotSquare { row });
+            }
+            let tile_row = line
+                .chars()
+                .enumerate()
+                .map(|(col, symbol)| Tile::parse(symbol).ok_or(LayoutError::UnknownTile { row, col, symbol }))
+                .collect::<Result<Vec<_>, _>>()?;
+            tiles.push(tile_row);
+        }
+
+        let layout = Layout { name: name.to_string(), tiles };
+        for row in 0..size {
+            for col in 0..size {
+                let tile = layout.tiles[row][col];
+                if layout.tiles[size - 1 - row][size - 1 - col] != tile {
+                    return Err(LayoutError::NotSymmetric { row, col });
+                }
+                if let Tile::Teleporter(letter) = tile {
+                    let count = layout.tiles.iter().flatten().filter(|&&t| t == tile).count();
+                    if count != 2 {
+                        return Err(LayoutError::UnpairedTeleporter(letter));
+                    }
+                }
+            }
+        }
+        if !layout.tiles[size - 1].iter().any(|tile| matches!(tile, Tile::Open | Tile::Bonus)) {
+            return Err(LayoutError::NoStart);
+        }
+        Ok(layout)
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn size(&self) -> usize {
+        self.tiles.len()
+    }
+
+    /// The tile at a square; squares off the layout are open.
+    pub fn tile(&self, row: usize, col: usize) -> Tile {
+        self.tiles.get(row).and_then(|r| r.get(col)).copied().unwrap_or(Tile::Open)
+    }
+
+    /// The layout in its text form, one string per row.
+    pub fn rows(&self) -> Vec<String> {
+        self.tiles.iter().map(|row| row.iter().map(Tile::symbol).collect()).collect()
+    }
+
+    /// Whether the layout has any tiles at all.
+    pub fn is_open(&self) -> bool {
+        self.tiles.iter().flatten().all(|&tile| tile == Tile::Open)
+    }
+
+    /// Where a piece that moves onto the teleporter at (`row`, `col`) comes
+    /// out; `None` if that square is not a teleporter.
+    pub fn partner(&self, row: usize, col: usize) -> Option<(usize, usize)> {
+        let tile = self.tile(row, col);
+        if !matches!(tile, Tile::Teleporter(_)) {
+            return None;
+        }
+        (0..self.size())
+            .flat_map(|r| (0..self.size()).map(move |c| (r, c)))
+            .find(|&square| square != (row, col) && self.tile(square.0, square.1) == tile)
+    }
+
+    /// Whether no wall stands between two squares on a straight line; the
+    /// squares themselves are not checked.
+    pub fn clear_path(&self, (row, col): (usize, usize), (to_row, to_col): (usize, usize)) -> bool {
+        let (d_row, d_col) = (to_row as isize - row as isize, to_col as isize - col as isize);
+        let distance = d_row.abs().max(d_col.abs());
+        (1..distance).all(|i| {
+            let r = row as isize + d_row / distance * i;
+            let c = col as isize + d_col / distance * i;
+            self.tile(r as usize, c as usize) != Tile::Wall
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum LayoutError {
+    Empty,
+    NotSquare { row: usize },
+    UnknownTile { row: usize, col: usize, symbol: char },
+    UnpairedTeleporter(char),
+    NotSymmetric { row: usize, col: usize },
+    NoStart,
+}
+
+impl fmt::Display for LayoutError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LayoutError::Empty => write!(f, "layout has no rows"),
+            LayoutError::NotSquare { row } => write!(f, "row {} is not as long as the layout is tall", row),
+            LayoutError::UnknownTile { row, col, symbol } => {
+                write!(f, "unknown tile `{}` at ({}, {})", symbol, row, col)
+            }
+            LayoutError::UnpairedTeleporter(letter) => {
+                write!(f, "teleporter `{}` does not appear exactly twice", letter)
+            }
+            LayoutError::NotSymmetric { row, col } => {
+                write!(f, "({}, {}) does not match its square rotated 180°", row, col)
+            }
+            LayoutError::NoStart => write!(f, "the bottom row has no square to start on"),
+        }
+    }
+}
+
+impl std::error::Error for LayoutError {}
+
+/// The built-in layouts a match can be played on.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LayoutPreset {
+    /// No map: boards of any size, no tiles.
+    #[default]
+    Open,
+    Pillar,
+    Minefield,
+    Wormhole,
+    Crossroads,
+}
+
+impl LayoutPreset {
+    pub const ALL: [LayoutPreset; 5] = [
+        LayoutPreset::Open,
+        LayoutPreset::Pillar,
+        LayoutPreset::Minefield,
+        LayoutPreset::Wormhole,
+        LayoutPreset::Crossroads,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            LayoutPreset::Open => "Open",
+            LayoutPreset::Pillar => "Pillar",
+            LayoutPreset::Minefield => "Minefield",
+            LayoutPreset::Wormhole => "Wormhole",
+            LayoutPreset::Crossroads => "Crossroads",
+        }
+    }
+
+    /// The name used on the command line.
+    pub fn key(&self) -> &'static str {
+        match self {
+            LayoutPreset::Open => "open",
+            LayoutPreset::Pillar => "pillar",
+            LayoutPreset::Minefield => "minefield",
+            LayoutPreset::Wormhole => "wormhole",
+            LayoutPreset::Crossroads => "crossroads",
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            LayoutPreset::Open => "No tiles; boards of any size.",
+            LayoutPreset::Pillar => "3×3 with a wall in the middle.",
+            LayoutPreset::Minefield => "3×3 with a bonus square between two neutral traps.",
+            LayoutPreset::Wormhole => "3×3 with a teleporter in each corner of one diagonal.",
+            LayoutPreset::Crossroads => "4×4 with walls, bonus squares and two teleporter pairs.",
+        }
+    }
+
+    fn rows(&self) -> &'static str {
+        match self {
+            LayoutPreset::Open => "",
+            LayoutPreset::Pillar => "...\n.#.\n...",
+            LayoutPreset::Minefield => "x..\n.+.\n..x",
+            LayoutPreset::Wormhole => "..a\n...\na..",
+            LayoutPreset::Crossroads => "#..b\n.a+.\n.+a.\nb..#",
+        }
+    }
+
+    /// The preset's map; `None` for `Open`, which has none.
+    pub fn layout(&self) -> Option<Layout> {
+        match self {
+            LayoutPreset::Open => None,
+            preset => Some(Layout::parse(preset.label(), preset.rows()).expect("preset layouts are valid")),
+        }
+    }
+}
@@ -0,0 +1,302 @@
+//! The flow of a match, from picking the rules to the rematch question.
+//!
+//! Every change goes through [`MatchState::apply`], which accepts only the
+//! events that make sense in the current [`MatchPhase`]:
+//!
+//! ```text
+//! Lobby ─Start─▶ Selecting ─BoardChosen(player)─▶ AwaitingOpponent
+//!                    │                                  │
+//!                    └──── both boards chosen ──────────┴─▶ Revealing
+//!                                                              │ RevealFinished
+//!            Selecting ◀─Continue (more rounds)─ RoundSummary ◀┘
+//!                                                   │ Continue (last round)
+//!                                                   ▼
+//!   Lobby ◀─both requested─ RematchPending ◀─── MatchOver
+//!                                 │                 │
+//!                                 └─RematchDeclined─┴─▶ RematchDeclined
+//! ```
+//!
+//! A round is resolved as soon as both boards are in and its scores are
+//! added to the totals once the reveal has finished. A rematch starts once
+//! both sides have asked for one, in either order, and either side can turn
+//! it down until then. Events carry the side that sent them and serialize,
+//! so a remote opponent's choices can be applied the same way as a local
+//! one's.
+
+use std::fmt;
+
+use serde::{Serialize, Deserialize};
+
+use super::board::{Board, BoardError};
+use super::game_board::{GameBoard, RoundRecord, Side};
+use super::layout::LayoutPreset;
+use super::movement::MovementPreset;
+use super::rules::{Ruleset, Variant};
+
+/// Rounds in a match unless configured otherwise.
+pub const ROUNDS_PER_MATCH: usize = 8;
+
+/// The rules a match is played under; fixed once the match starts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MatchConfig {
+    pub variant: Variant,
+    pub movement: MovementPreset,
+    pub layout: LayoutPreset,
+    pub rounds: usize,
+    /// A ranked match is played without the board hints.
+    #[serde(default)]
+    pub ranked: bool,
+}
+
+impl Default for MatchConfig {
+    fn default() -> Self {
+        MatchConfig {
+            variant: Variant::default(),
+            movement: MovementPreset::default(),
+            layout: LayoutPreset::default(),
+            rounds: ROUNDS_PER_MATCH,
+            ranked: false,
+        }
+    }
+}
+
+impl MatchConfig {
+    pub fn ruleset(&self) -> Ruleset {
+        Ruleset { movement: self.movement.movement(), ..self.variant.ruleset() }
+    }
+
+    /// Whether `board` can be played in this match.
+    pub fn check(&self, board: &Board) -> Result<(), BoardError> {
+        match self.layout.layout() {
+            Some(layout) => board.validate_on(&self.movement.movement(), &layout),
+            None => board.validate(&self.movement.movement()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MatchPhase {
+    /// The rules can still be changed.
+    Lobby,
+    /// Both sides are choosing a board for the round.
+    Selecting,
+    /// The player has chosen; the opponent has not.
+    AwaitingOpponent,
+    /// The round is resolved and being shown.
+    Revealing,
+    /// The round's scores are added to the totals.
+    RoundSummary,
+    MatchOver,
+    /// `by` has asked for a rematch; the other side has not answered.
+    RematchPending { by: Side },
+    /// `by` has turned down a rematch.
+    RematchDeclined { by: Side },
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum MatchEvent {
+    Configure(MatchConfig),
+    Start,
+    BoardChosen { side: Side, board: Board },
+    RevealFinished,
+    Continue,
+    RematchRequested { side: Side },
+    RematchDeclined { side: Side },
+}
+
+impl MatchEvent {
+    fn name(&self) -> &'static str {
+        match self {
+            MatchEvent::Configure(_) => "Configure",
+            MatchEvent::Start => "Start",
+            MatchEvent::BoardChosen { .. } => "BoardChosen",
+            MatchEvent::RevealFinished => "RevealFinished",
+            MatchEvent::Continue => "Continue",
+            MatchEvent::RematchRequested { .. } => "RematchRequested",
+            MatchEvent::RematchDeclined { .. } => "RematchDeclined",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum MatchError {
+    /// The event has no meaning in the current phase.
+    Unexpected { phase: MatchPhase, event: &'static str },
+    /// The side has already chosen a board this round.
+    AlreadyChosen(Side),
+    /// The side has already asked for a rematch.
+    AlreadyRequested(Side),
+    IllegalBoard { side: Side, error: BoardError },
+}
+
+impl fmt::Display for MatchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MatchError::Unexpected { phase, event } => write!(f, "{} is not expected during {:?}", event, phase),
+            MatchError::AlreadyChosen(side) => write!(f, "{} has already chosen a board", side),
+            MatchError::AlreadyRequested(side) => write!(f, "{} has already asked for a rematch", side),
+            MatchError::IllegalBoard { side, error } => write!(f, "{}'s board cannot be played: {}", side, error),
+        }
+    }
+}
+
+impl std::error::Error for MatchError {}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MatchState {
+    config: MatchConfig,
+    phase: MatchPhase,
+    round: usize,
+    player_score: i32,
+    opponent_score: i32,
+    player_board: Option<Board>,
+    opponent_board: Option<Board>,
+    rounds: Vec<RoundRecord>,
+    /// The last round resolved; rebuilt from `rounds` by [`MatchState::resumed`].
+    #[serde(skip)]
+    game_board: Option<GameBoard>,
+}
+
+impl Default for MatchState {
+    fn default() -> Self {
+        MatchState::new(MatchConfig::default())
+    }
+}
+
+impl MatchState {
+    pub fn new(config: MatchConfig) -> Self {
+        MatchState {
+            config,
+            phase: MatchPhase::Lobby,
+            round: 1,
+            player_score: 0,
+            opponent_score: 0,
+            player_board: None,
+            opponent_board: None,
+            rounds: Vec::new(),
+            game_board: None,
+        }
+    }
+
+    /// Restores the resolved round of a match loaded from storage.
+    pub fn resumed(mut self) -> Self {
+        if !matches!(self.phase, MatchPhase::Lobby | MatchPhase::Selecting | MatchPhase::AwaitingOpponent) {
+            self.game_board = self.rounds.last().map(RoundRecord::resolve);
+        }
+        self
+    }
+
+    pub fn config(&self) -> &MatchConfig {
+        &self.config
+    }
+
+    pub fn phase(&self) -> MatchPhase {
+        self.phase
+    }
+
+    /// The round being played, counting from 1.
+    pub fn round(&self) -> usize {
+        self.round
+    }
+
+    /// The totals as (player, opponent).
+    pub fn scores(&self) -> (i32, i32) {
+        (self.player_score, self.opponent_score)
+    }
+
+    /// The board `side` has chosen for the current round.
+    pub fn board(&self, side: Side) -> Option<&Board> {
+        match side {
+            Side::Player => self.player_board.as_ref(),
+            Side::Opponent => self.opponent_board.as_ref(),
+        }
+    }
+
+    /// Every round resolved so far, with the rules it was played under.
+    pub fn rounds(&self) -> &[RoundRecord] {
+        &self.rounds
+    }
+
+    /// The last round resolved, while it is on show.
+    pub fn game_board(&self) -> Option<&GameBoard> {
+        self.game_board.as_ref()
+    }
+
+    pub fn is_last_round(&self) -> bool {
+        self.round >= self.config.rounds
+    }
+
+    /// Whether the match has been played to the end.
+    pub fn is_over(&self) -> bool {
+        matches!(
+            self.phase,
+            MatchPhase::MatchOver | MatchPhase::RematchPending { .. } | MatchPhase::RematchDeclined { .. }
+        )
+    }
+
+    pub fn apply(&mut self, event: MatchEvent) -> Result<(), MatchError> {
+        let unexpected = MatchError::Unexpected { phase: self.phase, event: event.name() };
+        match (self.phase, event) {
+            (MatchPhase::Lobby, MatchEvent::Configure(config)) => self.config = config,
+            (MatchPhase::Lobby, MatchEvent::Start) => self.phase = MatchPhase::Selecting,
+            (MatchPhase::Selecting | MatchPhase::AwaitingOpponent, MatchEvent::BoardChosen { side, board }) => {
+                self.config.check(&board).map_err(|error| MatchError::IllegalBoard { side, error })?;
+                let slot = match side {
+                    Side::Player => &mut self.player_board,
+                    Side::Opponent => &mut self.opponent_board,
+                };
+                if slot.is_some() {
+                    return Err(MatchError::AlreadyChosen(side));
+                }
+                *slot = Some(board);
+                match (&self.player_board, &self.opponent_board) {
+                    (Some(player), Some(opponent)) => {
+                        let record = RoundRecord {
+                            ruleset: self.config.ruleset(),
+                            layout: self.config.layout.layout(),
+                            player: player.clone(),
+                            opponent: opponent.clone(),
+                        };
+                        self.game_board = Some(record.resolve());
+                        self.rounds.push(record);
+                        self.phase = MatchPhase::Revealing;
+                    }
+                    (Some(_), None) => self.phase = MatchPhase::AwaitingOpponent,
+                    _ => {}
+                }
+            }
+            (MatchPhase::Revealing, MatchEvent::RevealFinished) => {
+                if let Some(game_board) = &self.game_board {
+                    self.player_score += game_board.player_score;
+                    self.opponent_score += game_board.opponent_score;
+                }
+                self.phase = MatchPhase::RoundSummary;
+            }
+            (MatchPhase::RoundSummary, MatchEvent::Continue) => {
+                if self.is_last_round() {
+                    self.phase = MatchPhase::MatchOver;
+                } else {
+                    self.round += 1;
+                    self.player_board = None;
+                    self.opponent_board = None;
+                    self.game_board = None;
+                    self.phase = MatchPhase::Selecting;
+                }
+            }
+            (MatchPhase::MatchOver, MatchEvent::RematchRequested { side }) => {
+                self.phase = MatchPhase::RematchPending { by: side };
+            }
+            (MatchPhase::RematchPending { by }, MatchEvent::RematchRequested { side }) => {
+                if side == by {
+                    return Err(MatchError::AlreadyRequested(side));
+                }
+                *self = MatchState::new(self.config);
+            }
+            (MatchPhase::MatchOver | MatchPhase::RematchPending { .. }, MatchEvent::RematchDeclined { side }) => {
+                self.phase = MatchPhase::RematchDeclined { by: side };
+            }
+            _ => return Err(unexpected),
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,13 @@
+pub mod board;
+pub mod deck;
+pub mod enumerate;
+pub mod game_board;
+pub mod layout;
+pub mod match_state;
+pub mod movement;
+pub mod opponent;
+pub mod persona;
+pub mod player_model;
+pub mod rules;
+pub mod strategy;
+pub mod timing;
@@ -0,0 +1,162 @@
+//! Which squares a piece can step to and place traps on while a board is
+//! built.
+//!
+//! Every step goes in a straight line from the piece: forward or sideways,
+//! plus backward and the diagonals when enabled. A move covers one square, or
+//! two with jumps (passing over whatever is in between); a trap can go up to
+//! `trap_range` squares away. The target square must be empty either way.
+
+use serde::{Serialize, Deserialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Movement {
+    /// Steps may go diagonally.
+    pub diagonal: bool,
+    /// Steps may go away from the goal.
+    pub backward: bool,
+    /// Moves may cover two squares.
+    pub jumps: bool,
+    /// How many squares away a trap can be placed.
+    pub trap_range: usize,
+}
+
+impl Default for Movement {
+    fn default() -> Self {
+        Movement {
+            diagonal: false,
+            backward: false,
+            jumps: false,
+            trap_range: 1,
+        }
+    }
+}
+
+impl Movement {
+    /// Everything either movement allows.
+    pub fn union(&self, other: &Movement) -> Movement {
+        Movement {
+            diagonal: self.diagonal || other.diagonal,
+            backward: self.backward || other.backward,
+            jumps: self.jumps || other.jumps,
+            trap_range: self.trap_range.max(other.trap_range),
+        }
+    }
+
+    /// Unit steps as (row, col) deltas; rows count down towards the goal.
+    fn directions(&self) -> Vec<(isize, isize)> {
+        let mut directions = vec![(-1, 0), (0, -1), (0, 1)];
+        if self.backward {
+            directions.push((1, 0));
+        }
+        if self.diagonal {
+            directions.extend([(-1, -1), (-1, 1)]);
+            if self.backward {
+                directions.extend([(1, -1), (1, 1)]);
+            }
+        }
+        directions
+    }
+
+    fn max_move(&self) -> usize {
+        if self.jumps { 2 } else { 1 }
+    }
+
+    fn reaches(&self, (row, col): (usize, usize), (to_row, to_col): (usize, usize), max_distance: usize) -> bool {
+        let (d_row, d_col) = (to_row as isize - row as isize, to_col as isize - col as isize);
+        self.directions().into_iter().any(|(r, c)| {
+            (1..=max_distance as isize).any(|distance| (r * distance, c * distance) == (d_row, d_col))
+        })
+    }
+
+    fn targets(&self, size: usize, (row, col): (usize, usize), max_distance: usize) -> Vec<(usize, usize)> {
+        let mut targets = Vec::new();
+        for (r, c) in self.directions() {
+            for distance in 1..=max_distance as isize {
+                let (to_row, to_col) = (row as isize + r * distance, col as isize + c * distance);
+                if (0..size as isize).contains(&to_row) && (0..size as isize).contains(&to_col) {
+                    targets.push((to_row as usize, to_col as usize));
+                }
+            }
+        }
+        targets
+    }
+
+    pub fn can_move(&self, from: (usize, usize), to: (usize, usize)) -> bool {
+        self.reaches(from, to, self.max_move())
+    }
+
+    pub fn can_trap(&self, from: (usize, usize), to: (usize, usize)) -> bool {
+        self.reaches(from, to, self.trap_range)
+    }
+
+    /// Squares on a `size` board the piece at `from` could move to, ignoring
+    /// what is on them.
+    pub fn move_targets(&self, size: usize, from: (usize, usize)) -> Vec<(usize, usize)> {
+        self.targets(size, from, self.max_move())
+    }
+
+    /// Squares on a `size` board the piece at `from` could trap, ignoring
+    /// what is on them.
+    pub fn trap_targets(&self, size: usize, from: (usize, usize)) -> Vec<(usize, usize)> {
+        self.targets(size, from, self.trap_range)
+    }
+}
+
+/// The named movement rules boards can be built and matches played under.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MovementPreset {
+    #[default]
+    Classic,
+    Diagonal,
+    Backward,
+    Jumps,
+    LongTraps,
+    Free,
+}
+
+impl MovementPreset {
+    pub const ALL: [MovementPreset; 6] = [
+        MovementPreset::Classic,
+        MovementPreset::Diagonal,
+        MovementPreset::Backward,
+        MovementPreset::Jumps,
+        MovementPreset::LongTraps,
+        MovementPreset::Free,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            MovementPreset::Classic => "Classic",
+            MovementPreset::Diagonal => "Diagonal steps",
+            MovementPreset::Backward => "Backward steps",
+            MovementPreset::Jumps => "Two-square jumps",
+            MovementPreset::LongTraps => "Trap range 2",
+            MovementPreset::Free => "Anything goes",
+        }
+    }
+
+    /// The name used on the command line.
+    pub fn key(&self) -> &'static str {
+        match self {
+            MovementPreset::Classic => "classic",
+            MovementPreset::Diagonal => "diagonal",
+            MovementPreset::Backward => "backward",
+            MovementPreset::Jumps => "jumps",
+            MovementPreset::LongTraps => "long-traps",
+            MovementPreset::Free => "free",
+        }
+    }
+
+    pub fn movement(&self) -> Movement {
+        let classic = Movement::default();
+        match self {
+            MovementPreset::Classic => classic,
+            MovementPreset::Diagonal => Movement { diagonal: true, ..classic },
+            MovementPreset::Backward => Movement { backward: true, ..classic },
+            MovementPreset::Jumps => Movement { jumps: true, ..classic },
+            MovementPreset::LongTraps => Movement { trap_range: 2, ..classic },
+            MovementPreset::Free => Movement { diagonal: true, backward: true, jumps: true, trap_range: 2 },
+        }
+    }
+}
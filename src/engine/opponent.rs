@@ -0,0 +1,183 @@
+//! The people and personas the player has a record against, with how each
+//! is shown and the settings the player likes to play them under.
+
+use rand::Rng;
+use serde::{Serialize, Deserialize};
+
+use super::match_state::MatchConfig;
+use super::persona::Persona;
+use super::timing::GameSpeed;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OpponentType {
+    Human,
+    Computer,
+}
+
+impl OpponentType {
+    fn prefix(&self) -> &'static str {
+        match self {
+            OpponentType::Human => "human",
+            OpponentType::Computer => "cpu",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum AvatarColor {
+    #[default]
+    Blue,
+    Green,
+    Red,
+    Orange,
+    Purple,
+    Teal,
+    Pink,
+    Slate,
+}
+
+impl AvatarColor {
+    pub const ALL: [AvatarColor; 8] = [
+        AvatarColor::Blue,
+        AvatarColor::Green,
+        AvatarColor::Red,
+        AvatarColor::Orange,
+        AvatarColor::Purple,
+        AvatarColor::Teal,
+        AvatarColor::Pink,
+        AvatarColor::Slate,
+    ];
+
+    pub fn key(&self) -> &'static str {
+        match self {
+            AvatarColor::Blue => "blue",
+            AvatarColor::Green => "green",
+            AvatarColor::Red => "red",
+            AvatarColor::Orange => "orange",
+            AvatarColor::Purple => "purple",
+            AvatarColor::Teal => "teal",
+            AvatarColor::Pink => "pink",
+            AvatarColor::Slate => "slate",
+        }
+    }
+
+    /// The color's CSS value, dark enough for white text on top.
+    pub fn hex(&self) -> &'static str {
+        match self {
+            AvatarColor::Blue => "#2563eb",
+            AvatarColor::Green => "#16a34a",
+            AvatarColor::Red => "#dc2626",
+            AvatarColor::Orange => "#c2410c",
+            AvatarColor::Purple => "#7c3aed",
+            AvatarColor::Teal => "#0f766e",
+            AvatarColor::Pink => "#db2777",
+            AvatarColor::Slate => "#475569",
+        }
+    }
+}
+
+/// The badge shown next to an opponent's name.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Avatar {
+    pub color: AvatarColor,
+    /// The letter or symbol on the badge; the name's first letter if unset.
+    pub glyph: Option<char>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Opponent {
+    /// The display name, which can change; records are kept by `id`.
+    pub name: String,
+    /// Unique among the player's opponents, and fixed once made.
+    pub id: String,
+    pub opponent_type: OpponentType,
+    /// How a computer opponent builds and picks its boards.
+    #[serde(default)]
+    pub persona: Persona,
+    #[serde(default)]
+    pub avatar: Avatar,
+    #[serde(default)]
+    pub notes: String,
+    /// The speed to play at unless another is picked; the player's default
+    /// speed if unset.
+    #[serde(default)]
+    pub speed: Option<GameSpeed>,
+    /// The rules new matches start with; the player's default rules if unset.
+    #[serde(default)]
+    pub config: Option<MatchConfig>,
+    /// When the opponent was added, in milliseconds since the Unix epoch;
+    /// unknown for opponents added before it was recorded.
+    #[serde(default)]
+    pub created: Option<u64>,
+}
+
+impl Opponent {
+    /// A new human opponent, added at `created`, with an ID no other
+    /// opponent has, whatever its name.
+    pub fn human(name: String, created: u64, rng: &mut impl Rng) -> Self {
+        Opponent {
+            id: format!("human-{:x}-{:08x}", created, rng.gen::<u32>()),
+            name,
+            opponent_type: OpponentType::Human,
+            persona: Persona::default(),
+            avatar: Avatar::default(),
+            notes: String::new(),
+            speed: None,
+            config: None,
+            created: Some(created),
+        }
+    }
+
+    /// The built-in computer opponent playing as `persona`; there is only
+    /// ever one of each, so its ID stays the one it has always had.
+    pub fn computer(persona: Persona) -> Self {
+        let index = Persona::ALL.iter().position(|p| *p == persona).unwrap_or_default();
+        Opponent {
+            id: legacy_id(persona.name(), &OpponentType::Computer),
+            name: persona.name().to_string(),
+            opponent_type: OpponentType::Computer,
+            persona,
+            avatar: Avatar { color: AvatarColor::ALL[index % AvatarColor::ALL.len()], glyph: None },
+            notes: String::new(),
+            speed: None,
+            config: None,
+            created: None,
+        }
+    }
+
+    /// The glyph on the opponent's badge.
+    pub fn glyph(&self) -> char {
+        self.avatar
+            .glyph
+            .or_else(|| self.name.chars().find(|c| !c.is_whitespace()).and_then(|c| c.to_uppercase().next()))
+            .unwrap_or('?')
+    }
+}
+
+/// The ID opponents were saved under before IDs were made unique: the type
+/// and the lowercased name, so two humans of the same name shared one.
+pub fn legacy_id(name: &str, opponent_type: &OpponentType) -> String {
+    format!("{}_{}", opponent_type.prefix(), name.to_lowercase().replace(' ', "_"))
+}
+
+/// Gives every human opponent still saved under a name-based ID a unique
+/// one, and returns each `(old, new)` pair so that what is kept under the
+/// old ID can move with it. Running it again changes nothing.
+pub fn migrate(opponents: &mut [Opponent], rng: &mut impl Rng) -> Vec<(String, String)> {
+    let mut taken: Vec<String> = opponents.iter().map(|opponent| opponent.id.clone()).collect();
+    let mut moved = Vec::new();
+    for opponent in opponents.iter_mut() {
+        if opponent.opponent_type != OpponentType::Human || opponent.id != legacy_id(&opponent.name, &opponent.opponent_type) {
+            continue;
+        }
+        let new_id = loop {
+            let id = format!("human-0-{:08x}", rng.gen::<u32>());
+            if !taken.contains(&id) {
+                break id;
+            }
+        };
+        taken.push(new_id.clone());
+        moved.push((std::mem::replace(&mut opponent.id, new_id.clone()), new_id));
+    }
+    moved
+}
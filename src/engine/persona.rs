@@ -0,0 +1,153 @@
+//! Built-in computer opponents. Each persona builds its own library of
+//! boards for a match and has its own way of picking one each round; none
+//! of them sees the board the player has chosen for the round being played.
+//! Each also decides for itself whether to play the player again.
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+use serde::{Serialize, Deserialize};
+
+use super::board::{Board, CellContent};
+use super::enumerate::enumerate_boards_on;
+use super::game_board::RoundRecord;
+use super::layout::Layout;
+use super::match_state::{MatchConfig, MatchState};
+use super::player_model::PlayerModel;
+use super::strategy::StrategyBook;
+
+/// The most boards the picky personas keep in their library.
+const LIBRARY_SIZE: usize = 8;
+
+/// How many points the Trapper can lose a match by and still want a rematch.
+const REMATCH_SULK_MARGIN: i32 = 5;
+
+/// How far ahead in matches either side can get before the Sprinter or the
+/// Expert stops agreeing to rematches.
+const REMATCH_RECORD_GAP: i32 = 3;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Persona {
+    /// Any legal board, picked at random.
+    #[default]
+    Random,
+    Trapper,
+    Sprinter,
+    Mirror,
+    Adaptive,
+    /// Plays the mixed strategy trained by self-play for the match, when
+    /// there is one.
+    Expert,
+}
+
+impl Persona {
+    pub const ALL: [Persona; 6] = [
+        Persona::Random,
+        Persona::Trapper,
+        Persona::Sprinter,
+        Persona::Mirror,
+        Persona::Adaptive,
+        Persona::Expert,
+    ];
+
+    /// The name the persona is listed under as an opponent.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Persona::Random => "CPU",
+            Persona::Trapper => "Trapper",
+            Persona::Sprinter => "Sprinter",
+            Persona::Mirror => "Mirror",
+            Persona::Adaptive => "Adaptive",
+            Persona::Expert => "Expert",
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            Persona::Random => "Plays any legal board at random.",
+            Persona::Trapper => "Lays traps as early as it can.",
+            Persona::Sprinter => "Runs straight for the goal.",
+            Persona::Mirror => "Plays your last board back at you.",
+            Persona::Adaptive => "Learns which boards you play and counters them.",
+            Persona::Expert => "Mixes its boards so that no choice of yours can exploit them.",
+        }
+    }
+
+    /// The boards the persona plays from in a match under `config`, for
+    /// boards of `size` (the layout's size when the match has one).
+    pub fn library(&self, config: &MatchConfig, size: usize) -> Vec<Board> {
+        let layout = config.layout.layout().unwrap_or_else(|| Layout::open(size));
+        // Room for the shortest run to the goal and a couple of extra steps
+        let mut boards = enumerate_boards_on(&layout, layout.size() + 3, &config.movement.movement());
+        match self {
+            Persona::Trapper => {
+                boards.retain(|board| first_trap(board).is_some());
+                boards.sort_by_key(|board| (first_trap(board), usize::MAX - traps(board), board.sequence.len()));
+                boards.truncate(LIBRARY_SIZE);
+            }
+            Persona::Sprinter => {
+                // Only the quickest runs, without a step to spare for traps
+                boards.retain(|board| traps(board) == 0);
+                let shortest = boards.iter().map(|board| board.sequence.len()).min().unwrap_or(0);
+                boards.retain(|board| board.sequence.len() == shortest);
+                boards.truncate(LIBRARY_SIZE);
+            }
+            Persona::Random | Persona::Mirror | Persona::Adaptive | Persona::Expert => {}
+        }
+        boards
+    }
+
+    /// Picks the persona's board for the next round from `library`, given
+    /// the rounds played so far in the match, what it has learned about the
+    /// player and the strategies trained by self-play.
+    pub fn choose<R: Rng + ?Sized>(
+        &self,
+        config: &MatchConfig,
+        library: &[Board],
+        history: &[RoundRecord],
+        model: &PlayerModel,
+        book: &StrategyBook,
+        rng: &mut R,
+    ) -> Option<Board> {
+        let size = library.first()?.size;
+        let planned = match self {
+            Persona::Mirror => history.last().map(|round| round.player.clone()).filter(|board| board.size == size),
+            Persona::Adaptive => model.best_response(config, library),
+            Persona::Expert => book
+                .find(config, size)
+                .and_then(|strategy| strategy.sample(rng))
+                .filter(|board| config.check(board).is_ok())
+                .cloned(),
+            Persona::Random | Persona::Trapper | Persona::Sprinter => None,
+        };
+        planned.or_else(|| library.choose(rng).cloned())
+    }
+
+    /// Whether the persona takes the player up on a rematch after
+    /// `finished`, given the player's `record` against it as (wins, losses),
+    /// this match included.
+    pub fn accepts_rematch<R: Rng + ?Sized>(&self, finished: &MatchState, record: (i32, i32), rng: &mut R) -> bool {
+        let (player_score, own_score) = finished.scores();
+        let (wins, losses) = record;
+        match self {
+            // Whatever mood it is in
+            Persona::Random => rng.gen_bool(0.75),
+            // Sulks after a thrashing
+            Persona::Trapper => player_score - own_score < REMATCH_SULK_MARGIN,
+            // Stops racing a player who keeps beating it
+            Persona::Sprinter => wins - losses < REMATCH_RECORD_GAP,
+            // Always has a board of yours to play back, or more to learn
+            Persona::Mirror | Persona::Adaptive => true,
+            // Loses interest in a player it beats every time
+            Persona::Expert => losses - wins < REMATCH_RECORD_GAP,
+        }
+    }
+}
+
+fn traps(board: &Board) -> usize {
+    board.sequence.iter().filter(|(_, _, content)| *content == CellContent::Trap).count()
+}
+
+/// The step of the board's first trap.
+fn first_trap(board: &Board) -> Option<usize> {
+    board.sequence.iter().position(|(_, _, content)| *content == CellContent::Trap)
+}
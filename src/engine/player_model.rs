@@ -0,0 +1,97 @@
+//! What has been learned about one side's board choices: how often each
+//! board has been played, over every match seen. The computer opponents keep
+//! one for each player, and the board hints one for each opponent.
+
+use serde::{Serialize, Deserialize};
+
+use super::board::Board;
+use super::game_board::{GameBoard, RoundRecord};
+use super::match_state::MatchConfig;
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PlayerModel {
+    /// Each board seen, with how many times it has been played.
+    counts: Vec<(Board, u32)>,
+}
+
+impl PlayerModel {
+    /// Counts one more play of `board`.
+    pub fn observe(&mut self, board: &Board) {
+        match self.counts.iter_mut().find(|(seen, _)| seen == board) {
+            Some((_, count)) => *count += 1,
+            None => self.counts.push((board.clone(), 1)),
+        }
+    }
+
+    /// How many times `board` has been played.
+    pub fn plays(&self, board: &Board) -> u32 {
+        self.counts.iter().find(|(seen, _)| seen == board).map_or(0, |(_, count)| *count)
+    }
+
+    /// The average of `score` over the boards seen that could be met by a
+    /// board of `size` in a match under `config`, each weighted by how often
+    /// it has been played; `None` if there are none.
+    fn weighted_average(&self, config: &MatchConfig, size: usize, score: impl Fn(&Board) -> f64) -> Option<f64> {
+        let (mut total, mut plays) = (0.0, 0);
+        for (board, count) in &self.counts {
+            if board.size == size && config.check(board).is_ok() {
+                total += score(board) * f64::from(*count);
+                plays += count;
+            }
+        }
+        (plays > 0).then(|| total / f64::from(plays))
+    }
+
+    /// The average by which `candidate` outscores the boards seen, each
+    /// weighted by how often it has been played; `None` if no board seen
+    /// could meet it.
+    pub fn expected_margin(&self, config: &MatchConfig, candidate: &Board) -> Option<f64> {
+        self.weighted_average(config, candidate.size, |board| {
+            let round = resolve(config, board, candidate);
+            f64::from(round.opponent_score - round.player_score)
+        })
+    }
+
+    /// The points `candidate` can expect to score when played against the
+    /// boards seen, each weighted by how often it has been played; `None` if
+    /// no board seen could meet it.
+    pub fn expected_points(&self, config: &MatchConfig, candidate: &Board) -> Option<f64> {
+        self.weighted_average(config, candidate.size, |board| {
+            f64::from(resolve(config, candidate, board).player_score)
+        })
+    }
+
+    /// The indices of `candidates` with their expected points against the
+    /// boards seen, best first; candidates no board seen could meet are
+    /// left out.
+    pub fn rank_replies(&self, config: &MatchConfig, candidates: &[Board]) -> Vec<(usize, f64)> {
+        let mut ranked: Vec<(usize, f64)> = candidates
+            .iter()
+            .enumerate()
+            .filter_map(|(index, candidate)| Some((index, self.expected_points(config, candidate)?)))
+            .collect();
+        ranked.sort_by(|(_, a), (_, b)| b.total_cmp(a));
+        ranked
+    }
+
+    /// The board in `library` with the best expected margin against the
+    /// boards seen; `None` if the model knows nothing it could meet.
+    pub fn best_response(&self, config: &MatchConfig, library: &[Board]) -> Option<Board> {
+        library
+            .iter()
+            .filter_map(|candidate| Some((candidate, self.expected_margin(config, candidate)?)))
+            .max_by(|(_, a), (_, b)| a.total_cmp(b))
+            .map(|(candidate, _)| candidate.clone())
+    }
+}
+
+/// The round of `player` against `opponent` in a match under `config`.
+fn resolve(config: &MatchConfig, player: &Board, opponent: &Board) -> GameBoard {
+    RoundRecord {
+        ruleset: config.ruleset(),
+        layout: config.layout.layout(),
+        player: player.clone(),
+        opponent: opponent.clone(),
+    }
+    .resolve()
+}
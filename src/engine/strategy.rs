@@ -0,0 +1,190 @@
+//! Mixed strategies trained by self-play, for the Expert CPU.
+//!
+//! A round is a zero-sum game between the two boards: whatever one side
+//! outscores the other by, the other loses. [`train`] plays every board of
+//! the space against every other and runs regret matching on both sides;
+//! the average of the CPU's strategies over the iterations approaches an
+//! equilibrium, a mix of boards that no choice of the player's can exploit.
+//! Trained strategies are kept in a [`StrategyBook`], which the CLI writes
+//! and the web client loads.
+
+use rand::Rng;
+use serde::{Serialize, Deserialize};
+
+use super::board::Board;
+use super::game_board::RoundRecord;
+use super::layout::Layout;
+use super::match_state::MatchConfig;
+use super::rules::Ruleset;
+
+/// Boards played less often than this are dropped from a trained strategy.
+const MIN_PROBABILITY: f64 = 0.001;
+
+/// How often to play each board of one size under one set of rules.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MixedStrategy {
+    pub ruleset: Ruleset,
+    /// The map the strategy was trained on; `None` for an open board.
+    #[serde(default)]
+    pub layout: Option<Layout>,
+    pub size: usize,
+    /// The margin the strategy expects to win by, whatever the player picks.
+    pub value: f64,
+    /// The boards worth playing, with their probabilities.
+    pub boards: Vec<(Board, f64)>,
+}
+
+impl MixedStrategy {
+    /// Draws a board with the strategy's probabilities.
+    pub fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Option<&Board> {
+        let mut roll = rng.gen::<f64>() * self.boards.iter().map(|(_, p)| p).sum::<f64>();
+        for (board, probability) in &self.boards {
+            if roll < *probability {
+                return Some(board);
+            }
+            roll -= probability;
+        }
+        self.boards.last().map(|(board, _)| board)
+    }
+}
+
+/// Trained strategies for any number of sizes, rules and maps.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct StrategyBook {
+    pub strategies: Vec<MixedStrategy>,
+}
+
+impl StrategyBook {
+    /// The strategy for boards of `size` in a match under `config`.
+    pub fn find(&self, config: &MatchConfig, size: usize) -> Option<&MixedStrategy> {
+        let (ruleset, layout) = (config.ruleset(), config.layout.layout());
+        self.strategies
+            .iter()
+            .find(|strategy| strategy.size == size && strategy.ruleset == ruleset && strategy.layout == layout)
+    }
+
+    /// Adds `strategy`, replacing any trained for the same size, rules and map.
+    pub fn insert(&mut self, strategy: MixedStrategy) {
+        self.strategies.retain(|s| {
+            (s.size, &s.ruleset, &s.layout) != (strategy.size, &strategy.ruleset, &strategy.layout)
+        });
+        self.strategies.push(strategy);
+    }
+}
+
+/// The result of [`train`].
+#[derive(Debug, Clone)]
+pub struct Training {
+    pub strategy: MixedStrategy,
+    /// How far the two sides' average strategies are from an equilibrium:
+    /// what the best replies to them would gain, added up. Zero at an
+    /// equilibrium.
+    pub gap: f64,
+}
+
+/// Trains the CPU's strategy over `boards` (all of one size) with
+/// `iterations` rounds of regret matching against itself.
+pub fn train(ruleset: Ruleset, layout: Option<&Layout>, boards: &[Board], iterations: usize) -> Training {
+    // payoffs[i][j]: the CPU's margin playing boards[i] against boards[j]
+    let payoffs: Vec<Vec<f64>> = boards
+        .iter()
+        .map(|cpu| {
+            boards
+                .iter()
+                .map(|player| {
+                    let round = RoundRecord {
+                        ruleset,
+                        layout: layout.cloned(),
+                        player: player.clone(),
+                        opponent: cpu.clone(),
+                    }
+                    .resolve();
+                    f64::from(round.opponent_score - round.player_score)
+                })
+                .collect()
+        })
+        .collect();
+
+    let n = boards.len();
+    let (mut cpu_regrets, mut player_regrets) = (vec![0.0; n], vec![0.0; n]);
+    let (mut cpu_average, mut player_average) = (vec![0.0; n], vec![0.0; n]);
+    for iteration in 1..=iterations {
+        let cpu = regret_matching(&cpu_regrets);
+        let player = regret_matching(&player_regrets);
+        // Each side's gain from every board against the other's mix
+        let cpu_gains: Vec<f64> = (0..n).map(|i| (0..n).map(|j| payoffs[i][j] * player[j]).sum()).collect();
+        let player_gains: Vec<f64> = (0..n).map(|j| (0..n).map(|i| -payoffs[i][j] * cpu[i]).sum()).collect();
+        update_regrets(&mut cpu_regrets, &cpu, &cpu_gains);
+        update_regrets(&mut player_regrets, &player, &player_gains);
+        // Later iterations count for more, as regret matching+ expects
+        for i in 0..n {
+            cpu_average[i] += iteration as f64 * cpu[i];
+            player_average[i] += iteration as f64 * player[i];
+        }
+    }
+    normalize(&mut cpu_average);
+    normalize(&mut player_average);
+
+    let best_cpu_reply = (0..n)
+        .map(|i| (0..n).map(|j| payoffs[i][j] * player_average[j]).sum::<f64>())
+        .reduce(f64::max)
+        .unwrap_or(0.0);
+    let gap = best_cpu_reply - guaranteed(&payoffs, &cpu_average);
+
+    // Keep only the boards the strategy really plays
+    for p in cpu_average.iter_mut().filter(|p| **p < MIN_PROBABILITY) {
+        *p = 0.0;
+    }
+    normalize(&mut cpu_average);
+    let value = guaranteed(&payoffs, &cpu_average);
+
+    let strategy = MixedStrategy {
+        ruleset,
+        layout: layout.cloned(),
+        size: boards.first().map_or(0, |board| board.size),
+        value,
+        boards: boards
+            .iter()
+            .zip(&cpu_average)
+            .filter(|(_, p)| **p > 0.0)
+            .map(|(board, p)| (board.clone(), *p))
+            .collect(),
+    };
+    Training { strategy, gap: gap.max(0.0) }
+}
+
+/// Plays each board in proportion to its positive regret; evenly when none
+/// has any.
+fn regret_matching(regrets: &[f64]) -> Vec<f64> {
+    let mut strategy: Vec<f64> = regrets.iter().map(|r| r.max(0.0)).collect();
+    normalize(&mut strategy);
+    strategy
+}
+
+/// Regret matching+: regrets never go below zero, so boards that start
+/// paying off are picked up quickly.
+fn update_regrets(regrets: &mut [f64], strategy: &[f64], gains: &[f64]) {
+    let expected: f64 = strategy.iter().zip(gains).map(|(p, g)| p * g).sum();
+    for (regret, gain) in regrets.iter_mut().zip(gains) {
+        *regret = (*regret + gain - expected).max(0.0);
+    }
+}
+
+fn normalize(weights: &mut [f64]) {
+    let total: f64 = weights.iter().sum();
+    if total > 0.0 {
+        weights.iter_mut().for_each(|w| *w /= total);
+    } else if !weights.is_empty() {
+        let even = 1.0 / weights.len() as f64;
+        weights.iter_mut().for_each(|w| *w = even);
+    }
+}
+
+/// The CPU's expected margin with `strategy` against the player's best reply.
+fn guaranteed(payoffs: &[Vec<f64>], strategy: &[f64]) -> f64 {
+    (0..strategy.len())
+        .map(|j| (0..strategy.len()).map(|i| payoffs[i][j] * strategy[i]).sum::<f64>())
+        .reduce(f64::min)
+        .unwrap_or(0.0)
+}
@@ -0,0 +1,89 @@
+//! How long each part of a round lasts: choosing a board, the reveal, and
+//! the round's result before the next round's choosing starts.
+
+use serde::{Serialize, Deserialize};
+
+use super::match_state::{MatchEvent, MatchPhase, MatchState};
+
+/// The longest any one part of a round can be set to, in seconds.
+pub const MAX_SECONDS: u32 = 600;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TimingConfig {
+    /// Seconds to choose a board; `None` for no limit.
+    pub selection: Option<u32>,
+    /// Seconds the two boards are revealed before the round's result shows.
+    pub reveal: u32,
+    /// Seconds the round's result shows before the next round's choosing
+    /// starts; `None` to wait for the player.
+    pub results: Option<u32>,
+}
+
+impl TimingConfig {
+    /// The same timing with every part between 0 (1 for choosing) and
+    /// `MAX_SECONDS`.
+    pub fn clamped(self) -> Self {
+        TimingConfig {
+            selection: self.selection.map(|seconds| seconds.clamp(1, MAX_SECONDS)),
+            reveal: self.reveal.min(MAX_SECONDS),
+            results: self.results.map(|seconds| seconds.min(MAX_SECONDS)),
+        }
+    }
+
+    /// The event that moves `state` on by itself and the seconds to wait for
+    /// it: the end of the reveal, then the next round's choosing, or the
+    /// final score, once the round's result has been shown. `None` while the
+    /// match waits on a player.
+    pub fn auto_advance(&self, state: &MatchState) -> Option<(u32, MatchEvent)> {
+        let timing = self.clamped();
+        match state.phase() {
+            MatchPhase::Revealing => Some((timing.reveal, MatchEvent::RevealFinished)),
+            MatchPhase::RoundSummary => timing.results.map(|seconds| (seconds, MatchEvent::Continue)),
+            _ => None,
+        }
+    }
+}
+
+impl Default for TimingConfig {
+    fn default() -> Self {
+        GameSpeed::default().timing()
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum GameSpeed {
+    Lightning,
+    #[default]
+    Quick,
+    Relaxed,
+    /// No time limit on choosing, and each round's result stays until the
+    /// player moves on.
+    Chill,
+    Custom(TimingConfig),
+}
+
+impl GameSpeed {
+    pub const PRESETS: [GameSpeed; 4] = [GameSpeed::Lightning, GameSpeed::Quick, GameSpeed::Relaxed, GameSpeed::Chill];
+
+    /// The value the speed is listed under in the settings.
+    pub fn key(&self) -> &'static str {
+        match self {
+            GameSpeed::Lightning => "lightning",
+            GameSpeed::Quick => "quick",
+            GameSpeed::Relaxed => "relaxed",
+            GameSpeed::Chill => "chill",
+            GameSpeed::Custom(_) => "custom",
+        }
+    }
+
+    pub fn timing(&self) -> TimingConfig {
+        let (selection, reveal, results) = match self {
+            GameSpeed::Lightning => (Some(1), 1, Some(2)),
+            GameSpeed::Quick => (Some(5), 2, Some(2)),
+            GameSpeed::Relaxed => (Some(10), 2, Some(3)),
+            GameSpeed::Chill => (None, 2, None),
+            GameSpeed::Custom(timing) => return timing.clamped(),
+        };
+        TimingConfig { selection, reveal, results }
+    }
+}
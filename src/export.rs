@@ -0,0 +1,105 @@
+//! Raster export of boards and rounds: PNG stills and an animated GIF replay.
+//!
+//! The SVGs are drawn with vector labels and rasterized with resvg, so no
+//! fonts are needed and the output is the same in the browser, the CLI and
+//! tests.
+
+use std::fmt;
+
+use resvg::{tiny_skia, usvg};
+
+use crate::engine::board::Board;
+use crate::engine::game_board::GameBoard;
+use crate::engine::layout::Layout;
+use crate::render::svg::{self, RenderOptions};
+
+/// Hundredths of a second each replay frame stays up; the final frame is held
+/// three times as long.
+pub const REPLAY_FRAME_DELAY: u16 = 80;
+
+#[derive(Debug)]
+pub enum ExportError {
+    Svg(usvg::Error),
+    InvalidSize(u32),
+    Png(String),
+    Gif(gif::EncodingError),
+    EmptyReplay,
+}
+
+impl fmt::Display for ExportError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExportError::Svg(e) => write!(f, "could not parse SVG: {}", e),
+            ExportError::InvalidSize(width) => write!(f, "cannot render at {} pixels", width),
+            ExportError::Png(e) => write!(f, "could not encode PNG: {}", e),
+            ExportError::Gif(e) => write!(f, "could not encode GIF: {}", e),
+            ExportError::EmptyReplay => write!(f, "the round has no steps to replay"),
+        }
+    }
+}
+
+impl std::error::Error for ExportError {}
+
+fn raster_options(options: &RenderOptions) -> RenderOptions {
+    RenderOptions { vector_labels: true, ..*options }
+}
+
+fn rasterize(svg: &str, width: u32) -> Result<tiny_skia::Pixmap, ExportError> {
+    let tree = usvg::Tree::from_str(svg, &usvg::Options::default()).map_err(ExportError::Svg)?;
+    let mut pixmap = tiny_skia::Pixmap::new(width, width).ok_or(ExportError::InvalidSize(width))?;
+    let scale = width as f32 / tree.size().width();
+    resvg::render(&tree, tiny_skia::Transform::from_scale(scale, scale), &mut pixmap.as_mut());
+    Ok(pixmap)
+}
+
+fn encode_png(pixmap: &tiny_skia::Pixmap) -> Result<Vec<u8>, ExportError> {
+    pixmap.encode_png().map_err(|e| ExportError::Png(e.to_string()))
+}
+
+/// A single board, over the map it was built on if any, as a square PNG
+/// `width` pixels across.
+pub fn board_png(
+    board: &Board,
+    layout: Option<&Layout>,
+    options: &RenderOptions,
+    width: u32,
+) -> Result<Vec<u8>, ExportError> {
+    let svg = svg::board_svg_on(board, layout, &raster_options(options));
+    encode_png(&rasterize(&svg, width)?)
+}
+
+/// A resolved round as a square PNG `width` pixels across.
+pub fn round_png(game_board: &GameBoard, options: &RenderOptions, width: u32) -> Result<Vec<u8>, ExportError> {
+    let svg = svg::round_svg(game_board, &raster_options(options));
+    encode_png(&rasterize(&svg, width)?)
+}
+
+/// An animated GIF stepping through the round one step per frame.
+pub fn replay_gif(game_board: &GameBoard, options: &RenderOptions, width: u32) -> Result<Vec<u8>, ExportError> {
+    let frames = game_board.replay_steps();
+    if frames.is_empty() {
+        return Err(ExportError::EmptyReplay);
+    }
+    let side = u16::try_from(width).map_err(|_| ExportError::InvalidSize(width))?;
+    let options = raster_options(options);
+
+    let mut bytes = Vec::new();
+    {
+        let mut encoder = gif::Encoder::new(&mut bytes, side, side, &[]).map_err(ExportError::Gif)?;
+        encoder.set_repeat(gif::Repeat::Infinite).map_err(ExportError::Gif)?;
+        for (index, frame_board) in frames.iter().enumerate() {
+            let pixmap = rasterize(&svg::round_svg(frame_board, &options), width)?;
+            // Everything is drawn over an opaque background, so the
+            // premultiplied pixels are already plain RGBA
+            let mut rgba = pixmap.take();
+            let mut frame = gif::Frame::from_rgba_speed(side, side, &mut rgba, 10);
+            frame.delay = if index + 1 == frames.len() {
+                REPLAY_FRAME_DELAY * 3
+            } else {
+                REPLAY_FRAME_DELAY
+            };
+            encoder.write_frame(&frame).map_err(ExportError::Gif)?;
+        }
+    }
+    Ok(bytes)
+}
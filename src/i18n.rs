@@ -0,0 +1,369 @@
+//! Translated text for everything the game shows, from message bundles in a
+//! subset of [Fluent](https://projectfluent.org) syntax.
+//!
+//! Each language ships as one `.ftl` file in `locales/`. A message is
+//! `id = text`, continued on indented lines, with `{ $name }` placeables for
+//! its arguments, `{ "…" }` string literals, and selectors:
+//!
+//! ```text
+//! seconds-left = { $seconds ->
+//!         [one] { $seconds } second left!
+//!        *[other] { $seconds } seconds left!
+//!     }
+//! ```
+//!
+//! A variant is picked by an exact match on the argument, then by the plural
+//! category of a number in the bundle's language, then the `*` default.
+//! Messages missing from a translation fall back to English.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::sync::OnceLock;
+
+use serde::{Serialize, Deserialize};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Locale {
+    #[default]
+    English,
+    Spanish,
+}
+
+impl Locale {
+    pub const ALL: [Locale; 2] = [Locale::English, Locale::Spanish];
+
+    /// The BCP 47 tag, as set on the page's `lang` attribute.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Locale::English => "en",
+            Locale::Spanish => "es",
+        }
+    }
+
+    /// The language's name in that language, for the picker.
+    pub fn label(&self) -> &'static str {
+        match self {
+            Locale::English => "English",
+            Locale::Spanish => "Español",
+        }
+    }
+
+    /// The CLDR plural category of `n`. Spanish's "many", for round
+    /// millions, never comes up in the game and is left out.
+    pub fn plural_category(&self, n: i64) -> &'static str {
+        match self {
+            Locale::English | Locale::Spanish if n == 1 => "one",
+            Locale::English | Locale::Spanish => "other",
+        }
+    }
+
+    fn source(&self) -> &'static str {
+        match self {
+            Locale::English => include_str!("../locales/en.ftl"),
+            Locale::Spanish => include_str!("../locales/es.ftl"),
+        }
+    }
+}
+
+/// A value passed to a message.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Arg {
+    Number(i64),
+    Text(String),
+}
+
+impl fmt::Display for Arg {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Arg::Number(n) => write!(f, "{}", n),
+            Arg::Text(text) => f.write_str(text),
+        }
+    }
+}
+
+impl From<i64> for Arg {
+    fn from(n: i64) -> Self {
+        Arg::Number(n)
+    }
+}
+
+impl From<i32> for Arg {
+    fn from(n: i32) -> Self {
+        Arg::Number(n.into())
+    }
+}
+
+impl From<u32> for Arg {
+    fn from(n: u32) -> Self {
+        Arg::Number(n.into())
+    }
+}
+
+impl From<usize> for Arg {
+    fn from(n: usize) -> Self {
+        Arg::Number(n as i64)
+    }
+}
+
+impl From<&str> for Arg {
+    fn from(text: &str) -> Self {
+        Arg::Text(text.to_string())
+    }
+}
+
+impl From<String> for Arg {
+    fn from(text: String) -> Self {
+        Arg::Text(text)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Element {
+    Text(String),
+    Variable(String),
+    Select {
+        selector: String,
+        variants: Vec<(String, Vec<Element>)>,
+        default: usize,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseError {
+    /// The line the broken message starts on, from 1.
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// The messages of one language.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Bundle {
+    locale: Locale,
+    messages: BTreeMap<String, Vec<Element>>,
+}
+
+impl Bundle {
+    pub fn parse(locale: Locale, source: &str) -> Result<Bundle, ParseError> {
+        let lines: Vec<&str> = source.lines().collect();
+        let mut messages = BTreeMap::new();
+        let mut index = 0;
+        while index < lines.len() {
+            let line = lines[index];
+            let number = index + 1;
+            index += 1;
+            let error = |message: String| ParseError { line: number, message };
+            if line.trim().is_empty() || line.trim_start().starts_with('#') {
+                continue;
+            }
+            if line.starts_with(char::is_whitespace) {
+                return Err(error("indented line outside a message".to_string()));
+            }
+            let (id, value) = line.split_once('=').ok_or_else(|| error("expected `id = text`".to_string()))?;
+            let id = id.trim();
+            if !is_identifier(id) {
+                return Err(error(format!("`{}` is not a message id", id)));
+            }
+            let mut body = value.trim().to_string();
+            while index < lines.len() && lines[index].starts_with(char::is_whitespace) && !lines[index].trim().is_empty() {
+                if !body.is_empty() {
+                    body.push('\n');
+                }
+                body.push_str(lines[index].trim());
+                index += 1;
+            }
+            let pattern = parse_pattern(&body).map_err(error)?;
+            if messages.insert(id.to_string(), pattern).is_some() {
+                return Err(error(format!("`{}` is defined twice", id)));
+            }
+        }
+        Ok(Bundle { locale, messages })
+    }
+
+    pub fn locale(&self) -> Locale {
+        self.locale
+    }
+
+    pub fn contains(&self, id: &str) -> bool {
+        self.messages.contains_key(id)
+    }
+
+    /// Every message id, in order.
+    pub fn ids(&self) -> impl Iterator<Item = &str> {
+        self.messages.keys().map(String::as_str)
+    }
+
+    /// The arguments message `id` uses, in order and without repeats.
+    pub fn variables(&self, id: &str) -> Vec<String> {
+        fn collect(pattern: &[Element], found: &mut Vec<String>) {
+            for element in pattern {
+                let name = match element {
+                    Element::Text(_) => continue,
+                    Element::Variable(name) => name,
+                    Element::Select { selector, variants, .. } => {
+                        for (_, variant) in variants {
+                            collect(variant, found);
+                        }
+                        selector
+                    }
+                };
+                if !found.contains(name) {
+                    found.push(name.clone());
+                }
+            }
+        }
+        let mut found = Vec::new();
+        if let Some(pattern) = self.messages.get(id) {
+            collect(pattern, &mut found);
+        }
+        found.sort();
+        found
+    }
+
+    /// Message `id` with `args` filled in; `None` if the bundle lacks it. A
+    /// missing argument is left as `{$name}`.
+    pub fn format(&self, id: &str, args: &[(&str, Arg)]) -> Option<String> {
+        let pattern = self.messages.get(id)?;
+        let mut out = String::new();
+        self.write(pattern, args, &mut out);
+        Some(out)
+    }
+
+    fn write(&self, pattern: &[Element], args: &[(&str, Arg)], out: &mut String) {
+        let lookup = |name: &str| args.iter().find(|(arg, _)| *arg == name).map(|(_, value)| value);
+        for element in pattern {
+            match element {
+                Element::Text(text) => out.push_str(text),
+                Element::Variable(name) => match lookup(name) {
+                    Some(value) => out.push_str(&value.to_string()),
+                    None => out.push_str(&format!("{{${}}}", name)),
+                },
+                Element::Select { selector, variants, default } => {
+                    let value = lookup(selector);
+                    let exact = variants.iter().position(|(key, _)| value.is_some_and(|value| *key == value.to_string()));
+                    let category = match value {
+                        Some(Arg::Number(n)) => {
+                            let category = self.locale.plural_category(*n);
+                            variants.iter().position(|(key, _)| key == category)
+                        }
+                        _ => None,
+                    };
+                    let chosen = exact.or(category).unwrap_or(*default);
+                    self.write(&variants[chosen].1, args, out);
+                }
+            }
+        }
+    }
+}
+
+/// The bundle shipped for `locale`.
+pub fn bundle(locale: Locale) -> &'static Bundle {
+    static BUNDLES: OnceLock<Vec<Bundle>> = OnceLock::new();
+    let bundles = BUNDLES.get_or_init(|| {
+        Locale::ALL
+            .iter()
+            .map(|locale| {
+                Bundle::parse(*locale, locale.source())
+                    .unwrap_or_else(|e| panic!("the {} messages do not parse: {}", locale.code(), e))
+            })
+            .collect()
+    });
+    &bundles[Locale::ALL.iter().position(|l| *l == locale).unwrap_or(0)]
+}
+
+/// Message `id` in `locale`, falling back to English and then to the id
+/// itself.
+pub fn translate(locale: Locale, id: &str, args: &[(&str, Arg)]) -> String {
+    bundle(locale)
+        .format(id, args)
+        .or_else(|| bundle(Locale::English).format(id, args))
+        .unwrap_or_else(|| id.to_string())
+}
+
+fn is_identifier(id: &str) -> bool {
+    id.starts_with(|c: char| c.is_ascii_alphabetic())
+        && id.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+}
+
+fn parse_pattern(source: &str) -> Result<Vec<Element>, String> {
+    let mut pattern = Vec::new();
+    let mut rest = source;
+    while let Some(start) = rest.find(['{', '}']) {
+        if rest[start..].starts_with('}') {
+            return Err("unmatched `}`".to_string());
+        }
+        if start > 0 {
+            pattern.push(Element::Text(rest[..start].to_string()));
+        }
+        let end = start + closing_brace(&rest[start..]).ok_or("unclosed `{`")?;
+        pattern.push(parse_placeable(&rest[start + 1..end])?);
+        rest = &rest[end + 1..];
+    }
+    if !rest.is_empty() {
+        pattern.push(Element::Text(rest.to_string()));
+    }
+    Ok(pattern)
+}
+
+/// The index of the `}` closing the `{` that `source` starts with.
+fn closing_brace(source: &str) -> Option<usize> {
+    let mut depth = 0;
+    for (index, c) in source.char_indices() {
+        match c {
+            '{' => depth += 1,
+            '}' if depth == 1 => return Some(index),
+            '}' => depth -= 1,
+            _ => {}
+        }
+    }
+    None
+}
+
+fn parse_placeable(inner: &str) -> Result<Element, String> {
+    let inner = inner.trim();
+    if let Some((selector, rest)) = inner.split_once("->") {
+        let selector = variable(selector.trim())?;
+        let mut variants = Vec::new();
+        let mut default = None;
+        for line in rest.lines().map(str::trim).filter(|line| !line.is_empty()) {
+            let (is_default, line) = match line.strip_prefix('*') {
+                Some(line) => (true, line),
+                None => (false, line),
+            };
+            let (key, pattern) = line
+                .strip_prefix('[')
+                .and_then(|line| line.split_once(']'))
+                .ok_or_else(|| format!("expected `[key] text` in the selector on `${}`", selector))?;
+            if is_default {
+                if default.is_some() {
+                    return Err(format!("the selector on `${}` has two defaults", selector));
+                }
+                default = Some(variants.len());
+            }
+            variants.push((key.trim().to_string(), parse_pattern(pattern.trim())?));
+        }
+        let default = default.ok_or_else(|| format!("the selector on `${}` needs a `*` default", selector))?;
+        return Ok(Element::Select { selector, variants, default });
+    }
+    if inner.starts_with('$') {
+        return Ok(Element::Variable(variable(inner)?));
+    }
+    if let Some(text) = inner.strip_prefix('"').and_then(|inner| inner.strip_suffix('"')) {
+        return Ok(Element::Text(text.to_string()));
+    }
+    Err(format!("unsupported placeable `{{ {} }}`", inner))
+}
+
+fn variable(source: &str) -> Result<String, String> {
+    match source.strip_prefix('$') {
+        Some(name) if is_identifier(name) => Ok(name.to_string()),
+        _ => Err(format!("`{}` is not a variable", source)),
+    }
+}
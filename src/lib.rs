@@ -0,0 +1,5 @@
+pub mod engine;
+#[cfg(feature = "export")]
+pub mod export;
+pub mod i18n;
+pub mod render;
@@ -7,10 +7,18 @@ use std::collections::HashMap;
 
 mod components;
 use components::board::BoardCreator;
-use components::game::{Game, GameSpeed};
+use components::game::{Game, GameSpeed, GameState};
+use spaces_game::engine::timing::{TimingConfig, MAX_SECONDS};
+use components::pwa::AppPrompts;
 use components::saved_boards::SavedBoards;
+use components::utils::{clear_match, get_board_theme, get_locale, load_match, t, t_with};
+use spaces_game::i18n::Locale;
+use spaces_game::engine::persona::Persona;
+use spaces_game::engine::rules::Variant;
+use spaces_game::render::svg::Theme;
 use components::opponent::{
-    delete_opponent, Opponent, OpponentType, load_opponents, save_opponent
+    delete_opponent, migrate_opponent_ids, Opponent, OpponentAvatar, OpponentEditor, OpponentType, load_opponents,
+    save_opponent, update_opponent
 };
 
 #[derive(Serialize, Deserialize, Clone, PartialEq)]
@@ -18,6 +26,9 @@ pub struct OpponentStats {
     pub opponent_id: String,
     pub wins: i32,
     pub losses: i32,
+    /// Matches abandoned before the last round; each also counts as a loss.
+    #[serde(default)]
+    pub forfeits: i32,
 }
 
 #[derive(Serialize, Deserialize, Clone, PartialEq)]
@@ -26,6 +37,12 @@ pub struct UserData {
     greeting: String,
     default_game_speed: GameSpeed,
     opponent_stats: HashMap<String, OpponentStats>,
+    #[serde(default)]
+    board_theme: Theme,
+    #[serde(default)]
+    default_variant: Variant,
+    #[serde(default)]
+    language: Locale,
 }
 
 fn get_local_storage() -> Option<Storage> {
@@ -56,20 +73,33 @@ fn load_user_data() -> Option<UserData> {
 }
 
 // Modify the save_user_data function:
-fn save_user_data(name: &str, greeting: &str, speed: GameSpeed) -> Result<(), serde_json::Error> {
+fn save_user_data(
+    name: &str,
+    greeting: &str,
+    speed: GameSpeed,
+    theme: Theme,
+    variant: Variant,
+    language: Locale,
+) -> Result<(), serde_json::Error> {
     if let Some(storage) = get_local_storage() {
         // First try to load existing data to preserve opponent stats
         let mut existing_data = load_user_data().unwrap_or_else(|| UserData {
             name: name.to_string(),
             greeting: greeting.to_string(),
-            default_game_speed: speed.clone(),
+            default_game_speed: speed,
             opponent_stats: HashMap::new(),  // Initialize empty stats
+            board_theme: theme,
+            default_variant: variant,
+            language,
         });
         
         // Update the basic info
         existing_data.name = name.to_string();
         existing_data.greeting = greeting.to_string();
-        existing_data.default_game_speed = speed.clone();
+        existing_data.default_game_speed = speed;
+        existing_data.board_theme = theme;
+        existing_data.default_variant = variant;
+        existing_data.language = language;
 
         let json = serde_json::to_string(&existing_data)?;
         storage.set_item("user_data", &json).unwrap_or_else(|e| {
@@ -89,6 +119,9 @@ pub fn update_opponent_stats(opponent_id: &str, won: bool) -> Result<(), serde_j
             greeting: String::new(),
             default_game_speed: GameSpeed::Quick,
             opponent_stats: HashMap::new(),
+            board_theme: Theme::default(),
+            default_variant: Variant::default(),
+            language: Locale::default(),
         });
 
         let stats = user_data.opponent_stats.entry(opponent_id.to_string())
@@ -96,6 +129,7 @@ pub fn update_opponent_stats(opponent_id: &str, won: bool) -> Result<(), serde_j
                 opponent_id: opponent_id.to_string(),
                 wins: 0,
                 losses: 0,
+                forfeits: 0,
             });
 
         if won {
@@ -112,6 +146,60 @@ pub fn update_opponent_stats(opponent_id: &str, won: bool) -> Result<(), serde_j
     Ok(())
 }
 
+/// The player's (wins, losses) against `opponent_id`.
+pub fn opponent_record(opponent_id: &str) -> (i32, i32) {
+    load_user_data()
+        .and_then(|user_data| user_data.opponent_stats.get(opponent_id).map(|stats| (stats.wins, stats.losses)))
+        .unwrap_or((0, 0))
+}
+
+/// Records an abandoned match against `opponent_id` as a loss and a forfeit.
+pub fn record_forfeit(opponent_id: &str) -> Result<(), serde_json::Error> {
+    update_opponent_stats(opponent_id, false)?;
+    if let (Some(storage), Some(mut user_data)) = (get_local_storage(), load_user_data()) {
+        if let Some(stats) = user_data.opponent_stats.get_mut(opponent_id) {
+            stats.forfeits += 1;
+        }
+        let json = serde_json::to_string(&user_data)?;
+        storage.set_item("user_data", &json).unwrap_or_else(|e| {
+            web_sys::console::log_1(&format!("Failed to save to storage: {:?}", e).into());
+        });
+    }
+    Ok(())
+}
+
+/// Moves the stats kept under each old opponent ID to its new one.
+fn move_opponent_stats(moved: &[(String, String)]) -> Result<(), serde_json::Error> {
+    if let (Some(storage), Some(mut user_data)) = (get_local_storage(), load_user_data()) {
+        for (old, new) in moved {
+            if let Some(mut stats) = user_data.opponent_stats.remove(old) {
+                stats.opponent_id = new.clone();
+                user_data.opponent_stats.insert(new.clone(), stats);
+            }
+        }
+        let json = serde_json::to_string(&user_data)?;
+        storage.set_item("user_data", &json).unwrap_or_else(|e| {
+            web_sys::console::log_1(&format!("Failed to save to storage: {:?}", e).into());
+        });
+    }
+    Ok(())
+}
+
+/// The saved record of `opponent` as it stands, so that rows keyed by ID
+/// show edits without being rebuilt.
+fn current_opponent(opponents: Memo<Vec<Opponent>>, opponent: Opponent) -> Memo<Opponent> {
+    Memo::new(move |_| {
+        opponents
+            .with(|all| all.iter().find(|saved| saved.id == opponent.id).cloned())
+            .unwrap_or_else(|| opponent.clone())
+    })
+}
+
+/// A speed for the Play menu, kept on one line.
+fn speed_option(speed: &GameSpeed) -> String {
+    t(&format!("speed-{}", speed.key())).replace(' ', "\u{00A0}")
+}
+
 #[component]
 fn App() -> impl IntoView {
     let (name, set_name) = signal(String::new());
@@ -119,30 +207,61 @@ fn App() -> impl IntoView {
     let (show_form, set_show_form) = signal(true);
     let (show_profile, set_show_profile) = signal(false);
     let (default_game_speed, set_default_game_speed) = signal(GameSpeed::Quick);
-    let (show_game, set_show_game) = signal(None::<(Opponent, GameSpeed)>);
+    // The timing the custom speed is set to, kept while a preset is picked
+    let custom_timing = RwSignal::new(TimingConfig::default());
+    let board_theme = get_board_theme();
+    let language = get_locale();
+    let default_variant = RwSignal::new(Variant::default());
+    let (show_game, set_show_game) = signal(None::<(Opponent, GameSpeed, Option<GameState>)>);
+    // A match left unfinished by a reload or "Exit Game"
+    let saved_match = RwSignal::new(load_match());
     let (show_board_creator, set_show_board_creator) = signal(false);
     let opponent_to_delete = RwSignal::new(None::<Opponent>);
+    // The opponent being added or edited in the profile
+    let opponent_to_edit = RwSignal::new(None::<Opponent>);
     let opponents_trigger = RwSignal::new(false);
+
+    // Opponents saved before IDs were unique keep their records under new ones
+    match migrate_opponent_ids() {
+        Ok(moved) if !moved.is_empty() => {
+            let _ = move_opponent_stats(&moved);
+        }
+        Ok(_) => {}
+        Err(e) => web_sys::console::log_1(&format!("Failed to migrate opponents: {}", e).into()),
+    }
     let opponents = Memo::new(move |_| {
         opponents_trigger.get();
         load_opponents().unwrap_or_default()
     });
 
-    let cpu_opponent = Opponent::new("CPU".to_string(), OpponentType::Computer);
-    let _ = save_opponent(cpu_opponent);
+    for persona in Persona::ALL {
+        let _ = save_opponent(Opponent::computer(persona));
+    }
 
     if let Some(data) = load_user_data() {
         set_name.set(data.name);
         set_greeting.set(data.greeting);
         set_default_game_speed.set(data.default_game_speed); // Add this line
+        custom_timing.set(data.default_game_speed.timing());
+        board_theme.set(data.board_theme);
+        default_variant.set(data.default_variant);
+        language.set(data.language);
         set_show_form.set(false);
     }
 
+    // Screen readers and spell checkers follow the page's language
+    Effect::new(move |_| {
+        let code = language.get().code();
+        if let Some(root) = window().and_then(|w| w.document()).and_then(|d| d.document_element()) {
+            let _ = root.set_attribute("lang", code);
+        }
+    });
+
     let handle_submit = move |_: MouseEvent| {
         if !name.get().is_empty() {
             let greeting_text = format!("Hello, {}!", name.get());
             set_greeting.set(greeting_text.clone());
-            let _ = save_user_data(&name.get(), &greeting_text, GameSpeed::Relaxed);
+            let _ = save_user_data(&name.get(), &greeting_text, GameSpeed::Relaxed, board_theme.get(), default_variant.get(), language.get());
             set_show_form.set(false);
         }
     };
@@ -151,7 +270,7 @@ fn App() -> impl IntoView {
         if ev.key() == "Enter" && !name.get().is_empty() {
             let greeting_text = format!("Hello, {}!", name.get());
             set_greeting.set(greeting_text.clone());
-            let _ = save_user_data(&name.get(), &greeting_text, GameSpeed::Relaxed);
+            let _ = save_user_data(&name.get(), &greeting_text, GameSpeed::Relaxed, board_theme.get(), default_variant.get(), language.get());
             set_show_form.set(false);
         }
     };
@@ -159,10 +278,12 @@ fn App() -> impl IntoView {
     view! {
         <div class="min-h-screen bg-slate-900 text-white flex flex-col items-center justify-center gap-4">
             <h1 class="text-4xl font-bold">
+                // The saved greeting only marks that a name was given; it is
+                // worded in the current language
                 {move || if greeting.get().is_empty() {
-                    "Hi! What's your name?".to_string()
+                    t("greeting-prompt")
                 } else {
-                    greeting.get()
+                    t_with("greeting", &[("name", name.get().into())])
                 }}
             </h1>
             {move || (!show_form.get()).then(|| view! {
@@ -170,7 +291,7 @@ fn App() -> impl IntoView {
                     class="text-blue-400 hover:text-blue-300 text-sm mb-4"
                     on:click=move |_| set_show_profile.set(true)
                 >
-                    "Edit Profile"
+                    {t("edit-profile")}
                 </button>
             })}
             {move || show_form.get().then(|| view! {
@@ -188,21 +309,67 @@ fn App() -> impl IntoView {
                         class="px-4 py-2 bg-blue-600 rounded hover:bg-blue-700"
                         on:click=handle_submit
                     >
-                        "Hello"
+                        {t("greeting-submit")}
                     </button>
                 </>
             })}
+            {move || (!show_form.get()).then(|| saved_match.get()).flatten().map(|state| {
+                let opponent_name = state.player2.as_ref().map(|p| p.name.clone()).unwrap_or_default();
+                let (player_score, opponent_score) = state.match_state.scores();
+                let (played, rounds) = (state.match_state.rounds().len(), state.match_state.config().rounds);
+                let resume_state = state.clone();
+                view! {
+                    <div class="flex items-center gap-4 p-3 bg-slate-800 border border-blue-600 rounded w-full max-w-4xl">
+                        <div class="flex-1 text-gray-300">
+                            {t_with("saved-match", &[
+                                ("opponent", opponent_name.into()),
+                                ("played", played.into()),
+                                ("rounds", rounds.into()),
+                                ("playerScore", player_score.into()),
+                                ("opponentScore", opponent_score.into()),
+                            ])}
+                        </div>
+                        <button
+                            class="px-3 py-1 bg-blue-600 hover:bg-blue-700 rounded text-sm"
+                            on:click=move |_| {
+                                if let Some(opponent) = resume_state.player2.clone() {
+                                    let speed = resume_state.speed;
+                                    set_show_game.set(Some((opponent, speed, Some(resume_state.clone()))));
+                                }
+                            }
+                        >
+                            {t("resume")}
+                        </button>
+                        <button
+                                class="px-3 py-1 bg-red-700 hover:bg-red-600 rounded text-sm"
+                                title=t("abandon-title")
+                                on:click=move |_| {
+                                    if let Some(opponent) = &state.player2 {
+                                        let _ = record_forfeit(&opponent.id);
+                                    }
+                                    clear_match();
+                                    saved_match.set(None);
+                                    opponents_trigger.update(|v| *v = !*v);
+                                }
+                            >
+                                {t("abandon")}
+                            </button>
+                    </div>
+                }
+            })}
             {move || (!show_form.get()).then(|| view! {
                 <div class="grid grid-cols-2 gap-8 w-full max-w-4xl px-4">
                 <div>
-                <h2 class="text-2xl font-bold mb-4">"Opponents"</h2>
+                <h2 class="text-2xl font-bold mb-4">{t("opponents")}</h2>
                 <div class="flex flex-col gap-2">
                     <For
                         each=move || opponents.get()
                         key=|opponent| opponent.id.clone()
                         children=move |opponent: Opponent| {
                             let opponent_id = opponent.id.clone();
-                            let opponent_stats = create_memo(move |_| {
+                            let profile = current_opponent(opponents, opponent);
+                            let play = move |speed: GameSpeed| set_show_game.set(Some((profile.get_untracked(), speed, None)));
+                            let opponent_stats = Memo::new(move |_| {
                                 opponents_trigger.get();  // Force recalculation when trigger changes
                                 if let Some(user_data) = load_user_data() {
                                     if let Some(stats) = user_data.opponent_stats.get(&opponent_id) {
@@ -217,35 +384,40 @@ fn App() -> impl IntoView {
                             
                             view! {
                                 <div class="flex items-center justify-between p-2 bg-slate-800 rounded">
-                                <div class="flex items-center gap-2 text-gray-300">
-                                    <span class="w-4 h-4 rounded-full bg-blue-600 flex items-center justify-center text-xs">
-                                        {if matches!(opponent.opponent_type, OpponentType::Computer) { "C" } else { "H" }}
-                                    </span>
-                                    {opponent.name.clone()}
+                                <div
+                                    class="flex items-center gap-2 text-gray-300"
+                                    title=move || profile.with(|opponent| {
+                                        let computer = matches!(opponent.opponent_type, OpponentType::Computer);
+                                        Some(opponent.notes.clone()).filter(|notes| !notes.trim().is_empty()).or_else(|| {
+                                            computer.then(|| t(&format!("persona-{}-description", opponent.persona.name().to_lowercase())))
+                                        })
+                                    })
+                                >
+                                    {move || view! { <OpponentAvatar opponent=profile.get()/> }}
+                                    {move || profile.with(|opponent| opponent.name.clone())}
                                     <span class="text-sm text-gray-500 ml-2">
                                         "(" {move || opponent_stats.get().0} "-" {move || opponent_stats.get().1} ")"
                                     </span>
                                 </div>
                                         <div class="flex gap-2">
                                         {
-                                            let delete_opponent = opponent.clone();
                                             view! {
                                                 <div class="flex gap-1">
                                                 {
-                                                    let opponent_lightning = opponent.clone();
-                                                    let opponent_quick = opponent.clone();
-                                                    let opponent_relaxed = opponent.clone();
-                                                    let opponent_chill = opponent.clone();
                                                     view! {
                                                         <button
-                                                            class="px-3 py-1 bg-green-600 hover:bg-green-700 rounded-l text-sm"
-                                                            on:click=move |_| set_show_game.set(Some((opponent.clone(), default_game_speed.get())))
+                                                            class="px-3 py-1 bg-green-600 hover:bg-green-700 rounded-l text-sm disabled:opacity-50"
+                                                            disabled=move || saved_match.get().is_some()
+                                                            on:click=move |_| {
+                                                                play(profile.with_untracked(|opponent| opponent.speed).unwrap_or(default_game_speed.get()));
+                                                            }
                                                         >
-                                                            "Play\u{00A0}\u{00A0}\u{00A0}\u{00A0}\u{00A0}\u{00A0}\u{00A0}\u{00A0}\u{00A0}\u{00A0}\u{00A0}\u{00A0}\u{00A0}\u{00A0}\u{00A0}\u{00A0}\u{00A0}\u{00A0}\u{00A0}\u{00A0}\u{00A0}\u{00A0}\u{00A0}\u{00A0}\u{00A0}"
+                                                            {move || format!("{}{}", t("play"), "\u{00A0}".repeat(25))}
                                                         </button>
                                                         <div class="relative">
                                                             <button
-                                                                class="px-2 py-1 bg-green-600 hover:bg-green-700 rounded-r text-sm border-l border-green-700"
+                                                                class="px-2 py-1 bg-green-600 hover:bg-green-700 rounded-r text-sm border-l border-green-700 disabled:opacity-50"
+                                                                disabled=move || saved_match.get().is_some()
                                                                 on:click=move |ev| {
                                                                     ev.prevent_default();
                                                                     let target = ev.target().unwrap();
@@ -276,35 +448,44 @@ fn App() -> impl IntoView {
                                                                     <button
                                                                         class="block w-full text-left px-3 py-1 hover:bg-green-700 text-sm border-t border-green-700"
                                                                         on:click=move |_| {
-                                                                            set_show_game.set(Some((opponent_lightning.clone(), GameSpeed::Lightning)));
+                                                                            play(GameSpeed::Lightning);
                                                                         }
                                                                     >
-                                                                        "Lightning!\u{00A0}(1s\u{00A0}to\u{00A0}choose)"
+                                                                        {move || speed_option(&GameSpeed::Lightning)}
                                                                     </button>
                                                                     <button
                                                                         class="block w-full text-left px-3 py-1 hover:bg-green-700 text-sm border-t border-green-700"
                                                                         on:click=move |_| {
-                                                                            set_show_game.set(Some((opponent_quick.clone(), GameSpeed::Quick)));
+                                                                            play(GameSpeed::Quick);
                                                                         }
                                                                     >
-                                                                        "Quick!\u{00A0}(5s\u{00A0}to\u{00A0}choose)"
+                                                                        {move || speed_option(&GameSpeed::Quick)}
                                                                     </button>
                                                                     <button
                                                                         class="block w-full text-left px-3 py-1 hover:bg-green-700 text-sm border-t border-green-700"
                                                                         on:click=move |_| {
-                                                                            set_show_game.set(Some((opponent_relaxed.clone(),GameSpeed::Relaxed)));
+                                                                            play(GameSpeed::Relaxed);
                                                                         }
                                                                     >
-                                                                        "Relaxed\u{00A0}(10s\u{00A0}to\u{00A0}choose)"
+                                                                        {move || speed_option(&GameSpeed::Relaxed)}
                                                                     </button>
                                                                     <button
                                                                         class="block w-full text-left px-3 py-1 hover:bg-green-700 text-sm border-t border-green-700 rounded-b"  // Added rounded-b
                                                                         on:click=move |_| {
-                                                                            set_show_game.set(Some((opponent_chill.clone(),GameSpeed::Chill)));
+                                                                            play(GameSpeed::Chill);
                                                                         }
                                                                     >
-                                                                        "Totally\u{00A0}Chill\u{00A0}(no\u{00A0}limit)"
+                                                                        {move || speed_option(&GameSpeed::Chill)}
                                                                     </button>
+                                                                    // The custom timing from the profile, when it's the default
+                                                                    {move || matches!(default_game_speed.get(), GameSpeed::Custom(_)).then(|| view! {
+                                                                        <button
+                                                                            class="block w-full text-left px-3 py-1 hover:bg-green-700 text-sm border-t border-green-700 rounded-b"
+                                                                            on:click=move |_| play(default_game_speed.get())
+                                                                        >
+                                                                            {move || speed_option(&default_game_speed.get())}
+                                                                        </button>
+                                                                    })}
                                                                 </div>
                                                         </div>
                                                     }
@@ -322,18 +503,16 @@ fn App() -> impl IntoView {
                     {move || opponent_to_delete.get().map(|opponent| view! {
                         <div class="fixed inset-0 bg-black bg-opacity-50 flex items-center justify-center z-50">
                             <div class="bg-slate-800 p-6 rounded-lg shadow-xl max-w-md w-full mx-4">
-                                <h3 class="text-xl font-bold mb-4">"Confirm Removal"</h3>
+                                <h3 class="text-xl font-bold mb-4">{t("confirm-removal")}</h3>
                                 <p class="text-gray-300 mb-6">
-                                    "Are you sure you want to remove "
-                                    <span class="font-semibold">{opponent.name.clone()}</span>
-                                    " from your opponents list?"
+                                    {t_with("confirm-removal-text", &[("name", opponent.name.clone().into())])}
                                 </p>
                                 <div class="flex justify-end gap-4">
                                     <button
                                         class="px-4 py-2 bg-gray-700 hover:bg-gray-600 rounded"
                                         on:click=move |_| opponent_to_delete.set(None)
                                     >
-                                        "Cancel"
+                                        {t("cancel")}
                                     </button>
                                     <button
                                         class="px-4 py-2 bg-red-600 hover:bg-red-700 rounded"
@@ -343,7 +522,7 @@ fn App() -> impl IntoView {
                                             opponent_to_delete.set(None);
                                         }
                                     >
-                                        "Remove"
+                                        {t("remove")}
                                     </button>
                                 </div>
                             </div>
@@ -351,7 +530,7 @@ fn App() -> impl IntoView {
                     })}
                 </div>
                     <div>
-                        <h2 class="text-2xl font-bold mb-4">"Boards"</h2>
+                        <h2 class="text-2xl font-bold mb-4">{t("boards")}</h2>
                         {move || (!show_board_creator.get()).then(|| view! {
                             <a 
                                 href="#" 
@@ -361,7 +540,7 @@ fn App() -> impl IntoView {
                                     set_show_board_creator.set(true);
                                 }
                             >
-                                "+ Create New Board"
+                                {t("create-board")}
                             </a>
                         })}
                         {move || show_board_creator.get().then(|| view! {
@@ -374,13 +553,17 @@ fn App() -> impl IntoView {
                 </div>
             })}
         </div>
-        {move || show_game.get().map(|(opponent, speed)| view! {  
+        <AppPrompts/>
+        {move || show_game.get().map(|(opponent, speed, resume)| view! {  
             <Game
                 player_name=name.get()
                 opponent=opponent
                 speed=speed  
+                variant=default_variant.get_untracked()
+                resume=resume
                 on_exit=move || {
                     opponents_trigger.update(|v| *v = !*v);  
+                    saved_match.set(load_match());
                     set_show_game.set(None)
                 }
                 on_stats_update=move || {
@@ -391,11 +574,11 @@ fn App() -> impl IntoView {
         {move || show_profile.get().then(|| view! {
             <div class="fixed inset-0 bg-black bg-opacity-50 flex items-center justify-center z-50">
                 <div class="bg-slate-800 p-6 rounded-lg shadow-xl max-w-md w-full mx-4 text-white">
-                    <h3 class="text-xl font-bold mb-4">"Edit Profile"</h3>
+                    <h3 class="text-xl font-bold mb-4">{t("edit-profile")}</h3>
                     <div class="flex flex-col gap-4">
                         <div>
                             <label class="block text-sm font-medium mb-1">
-                                "Username"
+                                {t("username")}
                             </label>
                             <input
                                 type="text"
@@ -406,54 +589,169 @@ fn App() -> impl IntoView {
                         </div>
                         <div>
                             <label class="block text-sm font-medium mb-1">
-                                "Default Game Speed"
+                                {t("default-speed")}
                             </label>
                             <select
                                 class="w-full px-4 py-2 rounded bg-slate-700 border border-slate-600 text-white"
                                 on:change=move |ev| {
-                                    match event_target_value(&ev).as_str() {
-                                        "lightning" => set_default_game_speed.set(GameSpeed::Lightning),
-                                        "quick" => set_default_game_speed.set(GameSpeed::Quick),
-                                        "relaxed" => set_default_game_speed.set(GameSpeed::Relaxed),
-                                        "chill" => set_default_game_speed.set(GameSpeed::Chill),
-                                        _ => (),
+                                    let key = event_target_value(&ev);
+                                    let speed = match GameSpeed::PRESETS.into_iter().find(|speed| speed.key() == key) {
+                                        Some(preset) => preset,
+                                        None => GameSpeed::Custom(custom_timing.get()),
+                                    };
+                                    set_default_game_speed.set(speed);
+                                }
+                            >
+                                {GameSpeed::PRESETS.into_iter().chain([GameSpeed::Custom(TimingConfig::default())]).map(|speed| view! {
+                                    <option
+                                        value=speed.key()
+                                        selected=move || default_game_speed.get().key() == speed.key()
+                                        class="text-white bg-slate-700"
+                                    >
+                                        {t(&format!("speed-{}", speed.key()))}
+                                    </option>
+                                }).collect_view()}
+                            </select>
+                            {move || matches!(default_game_speed.get(), GameSpeed::Custom(_)).then(|| {
+                                // A blank or 0 means no limit, or waiting for the player
+                                let field = move |label: &'static str, read: fn(&TimingConfig) -> u32, write: fn(&mut TimingConfig, u32)| view! {
+                                    <label class="flex items-center justify-between gap-2 mt-2 text-sm">
+                                        {t(label)}
+                                        <input
+                                            type="number"
+                                            min="0"
+                                            max=MAX_SECONDS
+                                            class="w-20 px-2 py-1 rounded bg-slate-700 border border-slate-600 text-white"
+                                            prop:value=move || read(&custom_timing.get()).to_string()
+                                            on:change=move |ev| {
+                                                let seconds = event_target_value(&ev).trim().parse().unwrap_or(0);
+                                                custom_timing.update(|timing| {
+                                                    write(timing, seconds);
+                                                    *timing = timing.clamped();
+                                                });
+                                                set_default_game_speed.set(GameSpeed::Custom(custom_timing.get()));
+                                            }
+                                        />
+                                    </label>
+                                };
+                                view! {
+                                    {field("timing-selection", |timing| timing.selection.unwrap_or(0), |timing, seconds| {
+                                        timing.selection = (seconds > 0).then_some(seconds);
+                                    })}
+                                    {field("timing-reveal", |timing| timing.reveal, |timing, seconds| timing.reveal = seconds)}
+                                    {field("timing-results", |timing| timing.results.unwrap_or(0), |timing, seconds| {
+                                        timing.results = (seconds > 0).then_some(seconds);
+                                    })}
+                                }
+                            })}
+                        </div>
+                        <div>
+                            <label class="block text-sm font-medium mb-1">
+                                {t("board-theme")}
+                            </label>
+                            <select
+                                class="w-full px-4 py-2 rounded bg-slate-700 border border-slate-600 text-white"
+                                on:change=move |ev| {
+                                    let value = event_target_value(&ev);
+                                    if let Some(theme) = Theme::ALL.into_iter().find(|theme| theme.key() == value) {
+                                        board_theme.set(theme);
                                     }
                                 }
                             >
-                                <option 
-                                    value="lightning" 
-                                    selected=move || matches!(default_game_speed.get(), GameSpeed::Lightning)
-                                    class="text-white bg-slate-700"
-                                >
-                                    "Lightning! (1s to choose)"
-                                </option>
-                                <option 
-                                    value="quick"
-                                    selected=move || matches!(default_game_speed.get(), GameSpeed::Quick)
-                                    class="text-white bg-slate-700"
-                                >
-                                    "Quick! (5s to choose)"
-                                </option>
-                                <option 
-                                    value="relaxed"
-                                    selected=move || matches!(default_game_speed.get(), GameSpeed::Relaxed)
-                                    class="text-white bg-slate-700"
-                                >
-                                    "Relaxed (10s to choose)"
-                                </option>
-                                <option 
-                                    value="chill"
-                                    selected=move || matches!(default_game_speed.get(), GameSpeed::Chill)
-                                    class="text-white bg-slate-700"
-                                >
-                                    "Totally Chill (no limit)"
-                                </option>
+                                {Theme::ALL.into_iter().map(|theme| view! {
+                                    <option
+                                        value=theme.key()
+                                        selected=move || board_theme.get() == theme
+                                        class="text-white bg-slate-700"
+                                    >
+                                        {t(&format!("theme-{}", theme.key()))}
+                                    </option>
+                                }).collect_view()}
+                            </select>
+                        </div>
+                        <div>
+                            <label class="block text-sm font-medium mb-1">
+                                {t("language")}
+                            </label>
+                            <select
+                                class="w-full px-4 py-2 rounded bg-slate-700 border border-slate-600 text-white"
+                                on:change=move |ev| {
+                                    let value = event_target_value(&ev);
+                                    if let Some(locale) = Locale::ALL.into_iter().find(|locale| locale.code() == value) {
+                                        language.set(locale);
+                                    }
+                                }
+                            >
+                                {Locale::ALL.into_iter().map(|locale| view! {
+                                    <option
+                                        value=locale.code()
+                                        lang=locale.code()
+                                        selected=move || language.get() == locale
+                                        class="text-white bg-slate-700"
+                                    >
+                                        {locale.label()}
+                                    </option>
+                                }).collect_view()}
                             </select>
                         </div>
 
 
                         <div>
-                        <h3 class="text-xl font-bold mb-4">"Manage Opponents"</h3>
+                            <label class="block text-sm font-medium mb-1">
+                                {t("default-rules")}
+                            </label>
+                            <select
+                                class="w-full px-4 py-2 rounded bg-slate-700 border border-slate-600 text-white"
+                                on:change=move |ev| {
+                                    let value = event_target_value(&ev);
+                                    if let Some(variant) = Variant::ALL.into_iter().find(|v| v.key() == value) {
+                                        default_variant.set(variant);
+                                    }
+                                }
+                            >
+                                {Variant::ALL.into_iter().map(|variant| view! {
+                                    <option
+                                        value=variant.key()
+                                        selected=move || default_variant.get() == variant
+                                        title=t(&format!("variant-{}-description", variant.key()))
+                                        class="text-white bg-slate-700"
+                                    >
+                                        {t(&format!("variant-{}", variant.key()))}
+                                    </option>
+                                }).collect_view()}
+                            </select>
+                        </div>
+
+
+                        <div>
+                        <h3 class="text-xl font-bold mb-4">{t("manage-opponents")}</h3>
+                        {move || match opponent_to_edit.get() {
+                            Some(opponent) => view! {
+                                <OpponentEditor
+                                    opponent=opponent
+                                    default_variant=default_variant.get_untracked()
+                                    on_save=Callback::new(move |opponent: Opponent| {
+                                        let _ = update_opponent(opponent);
+                                        opponents_trigger.update(|v| *v = !*v);
+                                        opponent_to_edit.set(None);
+                                    })
+                                    on_cancel=move || opponent_to_edit.set(None)
+                                />
+                            }.into_any(),
+                            None => view! {
+                                <a
+                                    href="#"
+                                    class="text-blue-400 hover:text-blue-300 block mb-2"
+                                    on:click=move |ev| {
+                                        ev.prevent_default();
+                                        let created = web_sys::js_sys::Date::now() as u64;
+                                        opponent_to_edit.set(Some(Opponent::human(String::new(), created, &mut rand::thread_rng())));
+                                    }
+                                >
+                                    {t("add-opponent")}
+                                </a>
+                            }.into_any(),
+                        }}
                         <div class="flex flex-col gap-2 max-h-64 overflow-y-auto">
                             <For
                                 each=move || opponents.get()
@@ -462,21 +760,20 @@ fn App() -> impl IntoView {
                                     let opponent_id_stats = opponent.id.clone();
                                     let opponent_id_remove = opponent.id.clone();
                                     let opponent_type = opponent.opponent_type.clone();
-                                    let opponent_name = opponent.name.clone();
+                                    let profile = current_opponent(opponents, opponent);
                     
                                     view! {
                                         <div class="flex items-center justify-between p-2 bg-slate-800 rounded">
                                             <div class="flex items-center gap-2 text-gray-300">
-                                                <span class="w-4 h-4 rounded-full bg-blue-600 flex items-center justify-center text-xs">
-                                                    {if matches!(opponent_type, OpponentType::Computer) { "C" } else { "H" }}
-                                                </span>
-                                                {opponent_name}
+                                                {move || view! { <OpponentAvatar opponent=profile.get()/> }}
+                                                {move || profile.with(|opponent| opponent.name.clone())}
                                                 {move || {
                                                     if let Some(user_data) = load_user_data() {
                                                         if let Some(stats) = user_data.opponent_stats.get(&opponent_id_stats) {
                                                             view! {
                                                                 <span class="text-sm text-gray-500 ml-2">
                                                                     "(" {stats.wins} "-" {stats.losses} ")"
+                                                                    {(stats.forfeits > 0).then(|| format!(" {}", t_with("forfeited", &[("count", stats.forfeits.into())])))}
                                                                 </span>
                                                             }.into_any()
                                                         } else {
@@ -493,7 +790,12 @@ fn App() -> impl IntoView {
                                             </div>
                                             
                                             <div class="flex gap-2">
-                                            // Add some debugging and make the condition more explicit
+                                            <button
+                                                class="text-blue-400 hover:text-blue-300 text-sm"
+                                                on:click=move |_| opponent_to_edit.set(Some(profile.get_untracked()))
+                                            >
+                                                {t("edit")}
+                                            </button>
                                             {
                                                 let is_human = !matches!(opponent_type, OpponentType::Computer);
                                                 if is_human {
@@ -505,7 +807,7 @@ fn App() -> impl IntoView {
                                                                 opponents_trigger.update(|v| *v = !*v);
                                                             }
                                                         >
-                                                            "Remove"
+                                                            {t("remove")}
                                                         </button>
                                                     }.into_any()
                                                 } else {
@@ -526,18 +828,25 @@ fn App() -> impl IntoView {
                                 class="px-4 py-2 bg-gray-700 hover:bg-gray-600 rounded"
                                 on:click=move |_| set_show_profile.set(false)
                             >
-                                "Cancel"
+                                {t("cancel")}
                             </button>
                             <button
                                 class="px-4 py-2 bg-blue-600 hover:bg-blue-700 rounded"
                                 on:click=move |_| {
                                     let greeting_text = format!("Hello, {}!", name.get());
                                     set_greeting.set(greeting_text.clone());
-                                    let _ = save_user_data(&name.get(), &greeting_text, default_game_speed.get());
+                                    let _ = save_user_data(
+                                        &name.get(),
+                                        &greeting_text,
+                                        default_game_speed.get(),
+                                        board_theme.get(),
+                                        default_variant.get(),
+                                        language.get(),
+                                    );
                                     set_show_profile.set(false);
                                 }
                             >
-                                "Save Changes"
+                                {t("save-changes")}
                             </button>
                         </div>
                     </div>
@@ -0,0 +1,158 @@
+//! Plain-language descriptions of boards and round results, for alt text and
+//! screen-reader announcements.
+//!
+//! Steps are numbered from 1 like the thumbnails, and squares are named by
+//! their place on the board as drawn ("top-left", "middle-right"), falling
+//! back to rows and columns counted from the top-left on bigger boards. The
+//! wording comes from the `describe-`, `square-` and `round-` messages of the
+//! locale's bundle.
+
+use crate::engine::board::{Board, CellContent};
+use crate::engine::game_board::{GameBoard, RoundEvent, Side};
+use crate::engine::layout::Layout;
+use crate::i18n::{translate, Arg, Locale};
+
+/// The steps of a board as its creator sees it, goal at the top.
+pub fn describe_board(board: &Board, layout: Option<&Layout>, locale: Locale) -> String {
+    describe_sequence(board, layout, locale, false)
+}
+
+/// The steps of a board as the other player sees it, rotated 180° like the
+/// opponent thumbnails.
+pub fn describe_opponent_board(board: &Board, layout: Option<&Layout>, locale: Locale) -> String {
+    describe_sequence(board, layout, locale, true)
+}
+
+fn describe_sequence(board: &Board, layout: Option<&Layout>, locale: Locale, rotated: bool) -> String {
+    let size = board.size;
+    let t = |id: &str, args: &[(&str, Arg)]| translate(locale, id, args);
+    let square = |at: (usize, usize)| Arg::Text(square_name(locale, size, at));
+    let turn = |(row, col): (usize, usize)| if rotated { (size - 1 - row, size - 1 - col) } else { (row, col) };
+    let mut steps = Vec::new();
+    let mut piece: Option<(usize, usize)> = None;
+
+    for (index, &(row, col, ref content)) in board.sequence.iter().enumerate() {
+        let step = index + 1;
+        match (content, piece) {
+            (CellContent::Player, None) => {
+                steps.push(t("describe-start", &[("square", square(turn((row, col))))]));
+                piece = Some((row, col));
+            }
+            (CellContent::Player, Some(from)) => {
+                let to = turn((row, col));
+                let mut args = vec![
+                    ("direction", Arg::Text(direction(locale, turn(from), to))),
+                    ("square", square(to)),
+                    ("step", Arg::from(step)),
+                ];
+                piece = Some((row, col));
+                let id = match layout.and_then(|layout| layout.partner(row, col)) {
+                    Some(exit) => {
+                        args.push(("exit", square(turn(exit))));
+                        piece = Some(exit);
+                        "describe-teleport"
+                    }
+                    None => "describe-move",
+                };
+                steps.push(t(id, &args));
+            }
+            (CellContent::Trap, _) => {
+                steps.push(t("describe-trap", &[("square", square(turn((row, col)))), ("step", step.into())]));
+            }
+            (CellContent::Final, _) => steps.push(t("describe-goal", &[("step", step.into())])),
+            (CellContent::Empty, _) => {}
+        }
+    }
+
+    if steps.is_empty() {
+        return t("describe-empty", &[]);
+    }
+    format!("{}.", steps.join(", "))
+}
+
+/// What happened in a round, from the player's side, and its score.
+pub fn describe_round(game_board: &GameBoard, locale: Locale) -> String {
+    let size = game_board.size;
+    let at = |row: usize, col: usize| Arg::Text(square_name(locale, size, (row, col)));
+    let side_arg = |side: Side| Arg::from(match side {
+        Side::Player => "player",
+        Side::Opponent => "opponent",
+    });
+    let mut sentences = Vec::new();
+
+    for event in &game_board.events {
+        let (id, args) = match *event {
+            RoundEvent::GoalReached { side, step } => ("round-goal", vec![("side", side_arg(side)), ("step", (step + 1).into())]),
+            RoundEvent::TrapHit { side, step, row, col } => {
+                ("round-trap", vec![("side", side_arg(side)), ("square", at(row, col)), ("step", (step + 1).into())])
+            }
+            RoundEvent::NeutralTrapHit { side, step, row, col } => {
+                ("round-neutral-trap", vec![("side", side_arg(side)), ("square", at(row, col)), ("step", (step + 1).into())])
+            }
+            RoundEvent::Collision { step, row, col } => {
+                ("round-collision", vec![("square", at(row, col)), ("step", (step + 1).into())])
+            }
+            RoundEvent::Bounced { side, step, row, col, .. } => {
+                ("round-bounced", vec![("side", side_arg(side)), ("square", at(row, col)), ("step", (step + 1).into())])
+            }
+            RoundEvent::Teleported { side, step, row, col } => {
+                ("round-teleported", vec![("side", side_arg(side)), ("square", at(row, col)), ("step", (step + 1).into())])
+            }
+            _ => continue,
+        };
+        sentences.push(format!("{}.", translate(locale, id, &args)));
+    }
+
+    sentences.push(translate(
+        locale,
+        "round-score",
+        &[("player", game_board.player_score.into()), ("opponent", game_board.opponent_score.into())],
+    ));
+    sentences.join(" ")
+}
+
+/// The name of a square on a board of `size` as drawn.
+fn square_name(locale: Locale, size: usize, (row, col): (usize, usize)) -> String {
+    let vertical = match row {
+        0 => Some("top"),
+        r if r + 1 == size => Some("bottom"),
+        r if 2 * r + 1 == size => Some("middle"),
+        _ => None,
+    };
+    let horizontal = match col {
+        0 => Some("left"),
+        c if c + 1 == size => Some("right"),
+        c if 2 * c + 1 == size => Some("middle"),
+        _ => None,
+    };
+    match (vertical, horizontal) {
+        (Some(vertical), Some(horizontal)) => translate(locale, &format!("square-{}-{}", vertical, horizontal), &[]),
+        (Some(vertical), None) => translate(locale, "square-in-row", &[("row", vertical.into()), ("column", (col + 1).into())]),
+        (None, Some(horizontal)) => {
+            translate(locale, "square-in-column", &[("row", (row + 1).into()), ("column", horizontal.into())])
+        }
+        (None, None) => translate(locale, "square", &[("row", (row + 1).into()), ("column", (col + 1).into())]),
+    }
+}
+
+/// How the piece got from `from` to `to`: "move up", "jump left", "move
+/// up-right".
+fn direction(locale: Locale, from: (usize, usize), to: (usize, usize)) -> String {
+    let vertical = match to.0.cmp(&from.0) {
+        std::cmp::Ordering::Less => Some("up"),
+        std::cmp::Ordering::Greater => Some("down"),
+        std::cmp::Ordering::Equal => None,
+    };
+    let horizontal = match to.1.cmp(&from.1) {
+        std::cmp::Ordering::Less => Some("left"),
+        std::cmp::Ordering::Greater => Some("right"),
+        std::cmp::Ordering::Equal => None,
+    };
+    let verb = if from.0.abs_diff(to.0) > 1 || from.1.abs_diff(to.1) > 1 { "jump" } else { "move" };
+    let way = match (vertical, horizontal) {
+        (Some(vertical), Some(horizontal)) => format!("{}-{}", vertical, horizontal),
+        (Some(way), None) | (None, Some(way)) => way.to_string(),
+        (None, None) => "none".to_string(),
+    };
+    translate(locale, "describe-direction", &[("verb", verb.into()), ("way", way.into())])
+}
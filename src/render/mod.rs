@@ -0,0 +1,3 @@
+pub mod describe;
+pub mod svg;
+pub mod text;
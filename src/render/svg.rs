@@ -0,0 +1,575 @@
+//! SVG rendering shared by saved-board thumbnails, opponent thumbnails and the
+//! round result board.
+//!
+//! Everything is drawn into a 100×100 viewBox. The grid fills the 90×90 area
+//! inside a 5px margin and every piece, trap and label is scaled from the cell
+//! size, so larger boards render with the same proportions as 2×2 ones.
+
+use std::fmt::Write;
+
+use serde::{Serialize, Deserialize};
+
+use crate::engine::board::{Board, CellContent};
+use crate::engine::game_board::{GameBoard, Side};
+use crate::engine::layout::{Layout, Tile};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Theme {
+    #[default]
+    Dark,
+    Light,
+    ColorBlind,
+}
+
+impl Theme {
+    pub const ALL: [Theme; 3] = [Theme::Dark, Theme::Light, Theme::ColorBlind];
+
+    /// The value the theme is listed under in the settings.
+    pub fn key(&self) -> &'static str {
+        match self {
+            Theme::Dark => "dark",
+            Theme::Light => "light",
+            Theme::ColorBlind => "color-blind",
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Theme::Dark => "Dark",
+            Theme::Light => "Light",
+            Theme::ColorBlind => "Color-blind safe",
+        }
+    }
+
+    pub fn palette(&self) -> Palette {
+        match self {
+            Theme::Dark => Palette {
+                background: "rgb(30, 41, 59)",
+                cell: "rgb(51, 65, 85)",
+                player: "rgb(37, 99, 235)",
+                opponent: "rgb(147, 51, 234)",
+                player_trap: "rgb(220, 38, 38)",
+                opponent_trap: "rgb(249, 115, 22)",
+                piece_text: "white",
+                wall: "rgb(15, 23, 42)",
+                neutral_trap: "rgb(148, 163, 184)",
+                bonus: "rgb(234, 179, 8)",
+                teleporter: "rgb(20, 184, 166)",
+            },
+            Theme::Light => Palette {
+                background: "rgb(241, 245, 249)",
+                cell: "rgb(203, 213, 225)",
+                player: "rgb(37, 99, 235)",
+                opponent: "rgb(126, 34, 206)",
+                player_trap: "rgb(185, 28, 28)",
+                opponent_trap: "rgb(194, 65, 12)",
+                piece_text: "white",
+                wall: "rgb(71, 85, 105)",
+                neutral_trap: "rgb(100, 116, 139)",
+                bonus: "rgb(202, 138, 4)",
+                teleporter: "rgb(13, 148, 136)",
+            },
+            // Okabe-Ito colors, distinguishable with the common forms of color blindness
+            Theme::ColorBlind => Palette {
+                background: "rgb(30, 41, 59)",
+                cell: "rgb(51, 65, 85)",
+                player: "rgb(0, 114, 178)",
+                opponent: "rgb(230, 159, 0)",
+                player_trap: "rgb(213, 94, 0)",
+                opponent_trap: "rgb(204, 121, 167)",
+                piece_text: "white",
+                wall: "rgb(0, 0, 0)",
+                neutral_trap: "rgb(153, 153, 153)",
+                bonus: "rgb(240, 228, 66)",
+                teleporter: "rgb(86, 180, 233)",
+            },
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Palette {
+    pub background: &'static str,
+    pub cell: &'static str,
+    pub player: &'static str,
+    pub opponent: &'static str,
+    pub player_trap: &'static str,
+    pub opponent_trap: &'static str,
+    pub piece_text: &'static str,
+    pub wall: &'static str,
+    pub neutral_trap: &'static str,
+    pub bonus: &'static str,
+    pub teleporter: &'static str,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RenderOptions {
+    pub theme: Theme,
+    /// Whose view of a single board to draw. `Side::Opponent` rotates it 180°
+    /// and uses the opponent's colors. Round results are always drawn from the
+    /// player's side.
+    pub side: Side,
+    pub show_step_numbers: bool,
+    /// Leave out the arrow marking where the piece leaves the board.
+    pub hide_final_move: bool,
+    /// Draw step numbers and collision stars as paths instead of `<text>`, so
+    /// rasterizers without fonts still show them.
+    pub vector_labels: bool,
+}
+
+impl Default for RenderOptions {
+    fn default() -> Self {
+        RenderOptions {
+            theme: Theme::Dark,
+            side: Side::Player,
+            show_step_numbers: true,
+            hide_final_move: true,
+            vector_labels: false,
+        }
+    }
+}
+
+impl RenderOptions {
+    pub fn opponent() -> Self {
+        RenderOptions { side: Side::Opponent, ..RenderOptions::default() }
+    }
+
+    pub fn with_theme(mut self, theme: Theme) -> Self {
+        self.theme = theme;
+        self
+    }
+}
+
+/// Cell placement for a board of a given size.
+#[derive(Debug, Clone, Copy)]
+struct Geometry {
+    stride: f32,
+    cell: f32,
+}
+
+impl Geometry {
+    fn new(size: usize) -> Self {
+        let stride = 90.0 / size.max(1) as f32;
+        Geometry { stride, cell: stride * 8.0 / 9.0 }
+    }
+
+    fn origin(&self, row: usize, col: usize) -> (f32, f32) {
+        (col as f32 * self.stride, row as f32 * self.stride)
+    }
+
+    fn radius(&self) -> f32 {
+        self.cell * 0.375
+    }
+
+    fn font_size(&self) -> f32 {
+        self.cell * 0.4
+    }
+
+    fn trap_inset(&self) -> f32 {
+        self.cell / 8.0
+    }
+
+    fn trap_length(&self) -> f32 {
+        self.cell * 0.75
+    }
+
+    fn stroke(&self) -> f32 {
+        self.cell / 10.0
+    }
+}
+
+pub fn data_uri(svg: &str) -> String {
+    format!(r#"data:image/svg+xml,{}"#, urlencoding::encode(svg))
+}
+
+/// Seven-segment strokes in a 1×2 box, in the order a (top) through g
+/// (middle), for drawing digits without a font.
+const SEGMENTS: [((f32, f32), (f32, f32)); 7] = [
+    ((0.0, 0.0), (1.0, 0.0)),
+    ((1.0, 0.0), (1.0, 1.0)),
+    ((1.0, 1.0), (1.0, 2.0)),
+    ((0.0, 2.0), (1.0, 2.0)),
+    ((0.0, 1.0), (0.0, 2.0)),
+    ((0.0, 0.0), (0.0, 1.0)),
+    ((0.0, 1.0), (1.0, 1.0)),
+];
+
+/// Lit segments per digit, bit 0 being segment a.
+const DIGITS: [u8; 10] = [
+    0b0111111, 0b0000110, 0b1011011, 0b1001111, 0b1100110,
+    0b1101101, 0b1111101, 0b0000111, 0b1111111, 0b1101111,
+];
+
+struct Canvas {
+    svg: String,
+    geometry: Geometry,
+    vector_labels: bool,
+}
+
+impl Canvas {
+    fn new(size: usize, palette: &Palette, options: &RenderOptions) -> Self {
+        let geometry = Geometry::new(size);
+        let mut svg = format!(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 100 100"><rect width="100" height="100" fill="{}"/><g transform="translate(5,5)">"#,
+            palette.background
+        );
+        for row in 0..size {
+            for col in 0..size {
+                let (x, y) = geometry.origin(row, col);
+                let _ = write!(
+                    svg,
+                    r#"<rect x="{}" y="{}" width="{}" height="{}" fill="{}"/>"#,
+                    x, y, geometry.cell, geometry.cell, palette.cell
+                );
+            }
+        }
+        Canvas { svg, geometry, vector_labels: options.vector_labels }
+    }
+
+    fn finish(mut self) -> String {
+        self.svg.push_str("</g></svg>");
+        self.svg
+    }
+
+    /// The map's tiles, drawn over the empty cells and under everything else.
+    fn tiles(&mut self, layout: &Layout, palette: &Palette) {
+        let cell = self.geometry.cell;
+        for row in 0..layout.size() {
+            for col in 0..layout.size() {
+                let (x, y) = self.geometry.origin(row, col);
+                match layout.tile(row, col) {
+                    Tile::Open => {}
+                    Tile::Wall => {
+                        let _ = write!(
+                            self.svg,
+                            r#"<rect x="{}" y="{}" width="{}" height="{}" fill="{}"/>"#,
+                            x, y, cell, cell, palette.wall
+                        );
+                    }
+                    Tile::NeutralTrap => self.trap((x, y), palette.neutral_trap, None, 0.0, false),
+                    Tile::Bonus => {
+                        // A diamond in the corner, clear of the pieces
+                        let half = cell / 10.0;
+                        let (center_x, center_y) = (x + cell / 6.0, y + cell / 6.0);
+                        let _ = write!(
+                            self.svg,
+                            r#"<path d="M{} {} L{} {} L{} {} L{} {} Z" fill="{}"/>"#,
+                            center_x, center_y - half, center_x + half, center_y,
+                            center_x, center_y + half, center_x - half, center_y,
+                            palette.bonus
+                        );
+                    }
+                    Tile::Teleporter(letter) => {
+                        let _ = write!(
+                            self.svg,
+                            r#"<circle cx="{}" cy="{}" r="{}" stroke="{}" stroke-width="{}" fill="none"/>"#,
+                            x + cell / 2.0, y + cell / 2.0, cell * 0.45, palette.teleporter, self.geometry.stroke() / 2.0
+                        );
+                        // Vector labels only draw digits, so rasterized maps
+                        // leave the pair letters out
+                        if !self.vector_labels {
+                            let letter = letter.to_ascii_uppercase().to_string();
+                            self.label(x + cell - cell / 6.0, y + cell / 6.0, palette.teleporter, &letter);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Text centered on (x, y).
+    fn label(&mut self, x: f32, y: f32, fill: &str, text: &str) {
+        let font_size = self.geometry.font_size();
+        if !self.vector_labels {
+            let _ = write!(
+                self.svg,
+                r#"<text x="{}" y="{}" font-size="{}" fill="{}" text-anchor="middle" dy=".3em">{}</text>"#,
+                x, y, font_size, fill, text
+            );
+            return;
+        }
+
+        let height = font_size * 0.7;
+        let width = height / 2.0;
+        let gap = font_size * 0.25;
+        let digits: Vec<u8> = text.bytes().filter(u8::is_ascii_digit).map(|b| b - b'0').collect();
+        let total = digits.len() as f32 * (width + gap) - gap;
+        let top = y - height / 2.0;
+        let mut d = String::new();
+        for (i, digit) in digits.iter().enumerate() {
+            let left = x - total / 2.0 + i as f32 * (width + gap);
+            for (segment, &((x1, y1), (x2, y2))) in SEGMENTS.iter().enumerate() {
+                if DIGITS[*digit as usize] & (1 << segment) != 0 {
+                    let _ = write!(
+                        d,
+                        "M{} {} L{} {} ",
+                        left + x1 * width, top + y1 * width,
+                        left + x2 * width, top + y2 * width
+                    );
+                }
+            }
+        }
+        let _ = write!(
+            self.svg,
+            r#"<path d="{}" stroke="{}" stroke-width="{}" stroke-linecap="round" fill="none"/>"#,
+            d.trim_end(), fill, font_size / 8.0
+        );
+    }
+
+    /// A collision star whose baseline sits at y.
+    fn star(&mut self, x: f32, y: f32, fill: &str) {
+        let font_size = self.geometry.cell / 2.0;
+        if !self.vector_labels {
+            let _ = write!(
+                self.svg,
+                r#"<text x="{}" y="{}" font-size="{}" fill="{}" text-anchor="middle">*</text>"#,
+                x, y, font_size, fill
+            );
+            return;
+        }
+
+        let radius = font_size * 0.2;
+        let center_y = y - font_size * 0.5;
+        let mut d = String::new();
+        for angle in [90.0_f32, 30.0, 150.0] {
+            let (sin, cos) = angle.to_radians().sin_cos();
+            let _ = write!(
+                d,
+                "M{} {} L{} {} ",
+                x - radius * cos, center_y - radius * sin,
+                x + radius * cos, center_y + radius * sin
+            );
+        }
+        let _ = write!(
+            self.svg,
+            r#"<path d="{}" stroke="{}" stroke-width="{}" stroke-linecap="round" fill="none"/>"#,
+            d.trim_end(), fill, font_size / 10.0
+        );
+    }
+
+    fn piece(&mut self, (x, y): (f32, f32), fill: &str, label: Option<usize>, text: &str) {
+        let center = self.geometry.cell / 2.0;
+        let _ = write!(
+            self.svg,
+            r#"<circle cx="{}" cy="{}" r="{}" fill="{}"/>"#,
+            x + center, y + center, self.geometry.radius(), fill
+        );
+        if let Some(step) = label {
+            self.label(x + center, y + center, text, &(step + 1).to_string());
+        }
+    }
+
+    /// Both pieces on one square: the player on the left half, the opponent
+    /// on the right.
+    fn split_piece(&mut self, (x, y): (f32, f32), palette: &Palette, labels: Option<(usize, usize)>) {
+        let center_x = x + self.geometry.cell / 2.0;
+        let center_y = y + self.geometry.cell / 2.0;
+        let radius = self.geometry.radius();
+
+        let _ = write!(
+            self.svg,
+            r#"<path d="M {},{} a {},{} 0 0 1 {},0 v {} a {},{} 0 0 1 -{},0" fill="{}"/>"#,
+            center_x - radius, center_y - radius,
+            radius, radius, radius, radius * 2.0, radius, radius, radius,
+            palette.player
+        );
+        let _ = write!(
+            self.svg,
+            r#"<path d="M {},{} a {},{} 0 0 0 -{},0 v {} a {},{} 0 0 0 {},0" fill="{}"/>"#,
+            center_x + radius, center_y - radius,
+            radius, radius, radius, radius * 2.0, radius, radius, radius,
+            palette.opponent
+        );
+        if let Some((player_step, opponent_step)) = labels {
+            self.label(center_x - radius / 2.0, center_y, palette.piece_text, &(player_step + 1).to_string());
+            self.label(center_x + radius / 2.0, center_y, palette.piece_text, &(opponent_step + 1).to_string());
+        }
+    }
+
+    /// An X across the cell; `tilt` rotates it a little so two overlapping
+    /// traps stay visible. The step label goes to the right of the cell unless
+    /// `label_left` is set.
+    fn trap(&mut self, (x, y): (f32, f32), stroke: &str, label: Option<usize>, tilt: f32, label_left: bool) {
+        let inset = self.geometry.trap_inset();
+        let length = self.geometry.trap_length();
+        let _ = write!(
+            self.svg,
+            r#"<g transform="translate({} {}) rotate({} {} {})"><path d="M0 0 l{} {} m0 -{} l-{} {}" stroke="{}" stroke-width="{}" opacity="0.6"/></g>"#,
+            x + inset, y + inset, tilt, length / 2.0, length / 2.0,
+            length, length, length, length, length,
+            stroke, self.geometry.stroke()
+        );
+        if let Some(step) = label {
+            let label_x = if label_left { x + inset } else { x + self.geometry.cell - inset };
+            self.label(label_x, y + self.geometry.cell / 2.0, stroke, &(step + 1).to_string());
+        }
+    }
+
+    /// A line between the centers of two cells, for steps that do not go to a
+    /// neighboring cell.
+    fn connector(&mut self, (from_x, from_y): (f32, f32), (to_x, to_y): (f32, f32), stroke: &str) {
+        let center = self.geometry.cell / 2.0;
+        let _ = write!(
+            self.svg,
+            r#"<path d="M{} {} L{} {}" stroke="{}" stroke-width="{}" stroke-linecap="round" opacity="0.6"/>"#,
+            from_x + center, from_y + center, to_x + center, to_y + center,
+            stroke, self.geometry.stroke()
+        );
+    }
+
+    fn exit_arrow(&mut self, col: usize, at_bottom: bool, size: usize, fill: &str) {
+        let (x, _) = self.geometry.origin(0, col);
+        let center = x + self.geometry.cell / 2.0;
+        let half = self.geometry.cell / 5.0;
+        let (base, tip) = if at_bottom {
+            let edge = (size - 1) as f32 * self.geometry.stride + self.geometry.cell;
+            (edge, edge + 4.0)
+        } else {
+            (0.0, -4.0)
+        };
+        let _ = write!(
+            self.svg,
+            r#"<path d="M{} {} L{} {} L{} {} Z" fill="{}"/>"#,
+            center - half, base, center, tip, center + half, base, fill
+        );
+    }
+}
+
+/// A single board's sequence, as a thumbnail.
+pub fn board_svg(board: &Board, options: &RenderOptions) -> String {
+    board_svg_on(board, None, options)
+}
+
+/// A single board's sequence drawn over the map it was built on.
+pub fn board_svg_on(board: &Board, layout: Option<&Layout>, options: &RenderOptions) -> String {
+    let palette = options.theme.palette();
+    let size = board.grid.len();
+    let rotated = options.side == Side::Opponent;
+    let (piece_fill, trap_stroke) = if rotated {
+        (palette.opponent, palette.opponent_trap)
+    } else {
+        (palette.player, palette.player_trap)
+    };
+
+    let mut canvas = Canvas::new(size, &palette, options);
+    // Layouts look the same from both sides, so they are never rotated
+    if let Some(layout) = layout.filter(|layout| layout.size() == size) {
+        canvas.tiles(layout, &palette);
+    }
+    let orient = |i: usize, j: usize| if rotated { (size - 1 - i, size - 1 - j) } else { (i, j) };
+
+    // Diagonal steps and jumps get a line from the square they left, drawn
+    // underneath the pieces
+    let moves = board.sequence.iter().filter(|&&(i, j, ref content)| {
+        *content == CellContent::Player && i < size && j < size
+    });
+    for (&(from_row, from_col, _), &(to_row, to_col, _)) in moves.clone().zip(moves.skip(1)) {
+        if from_row.abs_diff(to_row) + from_col.abs_diff(to_col) > 1 {
+            let geometry = canvas.geometry;
+            let origin = |(row, col): (usize, usize)| geometry.origin(row, col);
+            canvas.connector(origin(orient(from_row, from_col)), origin(orient(to_row, to_col)), piece_fill);
+        }
+    }
+
+    for (idx, &(i, j, ref content)) in board.sequence.iter().enumerate() {
+        if i >= size || j >= size {
+            continue;
+        }
+        let (row, col) = orient(i, j);
+        let origin = canvas.geometry.origin(row, col);
+        let label = options.show_step_numbers.then_some(idx);
+
+        match content {
+            CellContent::Player => canvas.piece(origin, piece_fill, label, palette.piece_text),
+            CellContent::Trap => canvas.trap(origin, trap_stroke, label, 0.0, false),
+            CellContent::Final if !options.hide_final_move => canvas.exit_arrow(col, rotated, size, piece_fill),
+            _ => {}
+        }
+    }
+    canvas.finish()
+}
+
+/// The resolved round: both sides' visits and traps up to their collision
+/// step, collision markers, and split circles where both pieces stood.
+pub fn round_svg(game_board: &GameBoard, options: &RenderOptions) -> String {
+    let palette = options.theme.palette();
+    let size = game_board.size;
+    let player_limit = game_board.player_collision_step.unwrap_or(usize::MAX);
+    let opponent_limit = game_board.opponent_collision_step.unwrap_or(usize::MAX);
+    let mut canvas = Canvas::new(size, &palette, options);
+    if let Some(layout) = game_board.layout.as_ref().filter(|layout| layout.size() == size) {
+        canvas.tiles(layout, &palette);
+    }
+    let label = |step: usize| options.show_step_numbers.then_some(step);
+
+    // Collision markers go underneath the pieces
+    for row in 0..size {
+        for col in 0..size {
+            let Some(square) = game_board.square(row, col) else { continue };
+            if square.collision_step.is_none() {
+                continue;
+            }
+            let (x, y) = canvas.geometry.origin(row, col);
+            let cell = canvas.geometry.cell;
+            let half = cell / 2.0;
+            canvas.star(x + half, y, palette.player_trap);
+            canvas.star(x + half, y + cell, palette.opponent_trap);
+            canvas.star(x, y + half, palette.player_trap);
+            canvas.star(x + cell, y + half, palette.opponent_trap);
+        }
+    }
+
+    for row in 0..size {
+        for col in 0..size {
+            let Some(square) = game_board.square(row, col) else { continue };
+            let origin = canvas.geometry.origin(row, col);
+
+            let player_step = square.player_visits.iter().copied().filter(|&s| s <= player_limit).max();
+            let opponent_step = square.opponent_visits.iter().copied().filter(|&s| s <= opponent_limit).max();
+            match (player_step, opponent_step) {
+                (Some(p_step), Some(o_step)) => {
+                    let labels = options.show_step_numbers.then_some((p_step, o_step));
+                    canvas.split_piece(origin, &palette, labels);
+                }
+                (Some(step), None) => canvas.piece(origin, palette.player, label(step), palette.piece_text),
+                (None, Some(step)) => canvas.piece(origin, palette.opponent, label(step), palette.piece_text),
+                (None, None) => {}
+            }
+
+            let player_trap = square.player_trap_step.filter(|&s| s <= player_limit);
+            let opponent_trap = square.opponent_trap_step.filter(|&s| s <= opponent_limit);
+            match (player_trap, opponent_trap) {
+                (Some(p_trap), Some(o_trap)) => {
+                    canvas.trap(origin, palette.player_trap, label(p_trap), 3.0, true);
+                    canvas.trap(origin, palette.opponent_trap, label(o_trap), -3.0, false);
+                }
+                (Some(step), None) => canvas.trap(origin, palette.player_trap, label(step), 0.0, false),
+                (None, Some(step)) => canvas.trap(origin, palette.opponent_trap, label(step), 0.0, false),
+                (None, None) => {}
+            }
+        }
+    }
+
+    // The exit arrow for whoever reached the goal
+    if !options.hide_final_move {
+        for (sequence, side) in [(&game_board.player_sequence, Side::Player), (&game_board.opponent_sequence, Side::Opponent)] {
+            let reached = match side {
+                Side::Player => game_board.player_goal_reached,
+                Side::Opponent => game_board.opponent_goal_reached,
+            };
+            let exit = sequence.iter().find(|(_, _, content)| *content == CellContent::Final);
+            if let (true, Some(&(_, col, _))) = (reached, exit) {
+                if col >= size {
+                    continue;
+                }
+                let (col, at_bottom, fill) = match side {
+                    Side::Player => (col, false, palette.player),
+                    Side::Opponent => (size - 1 - col, true, palette.opponent),
+                };
+                canvas.exit_arrow(col, at_bottom, size, fill);
+            }
+        }
+    }
+
+    canvas.finish()
+}
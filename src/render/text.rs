@@ -0,0 +1,137 @@
+//! Plain-text rendering of boards and round results for terminals, test
+//! snapshots and bug reports.
+//!
+//! Each cell lists what happened there, using 1-based step numbers like the
+//! SVG thumbnails: `P3` the player's piece, `O2` the opponent's piece, `x2` a
+//! player trap, `o1` an opponent trap, `!` a trap that was hit and `*` a
+//! collision. Rounds played on a map also show its tiles: `#` a wall, `^` a
+//! neutral trap, `+` a bonus square and `@A` one end of teleporter `a`.
+
+use crate::engine::board::{Board, CellContent};
+use crate::engine::game_board::GameBoard;
+use crate::engine::layout::Tile;
+
+pub const LEGEND: &str = "P/O piece, x/o trap (player/opponent), ! trap hit, * collision";
+
+pub const LAYOUT_LEGEND: &str = "# wall, ^ neutral trap, + bonus square, @ teleporter";
+
+/// A board as its creator sees it, goal at the top.
+pub fn render_board(board: &Board) -> String {
+    render_sequence(board, false)
+}
+
+/// A board as the other player sees it: rotated 180° and drawn with the
+/// opponent's markers, like the opponent thumbnails.
+pub fn render_opponent_board(board: &Board) -> String {
+    render_sequence(board, true)
+}
+
+fn render_sequence(board: &Board, rotated: bool) -> String {
+    let size = board.size;
+    let (piece, trap) = if rotated { ('O', 'o') } else { ('P', 'x') };
+
+    let mut pieces: Vec<Vec<Option<usize>>> = vec![vec![None; size]; size];
+    let mut traps: Vec<Vec<Option<usize>>> = vec![vec![None; size]; size];
+    for (idx, &(row, col, ref content)) in board.sequence.iter().enumerate() {
+        if row >= size || col >= size {
+            continue;
+        }
+        let (row, col) = if rotated { (size - 1 - row, size - 1 - col) } else { (row, col) };
+        match content {
+            CellContent::Player => pieces[row][col] = Some(idx),
+            CellContent::Trap => traps[row][col] = Some(idx),
+            _ => {}
+        }
+    }
+
+    let cells: Vec<Vec<String>> = pieces
+        .iter()
+        .zip(&traps)
+        .map(|(piece_row, trap_row)| {
+            piece_row
+                .iter()
+                .zip(trap_row)
+                .map(|(piece_step, trap_step)| {
+                    let mut tokens = Vec::new();
+                    if let Some(step) = piece_step {
+                        tokens.push(format!("{}{}", piece, step + 1));
+                    }
+                    if let Some(step) = trap_step {
+                        tokens.push(format!("{}{}", trap, step + 1));
+                    }
+                    tokens.join(" ")
+                })
+                .collect()
+        })
+        .collect();
+    grid(&cells)
+}
+
+/// The resolved round, drawn the way `svg::round_svg` draws it: only steps
+/// up to each side's collision are shown.
+pub fn render_round(game_board: &GameBoard) -> String {
+    let size = game_board.size;
+    let player_limit = game_board.player_collision_step.unwrap_or(usize::MAX);
+    let opponent_limit = game_board.opponent_collision_step.unwrap_or(usize::MAX);
+    let mut cells = vec![vec![String::new(); size]; size];
+
+    for (row, cells_row) in cells.iter_mut().enumerate() {
+        for (col, cell) in cells_row.iter_mut().enumerate() {
+            let Some(square) = game_board.square(row, col) else {
+                continue;
+            };
+            let mut tokens = Vec::new();
+
+            match game_board.layout.as_ref().map_or(Tile::Open, |layout| layout.tile(row, col)) {
+                Tile::Open => {}
+                Tile::Wall => tokens.push("#".to_string()),
+                Tile::NeutralTrap => tokens.push("^".to_string()),
+                Tile::Bonus => tokens.push("+".to_string()),
+                Tile::Teleporter(letter) => tokens.push(format!("@{}", letter.to_ascii_uppercase())),
+            }
+            if let Some(step) = square.player_visits.iter().filter(|&&s| s <= player_limit).max() {
+                tokens.push(format!("P{}", step + 1));
+            }
+            if let Some(step) = square.opponent_visits.iter().filter(|&&s| s <= opponent_limit).max() {
+                tokens.push(format!("O{}", step + 1));
+            }
+            if let Some(step) = square.player_trap_step.filter(|&s| s <= player_limit) {
+                tokens.push(format!("x{}", step + 1));
+            }
+            if let Some(step) = square.opponent_trap_step.filter(|&s| s <= opponent_limit) {
+                tokens.push(format!("o{}", step + 1));
+            }
+            if square.player_trap_hit_step.is_some() || square.opponent_trap_hit_step.is_some() {
+                tokens.push("!".to_string());
+            }
+            if square.collision_step.is_some() {
+                tokens.push("*".to_string());
+            }
+            *cell = tokens.join(" ");
+        }
+    }
+    grid(&cells)
+}
+
+fn grid(cells: &[Vec<String>]) -> String {
+    let width = cells
+        .iter()
+        .flatten()
+        .map(|cell| cell.chars().count())
+        .max()
+        .unwrap_or(0)
+        .max(3);
+    let columns = cells.first().map_or(0, Vec::len);
+    let border = format!("+{}\n", format!("{}+", "-".repeat(width + 2)).repeat(columns));
+
+    let mut out = border.clone();
+    for row in cells {
+        out.push('|');
+        for cell in row {
+            out.push_str(&format!(" {:^width$} |", cell, width = width));
+        }
+        out.push('\n');
+        out.push_str(&border);
+    }
+    out
+}
@@ -0,0 +1,182 @@
+//! Fixture loading and golden-file snapshots shared by the integration tests.
+//!
+//! Snapshots live in `tests/snapshots`. Run with `UPDATE_SNAPSHOTS=1` to write
+//! new or changed snapshots instead of comparing against them.
+
+#![allow(dead_code)]
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+use spaces_game::engine::board::Board;
+use spaces_game::engine::game_board::{GameBoard, RoundEvent, Side, Square};
+use spaces_game::engine::layout::Layout;
+use spaces_game::engine::rules::Ruleset;
+use spaces_game::render::text;
+
+pub mod strategies;
+
+#[derive(Deserialize)]
+pub struct Fixture {
+    /// The map the boards were built on, if any.
+    #[serde(default)]
+    pub layout: Option<Layout>,
+    pub player: Board,
+    pub opponent: Board,
+}
+
+fn tests_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests")
+}
+
+pub fn load_fixture(name: &str) -> Fixture {
+    let path = tests_dir().join("fixtures").join(format!("{}.json", name));
+    let data = fs::read_to_string(&path)
+        .unwrap_or_else(|e| panic!("could not read {}: {}", path.display(), e));
+    serde_json::from_str(&data)
+        .unwrap_or_else(|e| panic!("could not parse {}: {}", path.display(), e))
+}
+
+pub fn resolve(fixture: &Fixture) -> GameBoard {
+    match &fixture.layout {
+        Some(layout) => resolve_on(&fixture.player, &fixture.opponent, Ruleset::default(), layout),
+        None => resolve_boards(&fixture.player, &fixture.opponent, Ruleset::default()),
+    }
+}
+
+pub fn resolve_boards(player: &Board, opponent: &Board, ruleset: Ruleset) -> GameBoard {
+    let mut game_board = GameBoard::with_ruleset(player.size, ruleset);
+    game_board.process_turn(player, opponent);
+    game_board
+}
+
+pub fn resolve_on(player: &Board, opponent: &Board, ruleset: Ruleset, layout: &Layout) -> GameBoard {
+    let mut game_board = GameBoard::with_layout(ruleset, layout.clone());
+    game_board.process_turn(player, opponent);
+    game_board
+}
+
+/// The event as the other side would have logged it: sides swapped and
+/// positions rotated 180°.
+fn mirrored(event: &RoundEvent, size: usize) -> RoundEvent {
+    let swap = |side: Side| match side {
+        Side::Player => Side::Opponent,
+        Side::Opponent => Side::Player,
+    };
+    let (flip_row, flip_col) = (|row: usize| size - 1 - row, |col: usize| size - 1 - col);
+    match *event {
+        RoundEvent::Moved { side, step, row, col } => {
+            RoundEvent::Moved { side: swap(side), step, row: flip_row(row), col: flip_col(col) }
+        }
+        RoundEvent::Teleported { side, step, row, col } => {
+            RoundEvent::Teleported { side: swap(side), step, row: flip_row(row), col: flip_col(col) }
+        }
+        RoundEvent::TrapPlaced { side, step, row, col } => {
+            RoundEvent::TrapPlaced { side: swap(side), step, row: flip_row(row), col: flip_col(col) }
+        }
+        RoundEvent::GoalReached { side, step } => RoundEvent::GoalReached { side: swap(side), step },
+        RoundEvent::ForwardPoint { side, step, score } => RoundEvent::ForwardPoint { side: swap(side), step, score },
+        RoundEvent::SidewaysPoint { side, step, score } => RoundEvent::SidewaysPoint { side: swap(side), step, score },
+        RoundEvent::GoalPoint { side, step, score } => RoundEvent::GoalPoint { side: swap(side), step, score },
+        RoundEvent::BonusPoint { side, step, score } => RoundEvent::BonusPoint { side: swap(side), step, score },
+        RoundEvent::Collision { step, row, col } => {
+            RoundEvent::Collision { step, row: flip_row(row), col: flip_col(col) }
+        }
+        RoundEvent::CollisionPenalty { side, step, score } => {
+            RoundEvent::CollisionPenalty { side: swap(side), step, score }
+        }
+        RoundEvent::Bounced { side, step, row, col, score } => {
+            RoundEvent::Bounced { side: swap(side), step, row: flip_row(row), col: flip_col(col), score }
+        }
+        RoundEvent::TrapHit { side, step, row, col } => {
+            RoundEvent::TrapHit { side: swap(side), step, row: flip_row(row), col: flip_col(col) }
+        }
+        RoundEvent::NeutralTrapHit { side, step, row, col } => {
+            RoundEvent::NeutralTrapHit { side: swap(side), step, row: flip_row(row), col: flip_col(col) }
+        }
+        RoundEvent::TrapPenalty { side, step, score } => RoundEvent::TrapPenalty { side: swap(side), step, score },
+        RoundEvent::RoundEnded { step } => RoundEvent::RoundEnded { step },
+    }
+}
+
+/// Events as a sorted list: the order within a step carries no meaning.
+fn event_set(events: &[RoundEvent]) -> Vec<String> {
+    let mut keys: Vec<String> = events.iter().map(|e| format!("{:03} {:?}", e.step(), e)).collect();
+    keys.sort();
+    keys
+}
+
+/// A square's contents, with player and opponent fields exchanged when
+/// `swap` is set.
+fn square_key(square: &Square, swap: bool) -> String {
+    let sides = [
+        (square.player_trap_step, &square.player_visits, square.player_trap_hit_step),
+        (square.opponent_trap_step, &square.opponent_visits, square.opponent_trap_hit_step),
+    ];
+    let (first, second) = if swap { (sides[1], sides[0]) } else { (sides[0], sides[1]) };
+    format!("{:?} {:?} {:?}", first, second, square.collision_step)
+}
+
+/// Where `swapped` (the same boards resolved with sides exchanged) fails to
+/// mirror `forward`, if anywhere.
+pub fn mirror_mismatch(forward: &GameBoard, swapped: &GameBoard) -> Option<String> {
+    let scores = (forward.player_score, forward.opponent_score);
+    if scores != (swapped.opponent_score, swapped.player_score) {
+        return Some(format!("scores {:?} vs swapped {:?}", scores, (swapped.player_score, swapped.opponent_score)));
+    }
+    let goals = (forward.player_goal_reached, forward.opponent_goal_reached);
+    if goals != (swapped.opponent_goal_reached, swapped.player_goal_reached) {
+        return Some(format!("goals {:?} vs swapped {:?}", goals, (swapped.player_goal_reached, swapped.opponent_goal_reached)));
+    }
+    let size = forward.size;
+    let mirrored_events: Vec<RoundEvent> = swapped.events.iter().map(|e| mirrored(e, size)).collect();
+    if event_set(&forward.events) != event_set(&mirrored_events) {
+        return Some(format!("events {:#?} vs mirrored {:#?}", forward.events, mirrored_events));
+    }
+    for row in 0..size {
+        for col in 0..size {
+            let (Some(square), Some(other)) = (forward.square(row, col), swapped.square(size - 1 - row, size - 1 - col)) else {
+                return Some(format!("square ({}, {}) missing", row, col));
+            };
+            if square_key(square, false) != square_key(other, true) {
+                return Some(format!("square ({}, {}): {:?} vs mirrored {:?}", row, col, square, other));
+            }
+        }
+    }
+    None
+}
+
+/// Event log, final board and scores: everything a round snapshot checks.
+pub fn round_report(game_board: &GameBoard) -> String {
+    let mut report = String::new();
+    for event in &game_board.events {
+        report.push_str(&event.to_string());
+        report.push('\n');
+    }
+    report.push('\n');
+    report.push_str(&text::render_round(game_board));
+    report.push_str(&format!(
+        "\nPlayer: {}  Opponent: {}\n",
+        game_board.player_score, game_board.opponent_score
+    ));
+    report
+}
+
+pub fn assert_snapshot(name: &str, actual: &str) {
+    let path = tests_dir().join("snapshots").join(name);
+    if env::var_os("UPDATE_SNAPSHOTS").is_some() {
+        fs::write(&path, actual).unwrap_or_else(|e| panic!("could not write {}: {}", path.display(), e));
+        return;
+    }
+
+    let expected = fs::read_to_string(&path).unwrap_or_else(|_| {
+        panic!("missing snapshot {} (run with UPDATE_SNAPSHOTS=1 to create it)", path.display())
+    });
+    assert!(
+        expected == actual,
+        "snapshot {} changed (run with UPDATE_SNAPSHOTS=1 to accept)\n--- expected\n{}\n--- actual\n{}",
+        name, expected, actual
+    );
+}
@@ -0,0 +1,91 @@
+//! proptest strategies for legal boards of any size and movement.
+
+use proptest::prelude::*;
+use spaces_game::engine::board::{Board, CellContent};
+use spaces_game::engine::movement::{Movement, MovementPreset};
+
+/// One choice per step: which legal target to use and whether to move there
+/// or trap it.
+type Choice = (usize, bool);
+
+/// Replays `choices` from the starting column the same way `BoardCreator`
+/// builds boards, then walks straight up to finish. `None` when the piece
+/// boxes itself in before reaching the top row.
+fn build_board(size: usize, movement: &Movement, start_col: usize, choices: &[Choice]) -> Option<Board> {
+    let mut board = Board::new(size);
+    let mut position = (size - 1, start_col);
+    board.grid[position.0][position.1] = CellContent::Player;
+    board.sequence.push((position.0, position.1, CellContent::Player));
+
+    let empty = |board: &Board, targets: Vec<(usize, usize)>| -> Vec<(usize, usize)> {
+        targets.into_iter().filter(|&(row, col)| board.grid[row][col] == CellContent::Empty).collect()
+    };
+
+    let step = |board: &mut Board, position: &mut (usize, usize), target: (usize, usize), trap: bool| {
+        if trap {
+            board.grid[target.0][target.1] = CellContent::Trap;
+            board.sequence.push((target.0, target.1, CellContent::Trap));
+        } else {
+            board.grid[position.0][position.1] = CellContent::Empty;
+            board.grid[target.0][target.1] = CellContent::Player;
+            board.sequence.push((target.0, target.1, CellContent::Player));
+            *position = target;
+        }
+    };
+
+    for &(pick, trap) in choices {
+        let moves = empty(&board, movement.move_targets(size, position));
+        let traps = empty(&board, movement.trap_targets(size, position));
+        let (options, trap) = match (trap, moves.is_empty(), traps.is_empty()) {
+            (_, true, true) => break,
+            (true, _, false) | (false, true, false) => (traps, true),
+            _ => (moves, false),
+        };
+        let target = options[pick % options.len()];
+        step(&mut board, &mut position, target, trap);
+    }
+
+    while position.0 > 0 {
+        let up = (position.0 - 1, position.1);
+        if board.grid[up.0][up.1] != CellContent::Empty {
+            return None;
+        }
+        step(&mut board, &mut position, up, false);
+    }
+
+    board.grid[position.0][position.1] = CellContent::Empty;
+    board.sequence.push((0, position.1, CellContent::Final));
+    Some(board)
+}
+
+pub fn legal_board(size: usize, movement: Movement) -> impl Strategy<Value = Board> {
+    (0..size, prop::collection::vec((any::<usize>(), any::<bool>()), 0..size * size * 2))
+        .prop_filter_map("piece boxed itself in", move |(start_col, choices)| {
+            build_board(size, &movement, start_col, &choices)
+        })
+}
+
+/// A movement preset and two boards legal under it, of the same size between
+/// 1×1 and 5×5.
+pub fn legal_board_pair() -> impl Strategy<Value = (Movement, Board, Board)> {
+    (prop::sample::select(MovementPreset::ALL.to_vec()), 1usize..=5).prop_flat_map(|(preset, size)| {
+        let movement = preset.movement();
+        (Just(movement), legal_board(size, movement), legal_board(size, movement))
+    })
+}
+
+/// Boards that need not be legal or even well formed: any size, any
+/// coordinates, any contents.
+pub fn arbitrary_board() -> impl Strategy<Value = Board> {
+    let content = prop_oneof![
+        Just(CellContent::Empty),
+        Just(CellContent::Player),
+        Just(CellContent::Trap),
+        Just(CellContent::Final),
+    ];
+    (0usize..6, prop::collection::vec((0usize..8, 0usize..8, content), 0..20)).prop_map(|(size, sequence)| Board {
+        grid: vec![vec![CellContent::Empty; size]; size],
+        size,
+        sequence,
+    })
+}
@@ -0,0 +1,162 @@
+//! Checks the resolver against the rule specification in
+//! `spaces_game::engine::rules` over every small board pair.
+
+mod common;
+
+use common::{load_fixture, mirror_mismatch, resolve_boards, resolve_on};
+use spaces_game::engine::board::Board;
+use spaces_game::engine::enumerate::{enumerate_boards, enumerate_boards_on};
+use spaces_game::engine::game_board::{GameBoard, RoundEvent};
+use spaces_game::engine::layout::LayoutPreset;
+use spaces_game::engine::movement::{Movement, MovementPreset};
+use spaces_game::engine::rules::{Resolution, Ruleset, Variant};
+
+const FIXTURES: [&str; 9] = [
+    "collision_same_square",
+    "both_trapped",
+    "goal_vs_trap_same_step",
+    "trap_after_opponent_arrives",
+    "both_reach_goal",
+    "collision_after_goal",
+    "trap_expires",
+    "collision_bounce",
+    "sideways_move",
+];
+
+fn assert_mirrored(player: &Board, opponent: &Board, ruleset: Ruleset) {
+    let forward = resolve_boards(player, opponent, ruleset);
+    let swapped = resolve_boards(opponent, player, ruleset);
+    if let Some(mismatch) = mirror_mismatch(&forward, &swapped) {
+        panic!(
+            "{:?} rules are not symmetric for\n{:?}\nvs\n{:?}\n{}",
+            ruleset.resolution, player.sequence, opponent.sequence, mismatch
+        );
+    }
+}
+
+fn all_pairs(boards: &[Board], mut check: impl FnMut(&Board, &Board)) {
+    for player in boards {
+        for opponent in boards {
+            check(player, opponent);
+        }
+    }
+}
+
+#[test]
+fn fixtures_mirror_under_every_ruleset() {
+    for name in FIXTURES {
+        let fixture = load_fixture(name);
+        for resolution in Resolution::ALL {
+            for variant in Variant::ALL {
+                assert_mirrored(&fixture.player, &fixture.opponent, Ruleset { resolution, ..variant.ruleset() });
+            }
+        }
+    }
+}
+
+#[test]
+fn every_2x2_pair_mirrors_under_both_resolutions() {
+    let boards = enumerate_boards(2, 5, &Movement::default());
+    for resolution in Resolution::ALL {
+        all_pairs(&boards, |player, opponent| assert_mirrored(player, opponent, Ruleset { resolution, ..Ruleset::default() }));
+    }
+}
+
+#[test]
+fn every_2x2_pair_mirrors_under_every_variant() {
+    let boards = enumerate_boards(2, 5, &Movement::default());
+    for variant in Variant::ALL {
+        all_pairs(&boards, |player, opponent| assert_mirrored(player, opponent, variant.ruleset()));
+    }
+}
+
+#[test]
+fn every_2x2_pair_mirrors_under_every_movement() {
+    for preset in MovementPreset::ALL {
+        let boards = enumerate_boards(2, 5, &preset.movement());
+        let ruleset = Ruleset { movement: preset.movement(), ..Ruleset::default() };
+        all_pairs(&boards, |player, opponent| assert_mirrored(player, opponent, ruleset));
+    }
+}
+
+#[test]
+fn short_3x3_pairs_mirror_under_both_resolutions() {
+    let boards = enumerate_boards(3, 5, &Movement::default());
+    for resolution in Resolution::ALL {
+        all_pairs(&boards, |player, opponent| assert_mirrored(player, opponent, Ruleset { resolution, ..Ruleset::default() }));
+    }
+}
+
+#[test]
+fn short_pairs_mirror_on_every_map() {
+    let (mut teleports, mut bonuses, mut neutral_traps) = (0, 0, 0);
+    for layout in LayoutPreset::ALL.iter().filter_map(LayoutPreset::layout) {
+        let boards = enumerate_boards_on(&layout, 5, &Movement::default());
+        assert!(!boards.is_empty(), "no boards on {}", layout.name());
+        for board in &boards {
+            assert_eq!(board.validate_on(&Movement::default(), &layout), Ok(()), "{:?}", board.sequence);
+        }
+        for variant in [Variant::Classic, Variant::Bumpers] {
+            all_pairs(&boards, |player, opponent| {
+                let forward = resolve_on(player, opponent, variant.ruleset(), &layout);
+                let swapped = resolve_on(opponent, player, variant.ruleset(), &layout);
+                if let Some(mismatch) = mirror_mismatch(&forward, &swapped) {
+                    panic!(
+                        "{} on {} is not symmetric for\n{:?}\nvs\n{:?}\n{}",
+                        variant.label(), layout.name(), player.sequence, opponent.sequence, mismatch
+                    );
+                }
+                for event in &forward.events {
+                    match event {
+                        RoundEvent::Teleported { .. } => teleports += 1,
+                        RoundEvent::BonusPoint { .. } => bonuses += 1,
+                        RoundEvent::NeutralTrapHit { .. } => neutral_traps += 1,
+                        _ => {}
+                    }
+                }
+            });
+        }
+    }
+    assert!(teleports > 0 && bonuses > 0 && neutral_traps > 0);
+}
+
+fn collides_with_finished_piece(game_board: &GameBoard) -> bool {
+    game_board.events.iter().any(|event| match event {
+        RoundEvent::Collision { step, .. } => game_board
+            .events
+            .iter()
+            .any(|e| matches!(e, RoundEvent::GoalReached { step: goal_step, .. } if goal_step == step)),
+        _ => false,
+    })
+}
+
+#[test]
+fn resolutions_differ_only_on_collisions_with_a_finished_piece() {
+    let mut differing = 0;
+    for size in 2..=3 {
+        all_pairs(&enumerate_boards(size, 5, &Movement::default()), |player, opponent| {
+            let standard = resolve_boards(player, opponent, Ruleset::default());
+            let ignored = resolve_boards(player, opponent, Ruleset::goal_piece_ignored());
+            if standard.events != ignored.events {
+                differing += 1;
+                assert!(
+                    collides_with_finished_piece(&standard) && !collides_with_finished_piece(&ignored),
+                    "unexpected difference for\n{:?}\nvs\n{:?}\nstandard: {:#?}\ngoal piece ignored: {:#?}",
+                    player.sequence, opponent.sequence, standard.events, ignored.events
+                );
+            }
+        });
+    }
+    assert!(differing > 0);
+}
+
+#[test]
+fn replays_keep_the_ruleset() {
+    let fixture = load_fixture("collision_after_goal");
+    for ruleset in [Ruleset::default(), Ruleset::goal_piece_ignored()] {
+        let game_board = resolve_boards(&fixture.player, &fixture.opponent, ruleset);
+        let last = game_board.replay_steps().pop().expect("round has steps");
+        assert_eq!(last.ruleset, ruleset);
+        assert_eq!(last.events, game_board.events);
+    }
+}
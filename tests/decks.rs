@@ -0,0 +1,79 @@
+mod common;
+
+use common::load_fixture;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use spaces_game::engine::board::Board;
+use spaces_game::engine::deck::{Deck, DeckError, DeckRule, HAND_SIZE};
+use spaces_game::engine::layout::LayoutPreset;
+use spaces_game::engine::match_state::MatchConfig;
+use spaces_game::engine::persona::Persona;
+
+fn config(rounds: usize) -> MatchConfig {
+    MatchConfig { rounds, ..MatchConfig::default() }
+}
+
+/// A deck of `count` distinct 2×2 boards.
+fn deck(rule: DeckRule, count: usize) -> Deck {
+    let boards: Vec<Board> = Persona::Random.library(&config(1), 2).into_iter().take(count).collect();
+    assert_eq!(boards.len(), count);
+    Deck { boards, ..Deck::new("Test", rule) }
+}
+
+#[test]
+fn open_decks_offer_every_board_every_round() {
+    let deck = deck(DeckRule::Open, 5);
+    let mut rng = StdRng::seed_from_u64(1);
+    let played = [deck.boards[0].clone(), deck.boards[0].clone()];
+    assert_eq!(deck.deal(&config(8), &played, &mut rng), deck.boards);
+}
+
+#[test]
+fn single_use_boards_are_played_once_per_match() {
+    let deck = deck(DeckRule::SingleUse, 5);
+    let mut rng = StdRng::seed_from_u64(1);
+    let played = [deck.boards[1].clone(), deck.boards[3].clone()];
+    let offered = deck.deal(&config(4), &played, &mut rng);
+    assert_eq!(offered, vec![deck.boards[0].clone(), deck.boards[2].clone(), deck.boards[4].clone()]);
+
+    assert_eq!(deck.check(&config(5)), Ok(()));
+    assert_eq!(deck.check(&config(8)), Err(DeckError::TooFewBoards { playable: 5, rounds: 8 }));
+}
+
+#[test]
+fn hands_are_drawn_from_the_deck() {
+    let deck = deck(DeckRule::Hand(HAND_SIZE), 10);
+    let mut rng = StdRng::seed_from_u64(1);
+    let hands: Vec<Vec<Board>> = (0..5).map(|_| deck.deal(&config(8), &[], &mut rng)).collect();
+    for hand in &hands {
+        assert_eq!(hand.len(), HAND_SIZE);
+        assert!(hand.iter().all(|board| deck.contains(board)));
+    }
+    assert!(hands.windows(2).any(|pair| pair[0] != pair[1]), "every hand was the same");
+
+    // A deck smaller than the hand deals all of it
+    let small = self::deck(DeckRule::Hand(HAND_SIZE), 2);
+    assert_eq!(small.deal(&config(8), &[], &mut rng).len(), 2);
+}
+
+#[test]
+fn only_boards_that_suit_the_match_are_dealt() {
+    let mut deck = deck(DeckRule::Open, 3);
+    let pillar = MatchConfig { layout: LayoutPreset::Pillar, ..config(8) };
+    assert_eq!(deck.check(&pillar), Err(DeckError::NoPlayableBoards));
+    assert!(deck.deal(&pillar, &[], &mut StdRng::seed_from_u64(1)).is_empty());
+
+    let bigger = load_fixture("sideways_move").player;
+    deck.toggle(&bigger);
+    assert_eq!(deck.boards.len(), 4);
+    assert_eq!(deck.playable(&config(8)).len(), 4);
+    deck.toggle(&bigger);
+    assert!(!deck.contains(&bigger));
+}
+
+#[test]
+fn decks_round_trip_through_json() {
+    let deck = deck(DeckRule::Hand(3), 4);
+    let json = serde_json::to_string(&deck).unwrap();
+    assert_eq!(serde_json::from_str::<Deck>(&json).unwrap(), deck);
+}
@@ -0,0 +1,111 @@
+mod common;
+
+use common::{assert_snapshot, load_fixture, resolve};
+use spaces_game::engine::deck::DeckRule;
+use spaces_game::engine::layout::LayoutPreset;
+use spaces_game::engine::movement::MovementPreset;
+use spaces_game::engine::opponent::AvatarColor;
+use spaces_game::engine::persona::Persona;
+use spaces_game::engine::rules::Variant;
+use spaces_game::i18n::{bundle, translate, Bundle, Locale};
+use spaces_game::render::describe::{describe_board, describe_opponent_board, describe_round};
+use spaces_game::render::svg::Theme;
+
+#[test]
+fn every_language_has_every_message_with_the_same_arguments() {
+    let english = bundle(Locale::English);
+    for locale in Locale::ALL {
+        let translation = bundle(locale);
+        for id in english.ids() {
+            assert!(translation.contains(id), "{} lacks `{}`", locale.code(), id);
+            assert_eq!(translation.variables(id), english.variables(id), "{} `{}`", locale.code(), id);
+        }
+        let extra: Vec<&str> = translation.ids().filter(|id| !english.contains(id)).collect();
+        assert!(extra.is_empty(), "{} has messages English lacks: {:?}", locale.code(), extra);
+    }
+}
+
+#[test]
+fn labels_exist_for_every_rule_map_and_setting() {
+    let mut ids = vec!["square-middle-middle".to_string()];
+    ids.extend(Variant::ALL.iter().flat_map(|v| [format!("variant-{}", v.key()), format!("variant-{}-description", v.key())]));
+    ids.extend(MovementPreset::ALL.iter().map(|m| format!("movement-{}", m.key())));
+    ids.extend(LayoutPreset::ALL.iter().flat_map(|l| [format!("layout-{}", l.key()), format!("layout-{}-description", l.key())]));
+    ids.extend(Theme::ALL.iter().map(|theme| format!("theme-{}", theme.key())));
+    ids.extend(DeckRule::ALL.iter().map(|rule| format!("deck-rule-{}", rule.key())));
+    ids.extend(Persona::ALL.iter().map(|p| format!("persona-{}-description", p.name().to_lowercase())));
+    ids.extend(AvatarColor::ALL.iter().map(|color| format!("color-{}", color.key())));
+    for vertical in ["top", "middle", "bottom"] {
+        for horizontal in ["left", "middle", "right"] {
+            ids.push(format!("square-{}-{}", vertical, horizontal));
+        }
+    }
+    let english = bundle(Locale::English);
+    let missing: Vec<&String> = ids.iter().filter(|id| !english.contains(id)).collect();
+    assert!(missing.is_empty(), "no message for {:?}", missing);
+}
+
+#[test]
+fn seconds_left_is_pluralized() {
+    let seconds = |locale, n: i32| translate(locale, "seconds-left", &[("seconds", n.into())]);
+    assert_eq!(seconds(Locale::English, 1), "1 second left!");
+    assert_eq!(seconds(Locale::English, 5), "5 seconds left!");
+    assert_eq!(seconds(Locale::English, 0), "0 seconds left!");
+    assert_eq!(seconds(Locale::Spanish, 1), "¡Queda 1 segundo!");
+    assert_eq!(seconds(Locale::Spanish, 10), "¡Quedan 10 segundos!");
+}
+
+#[test]
+fn variants_match_exact_values_before_plural_categories() {
+    let source = "\
+apples = { $count ->
+        [0] No apples
+        [one] One apple
+       *[other] { $count } apples
+    } for { $name }.
+";
+    let bundle = Bundle::parse(Locale::English, source).unwrap();
+    let apples = |count: i64| bundle.format("apples", &[("count", count.into()), ("name", "Ada".into())]).unwrap();
+    assert_eq!(apples(0), "No apples for Ada.");
+    assert_eq!(apples(1), "One apple for Ada.");
+    assert_eq!(apples(7), "7 apples for Ada.");
+    assert_eq!(bundle.variables("apples"), ["count", "name"]);
+    // Missing arguments are left showing, and missing selectors take the default
+    assert_eq!(bundle.format("apples", &[]).unwrap(), "{$count} apples for {$name}.");
+}
+
+#[test]
+fn missing_messages_fall_back_to_english_then_the_id() {
+    let spanish = Bundle::parse(Locale::Spanish, "cancel = Cancelar\n").unwrap();
+    assert_eq!(spanish.format("cancel", &[]).as_deref(), Some("Cancelar"));
+    assert_eq!(spanish.format("resume", &[]), None);
+    assert_eq!(translate(Locale::Spanish, "resume", &[]), "Reanudar");
+    assert_eq!(translate(Locale::Spanish, "no-such-message", &[]), "no-such-message");
+}
+
+#[test]
+fn broken_bundles_report_the_line() {
+    let error = |source: &str| Bundle::parse(Locale::English, source).unwrap_err();
+    assert_eq!(error("ok = fine\n\nbroken = { $name\n").line, 3);
+    assert_eq!(error("a = x\na = y\n").message, "`a` is defined twice");
+    assert_eq!(error("just text\n").line, 1);
+    let no_default = error("pick = { $n ->\n    [one] one\n    [other] many\n  }\n");
+    assert_eq!(no_default.to_string(), "line 1: the selector on `$n` needs a `*` default");
+}
+
+#[test]
+fn boards_and_rounds_are_described_in_spanish() {
+    let mut described = String::new();
+    for name in ["both_trapped", "diagonal_and_jump", "collision_bounce", "teleport_and_bonus"] {
+        let fixture = load_fixture(name);
+        let layout = fixture.layout.as_ref();
+        described.push_str(&format!(
+            "{}\njugador: {}\nrival: {}\nronda: {}\n\n",
+            name,
+            describe_board(&fixture.player, layout, Locale::Spanish),
+            describe_opponent_board(&fixture.opponent, layout, Locale::Spanish),
+            describe_round(&resolve(&fixture), Locale::Spanish),
+        ));
+    }
+    assert_snapshot("descriptions_es.txt", &described);
+}
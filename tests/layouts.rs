@@ -0,0 +1,86 @@
+use spaces_game::engine::board::{Board, BoardError, CellContent};
+use spaces_game::engine::layout::{Layout, LayoutError, LayoutPreset, Tile};
+use spaces_game::engine::movement::MovementPreset;
+
+#[test]
+fn presets_parse_and_round_trip() {
+    for preset in LayoutPreset::ALL {
+        let Some(layout) = preset.layout() else {
+            assert_eq!(preset, LayoutPreset::Open);
+            continue;
+        };
+        assert_eq!(layout.name(), preset.label());
+        let json = serde_json::to_string(&layout).unwrap();
+        assert_eq!(serde_json::from_str::<Layout>(&json).unwrap(), layout);
+    }
+}
+
+#[test]
+fn layouts_are_checked_when_parsed() {
+    assert_eq!(Layout::parse("empty", "\n"), Err(LayoutError::Empty));
+    assert_eq!(Layout::parse("short", "..\n."), Err(LayoutError::NotSquare { row: 1 }));
+    assert_eq!(Layout::parse("odd", "..\n.?"), Err(LayoutError::UnknownTile { row: 1, col: 1, symbol: '?' }));
+    assert_eq!(Layout::parse("lopsided", "#.\n.."), Err(LayoutError::NotSymmetric { row: 0, col: 0 }));
+    assert_eq!(Layout::parse("crowded", "aaa\n...\naaa"), Err(LayoutError::UnpairedTeleporter('a')));
+    assert_eq!(Layout::parse("sealed", "#.#\n...\n#.#").map(|l| l.size()), Ok(3));
+    assert_eq!(Layout::parse("walled", "##\n##"), Err(LayoutError::NoStart));
+
+    // Serialized layouts go through the same checks
+    let lopsided = r##"{"name":"lopsided","rows":["#.",".."]}"##;
+    assert!(serde_json::from_str::<Layout>(lopsided).is_err());
+}
+
+#[test]
+fn teleporters_pair_up() {
+    let layout = LayoutPreset::Crossroads.layout().unwrap();
+    assert_eq!(layout.tile(1, 1), Tile::Teleporter('a'));
+    assert_eq!(layout.partner(1, 1), Some((2, 2)));
+    assert_eq!(layout.partner(0, 3), Some((3, 0)));
+    assert_eq!(layout.partner(1, 2), None);
+}
+
+/// Plays `steps` after starting at (`size` - 1, `start_col`) without checking
+/// them, then finishes from the top row.
+fn board(size: usize, start_col: usize, steps: &[(usize, usize, CellContent)]) -> Board {
+    let mut board = Board::new(size);
+    let mut position = (size - 1, start_col);
+    board.sequence.push((position.0, position.1, CellContent::Player));
+    for (row, col, content) in steps {
+        if *content == CellContent::Player {
+            position = (*row, *col);
+        } else {
+            board.grid[*row][*col] = content.clone();
+        }
+        board.sequence.push((*row, *col, content.clone()));
+    }
+    board.sequence.push((0, position.1, CellContent::Final));
+    board
+}
+
+#[test]
+fn walls_block_moves_jumps_and_traps() {
+    let pillar = LayoutPreset::Pillar.layout().unwrap();
+    let jumps = MovementPreset::Jumps.movement();
+    let long_traps = MovementPreset::LongTraps.movement();
+
+    let around = board(3, 0, &[(1, 0, CellContent::Player), (0, 0, CellContent::Player)]);
+    assert_eq!(around.validate_on(&jumps, &pillar), Ok(()));
+
+    let into_wall = board(3, 1, &[(1, 1, CellContent::Player), (0, 1, CellContent::Player)]);
+    assert_eq!(into_wall.validate_on(&jumps, &pillar), Err(BoardError::IllegalStep { step: 1, row: 1, col: 1 }));
+
+    let over_wall = board(3, 1, &[(0, 1, CellContent::Player)]);
+    assert_eq!(over_wall.validate(&jumps), Ok(()));
+    assert_eq!(over_wall.validate_on(&jumps, &pillar), Err(BoardError::IllegalStep { step: 1, row: 0, col: 1 }));
+
+    let trap_over_wall = board(3, 1, &[(0, 1, CellContent::Trap), (2, 0, CellContent::Player), (1, 0, CellContent::Player), (0, 0, CellContent::Player)]);
+    assert_eq!(trap_over_wall.validate(&long_traps), Ok(()));
+    assert_eq!(trap_over_wall.validate_on(&long_traps, &pillar), Err(BoardError::IllegalStep { step: 1, row: 0, col: 1 }));
+}
+
+#[test]
+fn boards_must_match_the_layout_size() {
+    let open = board(2, 0, &[(0, 0, CellContent::Player)]);
+    let pillar = LayoutPreset::Pillar.layout().unwrap();
+    assert_eq!(open.validate_on(&MovementPreset::Classic.movement(), &pillar), Err(BoardError::LayoutSizeMismatch));
+}
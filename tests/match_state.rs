@@ -0,0 +1,250 @@
+mod common;
+
+use common::{load_fixture, resolve};
+use spaces_game::engine::board::{Board, BoardError};
+use spaces_game::engine::game_board::Side;
+use spaces_game::engine::layout::LayoutPreset;
+use spaces_game::engine::match_state::{MatchConfig, MatchError, MatchEvent, MatchPhase, MatchState};
+use spaces_game::engine::rules::Variant;
+
+fn config(rounds: usize) -> MatchConfig {
+    MatchConfig { rounds, ..MatchConfig::default() }
+}
+
+fn choose(state: &mut MatchState, side: Side, board: &Board) -> Result<(), MatchError> {
+    state.apply(MatchEvent::BoardChosen { side, board: board.clone() })
+}
+
+/// Plays one round of `state` with the boards of `fixture`, up to its summary.
+fn play_round(state: &mut MatchState, fixture: &str) {
+    let fixture = load_fixture(fixture);
+    choose(state, Side::Player, &fixture.player).unwrap();
+    choose(state, Side::Opponent, &fixture.opponent).unwrap();
+    state.apply(MatchEvent::RevealFinished).unwrap();
+}
+
+#[test]
+fn a_match_walks_through_every_phase() {
+    let mut state = MatchState::new(config(2));
+    let fixture = load_fixture("both_reach_goal");
+    let round = resolve(&fixture);
+    assert_eq!(state.phase(), MatchPhase::Lobby);
+
+    state.apply(MatchEvent::Start).unwrap();
+    assert_eq!(state.phase(), MatchPhase::Selecting);
+    choose(&mut state, Side::Player, &fixture.player).unwrap();
+    assert_eq!(state.phase(), MatchPhase::AwaitingOpponent);
+    choose(&mut state, Side::Opponent, &fixture.opponent).unwrap();
+    assert_eq!(state.phase(), MatchPhase::Revealing);
+    assert_eq!(state.rounds().len(), 1);
+    // The round is resolved, but only counted once it has been shown
+    assert_eq!(state.game_board().map(|g| g.events.clone()), Some(round.events.clone()));
+    assert_eq!(state.scores(), (0, 0));
+
+    state.apply(MatchEvent::RevealFinished).unwrap();
+    assert_eq!(state.phase(), MatchPhase::RoundSummary);
+    assert_eq!(state.scores(), (round.player_score, round.opponent_score));
+
+    state.apply(MatchEvent::Continue).unwrap();
+    assert_eq!((state.phase(), state.round()), (MatchPhase::Selecting, 2));
+    assert!(state.board(Side::Player).is_none() && state.game_board().is_none());
+
+    play_round(&mut state, "both_reach_goal");
+    assert!(state.is_last_round());
+    state.apply(MatchEvent::Continue).unwrap();
+    assert_eq!(state.phase(), MatchPhase::MatchOver);
+    assert_eq!(state.scores(), (2 * round.player_score, 2 * round.opponent_score));
+
+    state.apply(MatchEvent::RematchRequested { side: Side::Player }).unwrap();
+    assert_eq!(state.phase(), MatchPhase::RematchPending { by: Side::Player });
+    state.apply(MatchEvent::RematchRequested { side: Side::Opponent }).unwrap();
+    assert_eq!((state.phase(), state.round(), state.scores()), (MatchPhase::Lobby, 1, (0, 0)));
+    assert_eq!(state.config(), &config(2));
+    assert!(state.rounds().is_empty());
+}
+
+/// A one-round match played to its end.
+fn finished_match() -> MatchState {
+    let mut state = MatchState::new(config(1));
+    state.apply(MatchEvent::Start).unwrap();
+    play_round(&mut state, "both_reach_goal");
+    state.apply(MatchEvent::Continue).unwrap();
+    state
+}
+
+#[test]
+fn a_rematch_needs_both_sides() {
+    let mut state = finished_match();
+    state.apply(MatchEvent::RematchRequested { side: Side::Opponent }).unwrap();
+    assert_eq!(state.phase(), MatchPhase::RematchPending { by: Side::Opponent });
+    assert_eq!(
+        state.apply(MatchEvent::RematchRequested { side: Side::Opponent }),
+        Err(MatchError::AlreadyRequested(Side::Opponent))
+    );
+    assert!(state.is_over());
+    state.apply(MatchEvent::RematchRequested { side: Side::Player }).unwrap();
+    assert_eq!(state.phase(), MatchPhase::Lobby);
+}
+
+#[test]
+fn either_side_can_turn_a_rematch_down() {
+    let mut state = finished_match();
+    state.apply(MatchEvent::RematchDeclined { side: Side::Opponent }).unwrap();
+    assert_eq!(state.phase(), MatchPhase::RematchDeclined { by: Side::Opponent });
+    assert!(state.is_over());
+    assert_eq!(
+        state.apply(MatchEvent::RematchRequested { side: Side::Player }),
+        Err(MatchError::Unexpected { phase: MatchPhase::RematchDeclined { by: Side::Opponent }, event: "RematchRequested" })
+    );
+
+    // Asking first does not stop the other side from saying no
+    let mut state = finished_match();
+    state.apply(MatchEvent::RematchRequested { side: Side::Player }).unwrap();
+    state.apply(MatchEvent::RematchDeclined { side: Side::Opponent }).unwrap();
+    assert_eq!(state.phase(), MatchPhase::RematchDeclined { by: Side::Opponent });
+    assert_eq!(state.rounds().len(), 1);
+}
+
+#[test]
+fn events_serialize_for_a_remote_opponent() {
+    let board = load_fixture("both_reach_goal").opponent;
+    for event in [
+        MatchEvent::BoardChosen { side: Side::Opponent, board },
+        MatchEvent::RematchRequested { side: Side::Opponent },
+        MatchEvent::RematchDeclined { side: Side::Opponent },
+    ] {
+        let json = serde_json::to_string(&event).unwrap();
+        assert_eq!(serde_json::from_str::<MatchEvent>(&json).unwrap(), event);
+    }
+}
+
+#[test]
+fn the_opponent_may_choose_first() {
+    let fixture = load_fixture("collision_same_square");
+    let mut state = MatchState::new(config(1));
+    state.apply(MatchEvent::Start).unwrap();
+    choose(&mut state, Side::Opponent, &fixture.opponent).unwrap();
+    assert_eq!(state.phase(), MatchPhase::Selecting);
+    choose(&mut state, Side::Player, &fixture.player).unwrap();
+    assert_eq!(state.phase(), MatchPhase::Revealing);
+}
+
+#[test]
+fn a_hot_seat_match_plays_through_to_a_rematch() {
+    // Both people pick from the boards saved on the one device, the player first
+    let config = config(3);
+    let saved: Vec<Board> = ["both_reach_goal", "collision_same_square", "both_trapped", "goal_vs_trap_same_step"]
+        .into_iter()
+        .flat_map(|name| {
+            let fixture = load_fixture(name);
+            [fixture.player, fixture.opponent]
+        })
+        .filter(|board| config.check(board).is_ok())
+        .collect();
+    assert!(saved.len() >= 4);
+
+    let mut state = MatchState::new(config);
+    state.apply(MatchEvent::Start).unwrap();
+    for round in 0..config.rounds {
+        choose(&mut state, Side::Player, &saved[round % saved.len()]).unwrap();
+        assert_eq!(state.phase(), MatchPhase::AwaitingOpponent);
+        assert!(state.game_board().is_none());
+        choose(&mut state, Side::Opponent, &saved[(round + 1) % saved.len()]).unwrap();
+        assert_eq!(state.phase(), MatchPhase::Revealing);
+        state.apply(MatchEvent::RevealFinished).unwrap();
+        state.apply(MatchEvent::Continue).unwrap();
+    }
+    assert_eq!(state.phase(), MatchPhase::MatchOver);
+    assert_eq!(state.rounds().len(), config.rounds);
+
+    state.apply(MatchEvent::RematchRequested { side: Side::Player }).unwrap();
+    assert_eq!(state.phase(), MatchPhase::RematchPending { by: Side::Player });
+    state.apply(MatchEvent::RematchRequested { side: Side::Opponent }).unwrap();
+    assert_eq!(state.phase(), MatchPhase::Lobby);
+    assert!(state.rounds().is_empty());
+}
+
+#[test]
+fn events_out_of_phase_are_rejected() {
+    let fixture = load_fixture("collision_same_square");
+    let mut state = MatchState::new(config(1));
+    let unexpected = |phase, event| Err(MatchError::Unexpected { phase, event });
+
+    assert_eq!(state.apply(MatchEvent::RevealFinished), unexpected(MatchPhase::Lobby, "RevealFinished"));
+    assert_eq!(choose(&mut state, Side::Player, &fixture.player), unexpected(MatchPhase::Lobby, "BoardChosen"));
+    state.apply(MatchEvent::Start).unwrap();
+    assert_eq!(state.apply(MatchEvent::Start), unexpected(MatchPhase::Selecting, "Start"));
+    assert_eq!(
+        state.apply(MatchEvent::Configure(MatchConfig::default())),
+        unexpected(MatchPhase::Selecting, "Configure")
+    );
+
+    choose(&mut state, Side::Player, &fixture.player).unwrap();
+    assert_eq!(choose(&mut state, Side::Player, &fixture.player), Err(MatchError::AlreadyChosen(Side::Player)));
+    assert_eq!(state.apply(MatchEvent::Continue), unexpected(MatchPhase::AwaitingOpponent, "Continue"));
+
+    choose(&mut state, Side::Opponent, &fixture.opponent).unwrap();
+    assert_eq!(state.apply(MatchEvent::Continue), unexpected(MatchPhase::Revealing, "Continue"));
+    state.apply(MatchEvent::RevealFinished).unwrap();
+    state.apply(MatchEvent::Continue).unwrap();
+    assert_eq!(state.apply(MatchEvent::RevealFinished), unexpected(MatchPhase::MatchOver, "RevealFinished"));
+    // A rejected event leaves the state as it was
+    assert_eq!(state.phase(), MatchPhase::MatchOver);
+    assert_eq!(state.rounds().len(), 1);
+}
+
+#[test]
+fn boards_must_suit_the_match() {
+    let mut state = MatchState::new(config(1));
+    let layout_config = MatchConfig { layout: LayoutPreset::Pillar, ..config(1) };
+    state.apply(MatchEvent::Configure(layout_config)).unwrap();
+    state.apply(MatchEvent::Start).unwrap();
+
+    let open_board = load_fixture("collision_same_square").player;
+    assert_eq!(
+        choose(&mut state, Side::Opponent, &open_board),
+        Err(MatchError::IllegalBoard { side: Side::Opponent, error: BoardError::LayoutSizeMismatch })
+    );
+    assert_eq!(
+        choose(&mut state, Side::Player, &Board::new(3)),
+        Err(MatchError::IllegalBoard { side: Side::Player, error: BoardError::MissingStart })
+    );
+    assert!(state.board(Side::Player).is_none() && state.board(Side::Opponent).is_none());
+}
+
+#[test]
+fn rounds_keep_the_configured_rules() {
+    let mut state = MatchState::new(config(1));
+    let bumpers = MatchConfig { variant: Variant::Bumpers, ..config(1) };
+    state.apply(MatchEvent::Configure(bumpers)).unwrap();
+    state.apply(MatchEvent::Start).unwrap();
+    play_round(&mut state, "collision_bounce");
+    assert_eq!(state.rounds()[0].ruleset, bumpers.ruleset());
+    assert_eq!(state.game_board().map(|g| g.ruleset), Some(bumpers.ruleset()));
+}
+
+#[test]
+fn resumed_matches_show_their_last_round() {
+    let mut state = MatchState::new(config(3));
+    state.apply(MatchEvent::Start).unwrap();
+    play_round(&mut state, "both_trapped");
+
+    let json = serde_json::to_string(&state).unwrap();
+    let restored: MatchState = serde_json::from_str(&json).unwrap();
+    assert!(restored.game_board().is_none());
+    let restored = restored.resumed();
+    assert_eq!((restored.phase(), restored.round(), restored.scores()), (MatchPhase::RoundSummary, 1, state.scores()));
+    assert_eq!(
+        restored.game_board().map(|g| g.events.clone()),
+        state.game_board().map(|g| g.events.clone())
+    );
+}
+
+#[test]
+fn configs_saved_before_ranked_play_are_unranked() {
+    let mut json = serde_json::to_value(MatchConfig::default()).unwrap();
+    json.as_object_mut().unwrap().remove("ranked");
+    let config: MatchConfig = serde_json::from_value(json).unwrap();
+    assert!(!config.ranked);
+}
+
@@ -0,0 +1,64 @@
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use spaces_game::engine::match_state::MatchConfig;
+use spaces_game::engine::opponent::{legacy_id, migrate, Opponent, OpponentType};
+use spaces_game::engine::persona::Persona;
+use spaces_game::engine::timing::GameSpeed;
+
+#[test]
+fn humans_of_the_same_name_get_different_ids() {
+    let mut rng = StdRng::seed_from_u64(7);
+    let first = Opponent::human("Sam".to_string(), 1_700_000_000_000, &mut rng);
+    let second = Opponent::human("Sam".to_string(), 1_700_000_000_000, &mut rng);
+    assert_ne!(first.id, second.id);
+    assert_ne!(first.id, legacy_id("Sam", &OpponentType::Human));
+    assert_eq!(first.created, Some(1_700_000_000_000));
+}
+
+#[test]
+fn computer_ids_stay_as_they_were() {
+    assert_eq!(Opponent::computer(Persona::Random).id, "cpu_cpu");
+    assert_eq!(Opponent::computer(Persona::Trapper).id, "cpu_trapper");
+    let colors: Vec<_> = Persona::ALL.iter().map(|&persona| Opponent::computer(persona).avatar.color).collect();
+    assert!(colors.windows(2).all(|pair| pair[0] != pair[1]), "neighbouring personas share a color");
+}
+
+#[test]
+fn the_badge_falls_back_to_the_first_letter() {
+    let mut opponent = Opponent::human("  ada lovelace".to_string(), 0, &mut StdRng::seed_from_u64(1));
+    assert_eq!(opponent.glyph(), 'A');
+    opponent.avatar.glyph = Some('★');
+    assert_eq!(opponent.glyph(), '★');
+    opponent.name.clear();
+    opponent.avatar.glyph = None;
+    assert_eq!(opponent.glyph(), '?');
+}
+
+#[test]
+fn saved_opponents_load_and_move_to_unique_ids() {
+    // As saved before opponents had profiles
+    let saved = r#"[
+        {"name": "Mary Jane", "id": "human_mary_jane", "opponent_type": "Human"},
+        {"name": "Trapper", "id": "cpu_trapper", "opponent_type": "Computer", "persona": "Trapper"}
+    ]"#;
+    let mut opponents: Vec<Opponent> = serde_json::from_str(saved).unwrap();
+    assert_eq!(opponents[0].notes, "");
+    assert_eq!((opponents[0].speed, opponents[0].config, opponents[0].created), (None, None, None));
+
+    let mut rng = StdRng::seed_from_u64(3);
+    let moved = migrate(&mut opponents, &mut rng);
+    assert_eq!(moved, [("human_mary_jane".to_string(), opponents[0].id.clone())]);
+    assert_eq!(opponents[1].id, "cpu_trapper");
+    assert!(migrate(&mut opponents, &mut rng).is_empty(), "migrating twice moves nothing");
+}
+
+#[test]
+fn profiles_round_trip() {
+    let mut opponent = Opponent::human("Kim".to_string(), 42, &mut StdRng::seed_from_u64(9));
+    opponent.notes = "Loves traps".to_string();
+    opponent.speed = Some(GameSpeed::Relaxed);
+    opponent.config = Some(MatchConfig { rounds: 3, ranked: true, ..MatchConfig::default() });
+    opponent.avatar.glyph = Some('K');
+    let json = serde_json::to_string(&opponent).unwrap();
+    assert_eq!(serde_json::from_str::<Opponent>(&json).unwrap(), opponent);
+}
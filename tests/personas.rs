@@ -0,0 +1,204 @@
+mod common;
+
+use common::load_fixture;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use spaces_game::engine::board::{Board, CellContent};
+use spaces_game::engine::game_board::RoundRecord;
+use spaces_game::engine::layout::LayoutPreset;
+use spaces_game::engine::game_board::Side;
+use spaces_game::engine::match_state::{MatchConfig, MatchEvent, MatchState};
+use spaces_game::engine::movement::MovementPreset;
+use spaces_game::engine::persona::Persona;
+use spaces_game::engine::player_model::PlayerModel;
+use spaces_game::engine::strategy::StrategyBook;
+
+fn configs() -> Vec<(MatchConfig, usize)> {
+    vec![
+        (MatchConfig::default(), 2),
+        (MatchConfig::default(), 3),
+        (MatchConfig { movement: MovementPreset::Free, ..MatchConfig::default() }, 2),
+        (MatchConfig { layout: LayoutPreset::Crossroads, ..MatchConfig::default() }, 4),
+    ]
+}
+
+#[test]
+fn every_library_is_playable() {
+    for (config, size) in configs() {
+        for persona in Persona::ALL {
+            let library = persona.library(&config, size);
+            assert!(!library.is_empty(), "{} has no boards for {:?}", persona.name(), config);
+            for board in &library {
+                assert_eq!(board.size, size);
+                assert_eq!(config.check(board), Ok(()), "{}: {:?}", persona.name(), board.sequence);
+            }
+        }
+    }
+}
+
+#[test]
+fn personas_build_their_own_libraries() {
+    let config = MatchConfig::default();
+    let trapper = Persona::Trapper.library(&config, 3);
+    assert!(trapper.iter().all(|board| board.sequence[1].2 == CellContent::Trap));
+
+    let sprinter = Persona::Sprinter.library(&config, 3);
+    // Start, two moves forward and the final move
+    assert!(sprinter.iter().all(|board| board.sequence.len() == 4));
+    assert!(sprinter.iter().all(|board| board.sequence.iter().all(|step| step.2 != CellContent::Trap)));
+
+    assert!(Persona::Random.library(&config, 3).len() > trapper.len());
+}
+
+fn played(config: &MatchConfig, player: &str) -> RoundRecord {
+    let fixture = load_fixture(player);
+    RoundRecord {
+        ruleset: config.ruleset(),
+        layout: None,
+        player: fixture.player,
+        opponent: fixture.opponent,
+    }
+}
+
+#[test]
+fn mirror_plays_your_last_board() {
+    let config = MatchConfig::default();
+    let library = Persona::Mirror.library(&config, 2);
+    let mut rng = StdRng::seed_from_u64(7);
+    let (model, book) = (PlayerModel::default(), StrategyBook::default());
+    let history = [played(&config, "both_trapped"), played(&config, "collision_same_square")];
+    let chosen = Persona::Mirror.choose(&config, &library, &history, &model, &book, &mut rng);
+    assert_eq!(chosen.as_ref(), Some(&history[1].player));
+
+    // With nothing to copy, or a board of another size, it plays from its
+    // library
+    let first = Persona::Mirror.choose(&config, &library, &[], &model, &book, &mut rng).unwrap();
+    assert!(library.contains(&first));
+    let bigger = [played(&config, "sideways_move")];
+    let other_size = Persona::Mirror.choose(&config, &library, &bigger, &model, &book, &mut rng).unwrap();
+    assert!(library.contains(&other_size));
+}
+
+#[test]
+fn adaptive_counters_your_most_played_board() {
+    let config = MatchConfig::default();
+    let library = Persona::Adaptive.library(&config, 2);
+    let mut rng = StdRng::seed_from_u64(7);
+    let book = StrategyBook::default();
+    let favourite = played(&config, "both_reach_goal");
+    let mut model = PlayerModel::default();
+    for round in [favourite.clone(), played(&config, "both_trapped"), favourite.clone()] {
+        model.observe(&round.player);
+    }
+
+    let chosen = Persona::Adaptive.choose(&config, &library, &[], &model, &book, &mut rng).unwrap();
+    let best = library
+        .iter()
+        .filter_map(|board| model.expected_margin(&config, board))
+        .max_by(f64::total_cmp)
+        .unwrap();
+    assert_eq!(model.expected_margin(&config, &chosen), Some(best));
+    assert!(best > 0.0);
+
+    // Knowing nothing of the player, it plays from its library
+    let first = Persona::Adaptive.choose(&config, &library, &[], &PlayerModel::default(), &book, &mut rng).unwrap();
+    assert!(library.contains(&first));
+}
+
+#[test]
+fn the_model_weighs_boards_by_how_often_they_are_played() {
+    let config = MatchConfig::default();
+    let favourite = played(&config, "both_reach_goal");
+    let other = played(&config, "both_trapped");
+    let mut model = PlayerModel::default();
+    for round in [favourite.clone(), other.clone(), favourite.clone()] {
+        model.observe(&round.player);
+    }
+    assert_eq!((model.plays(&favourite.player), model.plays(&other.player)), (2, 1));
+
+    let margin = |round: &RoundRecord, candidate: &Board| {
+        let round = RoundRecord { opponent: candidate.clone(), ..round.clone() }.resolve();
+        f64::from(round.opponent_score - round.player_score)
+    };
+    let candidate = &favourite.opponent;
+    let expected = (2.0 * margin(&favourite, candidate) + margin(&other, candidate)) / 3.0;
+    assert_eq!(model.expected_margin(&config, candidate), Some(expected));
+
+    // Boards of another size tell it nothing about a 3x3 match
+    let bigger = load_fixture("sideways_move").opponent;
+    assert_eq!(model.expected_margin(&config, &bigger), None);
+    assert_eq!(model.best_response(&config, &[bigger]), None);
+}
+
+#[test]
+fn the_model_round_trips_through_json() {
+    let config = MatchConfig::default();
+    let mut model = PlayerModel::default();
+    for round in [played(&config, "both_reach_goal"), played(&config, "collision_same_square")] {
+        model.observe(&round.player);
+    }
+    let json = serde_json::to_string(&model).unwrap();
+    assert_eq!(serde_json::from_str::<PlayerModel>(&json).unwrap(), model);
+}
+
+#[test]
+fn hints_rank_boards_by_expected_points() {
+    let config = MatchConfig::default();
+    let mut opponent = PlayerModel::default();
+    let seen = load_fixture("both_reach_goal").opponent;
+    opponent.observe(&seen);
+    opponent.observe(&seen);
+    opponent.observe(&load_fixture("both_trapped").opponent);
+
+    let candidates = Persona::Random.library(&config, 2);
+    let ranked = opponent.rank_replies(&config, &candidates);
+    assert_eq!(ranked.len(), candidates.len());
+    assert!(ranked.windows(2).all(|pair| pair[0].1 >= pair[1].1));
+    for (index, points) in &ranked {
+        assert_eq!(opponent.expected_points(&config, &candidates[*index]), Some(*points));
+    }
+
+    // Boards the opponent has never met cannot be ranked
+    let bigger = [load_fixture("sideways_move").player];
+    assert!(opponent.rank_replies(&config, &bigger).is_empty());
+}
+
+/// A finished match of three rounds in which one side reaches the goal each
+/// round while the other walks into a trap: 6–0 to the player if `player_wins`.
+fn finished_match(player_wins: bool) -> MatchState {
+    let fixture = load_fixture("goal_vs_trap_same_step");
+    let (player, opponent) = match player_wins {
+        true => (fixture.opponent, fixture.player),
+        false => (fixture.player, fixture.opponent),
+    };
+    let mut state = MatchState::new(MatchConfig { rounds: 3, ..MatchConfig::default() });
+    state.apply(MatchEvent::Start).unwrap();
+    for _ in 0..3 {
+        state.apply(MatchEvent::BoardChosen { side: Side::Player, board: player.clone() }).unwrap();
+        state.apply(MatchEvent::BoardChosen { side: Side::Opponent, board: opponent.clone() }).unwrap();
+        state.apply(MatchEvent::RevealFinished).unwrap();
+        state.apply(MatchEvent::Continue).unwrap();
+    }
+    assert!(state.is_over());
+    state
+}
+
+#[test]
+fn personas_accept_or_decline_rematches_by_score_and_record() {
+    let mut rng = StdRng::seed_from_u64(5);
+    let (won, lost) = (finished_match(true), finished_match(false));
+    assert_eq!(won.scores(), (6, 0));
+
+    assert!(Persona::Trapper.accepts_rematch(&lost, (0, 1), &mut rng));
+    assert!(!Persona::Trapper.accepts_rematch(&won, (1, 0), &mut rng), "the Trapper sulks after a thrashing");
+
+    assert!(Persona::Sprinter.accepts_rematch(&won, (2, 0), &mut rng));
+    assert!(!Persona::Sprinter.accepts_rematch(&won, (3, 0), &mut rng));
+    assert!(Persona::Expert.accepts_rematch(&lost, (0, 2), &mut rng));
+    assert!(!Persona::Expert.accepts_rematch(&lost, (1, 4), &mut rng));
+    assert!(Persona::Mirror.accepts_rematch(&won, (10, 0), &mut rng));
+
+    // The CPU's answer depends on its mood, but it answers both ways
+    let answers: Vec<bool> = (0..32).map(|_| Persona::Random.accepts_rematch(&lost, (0, 1), &mut rng)).collect();
+    assert!(answers.contains(&true) && answers.contains(&false));
+}
@@ -0,0 +1,75 @@
+mod common;
+
+use common::strategies::{arbitrary_board, legal_board_pair};
+use common::{mirror_mismatch, resolve_boards};
+use proptest::prelude::*;
+use spaces_game::engine::board::Board;
+use spaces_game::engine::game_board::{GameBoard, RoundEvent};
+use spaces_game::engine::rules::{Resolution, Ruleset, Variant};
+
+fn resolve(player: &Board, opponent: &Board) -> GameBoard {
+    resolve_boards(player, opponent, Ruleset::default())
+}
+
+proptest! {
+    #[test]
+    fn generated_boards_are_legal((movement, player, opponent) in legal_board_pair()) {
+        prop_assert_eq!(player.validate(&movement), Ok(()));
+        prop_assert_eq!(opponent.validate(&movement), Ok(()));
+    }
+
+    #[test]
+    fn scores_are_never_negative(
+        (_, player, opponent) in legal_board_pair(),
+        variant in prop::sample::select(Variant::ALL.to_vec()),
+    ) {
+        let game_board = resolve_boards(&player, &opponent, variant.ruleset());
+        prop_assert!(game_board.player_score >= 0);
+        prop_assert!(game_board.opponent_score >= 0);
+        for event in &game_board.events {
+            if let RoundEvent::ForwardPoint { score, .. }
+                | RoundEvent::SidewaysPoint { score, .. }
+                | RoundEvent::GoalPoint { score, .. }
+                | RoundEvent::CollisionPenalty { score, .. }
+                | RoundEvent::TrapPenalty { score, .. } = event {
+                prop_assert!(*score >= 0, "negative score in {}", event);
+            }
+        }
+    }
+
+    #[test]
+    fn round_ends_at_first_collision((_, player, opponent) in legal_board_pair()) {
+        let game_board = resolve(&player, &opponent);
+        let collisions: Vec<&RoundEvent> = game_board.events
+            .iter()
+            .filter(|e| matches!(e, RoundEvent::Collision { .. }))
+            .collect();
+        prop_assert!(collisions.len() <= 1);
+        if let Some(collision) = collisions.first() {
+            let step = collision.step();
+            prop_assert!(game_board.events.iter().all(|e| e.step() <= step));
+            prop_assert_eq!(game_board.events.last(), Some(&RoundEvent::RoundEnded { step }));
+        }
+    }
+
+    #[test]
+    fn swapping_sides_mirrors_the_result(
+        (_, player, opponent) in legal_board_pair(),
+        variant in prop::sample::select(Variant::ALL.to_vec()),
+        resolution in prop::sample::select(Resolution::ALL.to_vec()),
+    ) {
+        let ruleset = Ruleset { resolution, ..variant.ruleset() };
+        let forward = resolve_boards(&player, &opponent, ruleset);
+        let swapped = resolve_boards(&opponent, &player, ruleset);
+        if let Some(mismatch) = mirror_mismatch(&forward, &swapped) {
+            prop_assert!(false, "{}", mismatch);
+        }
+    }
+
+    #[test]
+    fn any_board_pair_resolves_without_panicking(player in arbitrary_board(), opponent in arbitrary_board()) {
+        let game_board = resolve(&player, &opponent);
+        prop_assert!(game_board.player_score >= 0);
+        prop_assert!(game_board.opponent_score >= 0);
+    }
+}
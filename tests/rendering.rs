@@ -0,0 +1,100 @@
+mod common;
+
+use common::{assert_snapshot, load_fixture, resolve};
+use spaces_game::engine::board::CellContent;
+use spaces_game::i18n::Locale;
+use spaces_game::render::describe::{describe_board, describe_opponent_board, describe_round};
+use spaces_game::render::svg::{board_svg, round_svg, RenderOptions, Theme};
+use spaces_game::render::text;
+
+#[test]
+fn thumbnail_svg() {
+    let fixture = load_fixture("trap_after_opponent_arrives");
+    assert_snapshot("thumbnail.svg", &board_svg(&fixture.player, &RenderOptions::default()));
+}
+
+#[test]
+fn opponent_thumbnail_svg() {
+    let fixture = load_fixture("trap_after_opponent_arrives");
+    assert_snapshot("opponent_thumbnail.svg", &board_svg(&fixture.opponent, &RenderOptions::opponent()));
+}
+
+#[test]
+fn thumbnail_svg_connects_diagonal_steps_and_jumps() {
+    let fixture = load_fixture("diagonal_and_jump");
+    let player = board_svg(&fixture.player, &RenderOptions::default());
+    let opponent = board_svg(&fixture.opponent, &RenderOptions::opponent());
+    assert_eq!(player.matches(r#"stroke-linecap="round" opacity"#).count(), 2);
+    assert_eq!(opponent.matches(r#"stroke-linecap="round" opacity"#).count(), 1);
+    assert_snapshot("thumbnail_diagonal.svg", &player);
+    assert_snapshot("opponent_thumbnail_jump.svg", &opponent);
+}
+
+#[test]
+fn round_svg_with_collision() {
+    let game_board = resolve(&load_fixture("collision_same_square"));
+    assert_snapshot("round_collision.svg", &round_svg(&game_board, &RenderOptions::default()));
+}
+
+#[test]
+fn round_svg_with_overlapping_traps_in_light_theme() {
+    let game_board = resolve(&load_fixture("both_trapped"));
+    let options = RenderOptions::default().with_theme(Theme::Light);
+    assert_snapshot("round_both_trapped_light.svg", &round_svg(&game_board, &options));
+}
+
+#[test]
+fn round_svg_on_a_map() {
+    let game_board = resolve(&load_fixture("teleport_and_bonus"));
+    assert_snapshot("round_crossroads.svg", &round_svg(&game_board, &RenderOptions::default()));
+}
+
+#[test]
+fn round_svg_skips_an_exit_arrow_off_the_board() {
+    let mut game_board = resolve(&load_fixture("both_reach_goal"));
+    assert!(game_board.player_goal_reached && game_board.opponent_goal_reached);
+    let size = game_board.size;
+    for sequence in [&mut game_board.player_sequence, &mut game_board.opponent_sequence] {
+        for (_, col, content) in sequence.iter_mut() {
+            if *content == CellContent::Final {
+                *col = size + 3;
+            }
+        }
+    }
+    let options = RenderOptions { hide_final_move: false, ..RenderOptions::default() };
+    let rendered = round_svg(&game_board, &options);
+    assert!(rendered.ends_with("</svg>"));
+}
+
+#[test]
+fn board_text() {
+    let fixture = load_fixture("trap_after_opponent_arrives");
+    let rendered = format!(
+        "{}\n{}",
+        text::render_board(&fixture.player),
+        text::render_opponent_board(&fixture.opponent)
+    );
+    assert_snapshot("boards.txt", &rendered);
+}
+
+#[test]
+fn board_and_round_descriptions() {
+    let mut described = String::new();
+    for name in ["both_trapped", "diagonal_and_jump", "collision_bounce", "teleport_and_bonus"] {
+        let fixture = load_fixture(name);
+        let layout = fixture.layout.as_ref();
+        described.push_str(&format!(
+            "{}
+player: {}
+opponent: {}
+round: {}
+\n",
+            name,
+            describe_board(&fixture.player, layout, Locale::English),
+            describe_opponent_board(&fixture.opponent, layout, Locale::English),
+            describe_round(&resolve(&fixture), Locale::English),
+        ));
+    }
+    assert_snapshot("descriptions.txt", &described);
+}
+
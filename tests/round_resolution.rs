@@ -0,0 +1,191 @@
+mod common;
+
+use common::{assert_snapshot, load_fixture, resolve, resolve_boards, round_report};
+use spaces_game::engine::board::{Board, BoardError};
+use spaces_game::engine::game_board::{RoundEvent, Side};
+use spaces_game::engine::game_board::RoundRecord;
+use spaces_game::engine::movement::{Movement, MovementPreset};
+use spaces_game::engine::rules::{Ruleset, Variant};
+
+fn check(name: &str) -> spaces_game::engine::game_board::GameBoard {
+    let game_board = resolve(&load_fixture(name));
+    assert_snapshot(&format!("{}.txt", name), &round_report(&game_board));
+    game_board
+}
+
+fn check_variant(name: &str, variant: Variant) -> spaces_game::engine::game_board::GameBoard {
+    let fixture = load_fixture(name);
+    let game_board = resolve_boards(&fixture.player, &fixture.opponent, variant.ruleset());
+    assert_snapshot(&format!("{}_{}.txt", name, variant.key()), &round_report(&game_board));
+    game_board
+}
+
+#[test]
+fn collision_on_same_square_ends_the_round() {
+    let game_board = check("collision_same_square");
+    assert!(game_board.events.contains(&RoundEvent::Collision { step: 1, row: 0, col: 0 }));
+    assert_eq!(game_board.events.last(), Some(&RoundEvent::RoundEnded { step: 1 }));
+    assert_eq!((game_board.player_score, game_board.opponent_score), (0, 0));
+}
+
+#[test]
+fn both_players_trapped_on_the_same_step() {
+    let game_board = check("both_trapped");
+    assert!(game_board.player_round_ended && game_board.opponent_round_ended);
+    assert!(!game_board.player_goal_reached && !game_board.opponent_goal_reached);
+    assert!(game_board.events.contains(&RoundEvent::TrapHit { side: Side::Player, step: 2, row: 0, col: 0 }));
+    assert!(game_board.events.contains(&RoundEvent::TrapHit { side: Side::Opponent, step: 2, row: 0, col: 1 }));
+}
+
+#[test]
+fn goal_and_trap_on_the_same_step() {
+    let game_board = check("goal_vs_trap_same_step");
+    // The opponent's goal is applied before the player's trap check
+    assert!(game_board.opponent_goal_reached);
+    assert!(game_board.events.contains(&RoundEvent::TrapHit { side: Side::Player, step: 3, row: 0, col: 0 }));
+    assert_eq!((game_board.player_score, game_board.opponent_score), (0, 2));
+}
+
+#[test]
+fn trap_placed_after_opponent_arrives_still_catches_them() {
+    let game_board = check("trap_after_opponent_arrives");
+    assert!(game_board.events.contains(&RoundEvent::TrapHit { side: Side::Opponent, step: 1, row: 0, col: 1 }));
+}
+
+#[test]
+fn both_players_reach_the_goal() {
+    let game_board = check("both_reach_goal");
+    assert!(game_board.player_goal_reached && game_board.opponent_goal_reached);
+    assert_eq!((game_board.player_score, game_board.opponent_score), (2, 2));
+}
+
+#[test]
+fn piece_that_just_reached_the_goal_can_be_collided_with() {
+    let game_board = check("collision_after_goal");
+    assert!(game_board.player_goal_reached);
+    assert!(game_board.events.contains(&RoundEvent::Collision { step: 2, row: 0, col: 0 }));
+    assert_eq!((game_board.player_score, game_board.opponent_score), (1, 0));
+}
+
+#[test]
+fn goal_piece_ignored_rules_do_not_collide_with_a_piece_that_left_the_board() {
+    let fixture = load_fixture("collision_after_goal");
+    let game_board = resolve_boards(&fixture.player, &fixture.opponent, Ruleset::goal_piece_ignored());
+    assert_snapshot("collision_after_goal_goal_piece_ignored.txt", &round_report(&game_board));
+    assert!(!game_board.events.iter().any(|e| matches!(e, RoundEvent::Collision { .. })));
+    assert_eq!((game_board.player_score, game_board.opponent_score), (2, 0));
+}
+
+#[test]
+fn fading_traps_disarm_after_three_steps() {
+    let classic = check("trap_expires");
+    assert!(classic.events.contains(&RoundEvent::TrapHit { side: Side::Player, step: 5, row: 0, col: 2 }));
+
+    let fading = check_variant("trap_expires", Variant::FadingTraps);
+    assert!(!fading.events.iter().any(|e| matches!(e, RoundEvent::TrapHit { .. })));
+    assert!(fading.player_goal_reached);
+}
+
+#[test]
+fn bumpers_knock_pieces_back_and_play_on() {
+    let classic = check("collision_bounce");
+    assert!(matches!(classic.events.last(), Some(RoundEvent::RoundEnded { .. })));
+    assert!(!classic.player_goal_reached && !classic.opponent_goal_reached);
+
+    let bumpers = check_variant("collision_bounce", Variant::Bumpers);
+    assert!(bumpers.events.iter().any(|e| matches!(e, RoundEvent::Bounced { .. })));
+    assert!(bumpers.player_goal_reached || bumpers.opponent_goal_reached);
+}
+
+#[test]
+fn sideways_moves_score_when_enabled() {
+    let classic = check("sideways_move");
+    let sideways = check_variant("sideways_move", Variant::SidewaysScoring);
+    assert!(sideways.events.iter().any(|e| matches!(e, RoundEvent::SidewaysPoint { side: Side::Opponent, .. })));
+    assert_eq!(sideways.opponent_score, classic.opponent_score + 1);
+}
+
+#[test]
+fn big_goal_is_worth_three() {
+    let game_board = check_variant("both_reach_goal", Variant::BigGoal);
+    assert_eq!((game_board.player_score, game_board.opponent_score), (4, 4));
+}
+
+#[test]
+fn diagonal_steps_and_jumps_score_each_row_gained() {
+    let fixture = load_fixture("diagonal_and_jump");
+    assert!(fixture.player.validate(&MovementPreset::Classic.movement()).is_err());
+    assert!(fixture.player.validate(&MovementPreset::Diagonal.movement()).is_ok());
+    assert!(fixture.opponent.validate(&MovementPreset::Classic.movement()).is_err());
+    assert!(fixture.opponent.validate(&MovementPreset::Jumps.movement()).is_ok());
+
+    let game_board = check("diagonal_and_jump");
+    assert!(game_board.events.contains(&RoundEvent::ForwardPoint { side: Side::Opponent, step: 1, score: 2 }));
+    assert!(game_board.player_goal_reached || game_board.opponent_goal_reached);
+}
+
+#[test]
+fn teleporters_carry_pieces_to_the_other_end() {
+    let fixture = load_fixture("teleport_and_bonus");
+    let layout = fixture.layout.as_ref().expect("fixture has a layout");
+    assert_eq!(fixture.player.validate_on(&Movement::default(), layout), Ok(()));
+    assert_eq!(fixture.opponent.validate_on(&Movement::default(), layout), Ok(()));
+
+    let game_board = check("teleport_and_bonus");
+    assert!(game_board.events.contains(&RoundEvent::Teleported { side: Side::Opponent, step: 1, row: 2, col: 2 }));
+    // The opponent stands on the entrance, but the player comes out at the exit
+    assert!(game_board.events.contains(&RoundEvent::Teleported { side: Side::Player, step: 2, row: 1, col: 1 }));
+    assert!(!game_board.events.iter().any(|e| matches!(e, RoundEvent::Collision { .. })));
+    assert!(game_board.events.iter().any(|e| matches!(e, RoundEvent::BonusPoint { side: Side::Player, step: 1, .. })));
+}
+
+#[test]
+fn neutral_traps_catch_either_side() {
+    let game_board = check("neutral_trap");
+    assert!(game_board.events.contains(&RoundEvent::NeutralTrapHit { side: Side::Player, step: 3, row: 0, col: 0 }));
+    // Each side scores the shared bonus square once
+    let bonuses: Vec<Side> = game_board.events.iter().filter_map(|e| match e {
+        RoundEvent::BonusPoint { side, .. } => Some(*side),
+        _ => None,
+    }).collect();
+    assert_eq!(bonuses, [Side::Player, Side::Opponent]);
+}
+
+#[test]
+fn round_records_resolve_with_their_own_rules() {
+    let fixture = load_fixture("trap_expires");
+    let record = RoundRecord {
+        ruleset: Variant::FadingTraps.ruleset(),
+        layout: None,
+        player: fixture.player,
+        opponent: fixture.opponent,
+    };
+    let restored: RoundRecord = serde_json::from_str(&serde_json::to_string(&record).unwrap()).unwrap();
+    assert_eq!(restored.resolve().events, record.resolve().events);
+    assert!(!restored.resolve().events.iter().any(|e| matches!(e, RoundEvent::TrapHit { .. })));
+
+    // A ruleset saved before a field existed takes the classic value for it
+    let old: Ruleset = serde_json::from_str(r#"{"resolution":"Legacy"}"#).unwrap();
+    assert_eq!(old, Ruleset::default());
+    let old: Ruleset = serde_json::from_str(r#"{"resolution":"Symmetric"}"#).unwrap();
+    assert_eq!(old, Ruleset::goal_piece_ignored());
+
+    // Records keep the map they were played on
+    let fixture = load_fixture("teleport_and_bonus");
+    let record = RoundRecord {
+        ruleset: Ruleset::default(),
+        layout: fixture.layout.clone(),
+        player: fixture.player,
+        opponent: fixture.opponent,
+    };
+    let restored: RoundRecord = serde_json::from_str(&serde_json::to_string(&record).unwrap()).unwrap();
+    assert_eq!(restored.resolve().events, resolve(&load_fixture("teleport_and_bonus")).events);
+}
+
+#[test]
+fn oversized_boards_are_rejected_before_anything_is_allocated() {
+    let board: Board = serde_json::from_str(r#"{"grid":[],"size":4000000000,"sequence":[]}"#).unwrap();
+    assert_eq!(board.validate(&Movement::default()), Err(BoardError::GridSizeMismatch));
+    let empty: Board = serde_json::from_str(r#"{"grid":[],"size":0,"sequence":[]}"#).unwrap();
+    assert_eq!(empty.validate(&Movement::default()), Err(BoardError::EmptyBoard));
+}
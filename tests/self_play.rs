@@ -0,0 +1,85 @@
+use std::fs;
+use std::path::Path;
+
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use spaces_game::engine::enumerate::enumerate_boards;
+use spaces_game::engine::layout::LayoutPreset;
+use spaces_game::engine::match_state::MatchConfig;
+use spaces_game::engine::persona::Persona;
+use spaces_game::engine::player_model::PlayerModel;
+use spaces_game::engine::strategy::{train, StrategyBook};
+
+#[test]
+fn training_finds_an_equilibrium() {
+    let config = MatchConfig::default();
+    let boards = enumerate_boards(2, 5, &config.movement.movement());
+    let training = train(config.ruleset(), None, &boards, 2000);
+    let strategy = &training.strategy;
+
+    assert!(training.gap < 0.01, "gap {}", training.gap);
+    assert_eq!(strategy.size, 2);
+    let total: f64 = strategy.boards.iter().map(|(_, p)| p).sum();
+    assert!((total - 1.0).abs() < 1e-9);
+    assert!(strategy.boards.iter().all(|(board, p)| *p > 0.0 && boards.contains(board)));
+    // The classic rules treat both sides alike, so neither can expect to
+    // come out ahead
+    assert!(strategy.value.abs() < 0.01, "value {}", strategy.value);
+}
+
+#[test]
+fn the_book_keeps_one_strategy_per_size_rules_and_map() {
+    let config = MatchConfig::default();
+    let boards = enumerate_boards(2, 5, &config.movement.movement());
+    let mut book = StrategyBook::default();
+    book.insert(train(config.ruleset(), None, &boards, 10).strategy);
+    let retrained = train(config.ruleset(), None, &boards, 500).strategy;
+    book.insert(retrained.clone());
+
+    assert_eq!(book.strategies.len(), 1);
+    assert_eq!(book.find(&config, 2), Some(&retrained));
+    assert_eq!(book.find(&config, 3), None);
+    assert_eq!(book.find(&MatchConfig { layout: LayoutPreset::Pillar, ..config }, 2), None);
+
+    let json = serde_json::to_string(&book).unwrap();
+    assert_eq!(serde_json::from_str::<StrategyBook>(&json).unwrap(), book);
+}
+
+#[test]
+fn the_shipped_book_covers_the_classic_rules() {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("strategies/expert.json");
+    let book: StrategyBook = serde_json::from_str(&fs::read_to_string(path).unwrap()).unwrap();
+    let open = [2, 3, 4].map(|size| (MatchConfig::default(), size));
+    let maps = LayoutPreset::ALL
+        .into_iter()
+        .filter_map(|layout| Some((MatchConfig { layout, ..MatchConfig::default() }, layout.layout()?.size())));
+
+    for (config, size) in open.into_iter().chain(maps) {
+        let strategy = book.find(&config, size).unwrap_or_else(|| panic!("no strategy for {:?} at {}", config, size));
+        assert!(!strategy.boards.is_empty());
+        for (board, _) in &strategy.boards {
+            assert_eq!(config.check(board), Ok(()));
+        }
+    }
+}
+
+#[test]
+fn expert_plays_from_its_strategy() {
+    let config = MatchConfig::default();
+    let boards = enumerate_boards(2, 5, &config.movement.movement());
+    let mut book = StrategyBook::default();
+    book.insert(train(config.ruleset(), None, &boards, 2000).strategy);
+    let strategy = book.find(&config, 2).unwrap();
+
+    let library = Persona::Expert.library(&config, 2);
+    let model = PlayerModel::default();
+    let mut rng = StdRng::seed_from_u64(7);
+    for _ in 0..20 {
+        let chosen = Persona::Expert.choose(&config, &library, &[], &model, &book, &mut rng).unwrap();
+        assert!(strategy.boards.iter().any(|(board, _)| *board == chosen));
+    }
+
+    // Without a strategy for the match it plays from its library
+    let chosen = Persona::Expert.choose(&config, &library, &[], &model, &StrategyBook::default(), &mut rng).unwrap();
+    assert!(library.contains(&chosen));
+}
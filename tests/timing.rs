@@ -0,0 +1,70 @@
+mod common;
+
+use common::load_fixture;
+use spaces_game::engine::game_board::Side;
+use spaces_game::engine::match_state::{MatchConfig, MatchEvent, MatchPhase, MatchState};
+use spaces_game::engine::timing::{GameSpeed, TimingConfig, MAX_SECONDS};
+
+#[test]
+fn presets_keep_their_choosing_time_and_show_results_for_two_seconds_or_more() {
+    let selection: Vec<Option<u32>> = GameSpeed::PRESETS.iter().map(|speed| speed.timing().selection).collect();
+    assert_eq!(selection, [Some(1), Some(5), Some(10), None]);
+    assert_eq!(GameSpeed::Quick.timing(), TimingConfig { selection: Some(5), reveal: 2, results: Some(2) });
+    assert_eq!(GameSpeed::Chill.timing().results, None, "chill waits for the player after each round");
+    assert_eq!(TimingConfig::default(), GameSpeed::default().timing());
+}
+
+#[test]
+fn custom_timings_are_clamped() {
+    let wild = TimingConfig { selection: Some(0), reveal: 10_000, results: Some(MAX_SECONDS + 1) };
+    assert_eq!(
+        GameSpeed::Custom(wild).timing(),
+        TimingConfig { selection: Some(1), reveal: MAX_SECONDS, results: Some(MAX_SECONDS) }
+    );
+    let unlimited = TimingConfig { selection: None, reveal: 0, results: None };
+    assert_eq!(unlimited.clamped(), unlimited);
+}
+
+#[test]
+fn saved_speeds_still_load() {
+    // Profiles and matches saved before custom timings stored the bare name
+    assert_eq!(serde_json::from_str::<GameSpeed>("\"Relaxed\"").unwrap(), GameSpeed::Relaxed);
+    let custom = GameSpeed::Custom(TimingConfig { selection: None, reveal: 3, results: Some(4) });
+    let json = serde_json::to_string(&custom).unwrap();
+    assert_eq!(serde_json::from_str::<GameSpeed>(&json).unwrap(), custom);
+}
+
+#[test]
+fn rounds_move_on_by_themselves_once_both_boards_are_in() {
+    let fixture = load_fixture("both_reach_goal");
+    let timing = GameSpeed::Quick.timing();
+    let mut state = MatchState::new(MatchConfig { rounds: 2, ..MatchConfig::default() });
+    assert_eq!(timing.auto_advance(&state), None, "the match waits for the player to start it");
+    state.apply(MatchEvent::Start).unwrap();
+    let mut phases = Vec::new();
+    for _ in 0..2 {
+        assert_eq!(timing.auto_advance(&state), None, "the match waits for both boards");
+        state.apply(MatchEvent::BoardChosen { side: Side::Player, board: fixture.player.clone() }).unwrap();
+        state.apply(MatchEvent::BoardChosen { side: Side::Opponent, board: fixture.opponent.clone() }).unwrap();
+        while let Some((seconds, event)) = timing.auto_advance(&state) {
+            phases.push((state.phase(), seconds));
+            state.apply(event).unwrap();
+        }
+    }
+    assert_eq!(state.phase(), MatchPhase::MatchOver);
+    let round = [(MatchPhase::Revealing, 2), (MatchPhase::RoundSummary, 2)];
+    assert_eq!(phases, [round, round].concat());
+}
+
+#[test]
+fn without_a_results_time_the_summary_waits_for_the_player() {
+    let fixture = load_fixture("both_reach_goal");
+    let mut state = MatchState::new(MatchConfig::default());
+    state.apply(MatchEvent::Start).unwrap();
+    state.apply(MatchEvent::BoardChosen { side: Side::Player, board: fixture.player.clone() }).unwrap();
+    state.apply(MatchEvent::BoardChosen { side: Side::Opponent, board: fixture.opponent.clone() }).unwrap();
+    let chill = GameSpeed::Chill.timing();
+    assert_eq!(chill.auto_advance(&state), Some((2, MatchEvent::RevealFinished)));
+    state.apply(MatchEvent::RevealFinished).unwrap();
+    assert_eq!(chill.auto_advance(&state), None);
+}